@@ -0,0 +1,344 @@
+use crate::{
+  clients::RedisClient,
+  error::{RedisError, RedisErrorKind},
+  interfaces::{ClientLike, StreamsInterface},
+  prelude::RedisResult,
+  types::{RedisKey, RedisValue, StreamConsumerConfig, StreamEntry, XReadResponse, XReadValue, XID},
+};
+use bytes_utils::Str;
+use futures::{future::try_join_all, Future};
+use std::{collections::HashMap, fmt, sync::Arc, time::Instant};
+use tokio::sync::{watch, Semaphore};
+
+/// Read the delivery count for each entry in an `XPENDING` response.
+fn parse_delivery_counts(value: RedisValue) -> HashMap<Str, u64> {
+  value
+    .into_array()
+    .into_iter()
+    .filter_map(|entry| {
+      let mut parts = entry.into_array();
+      if parts.len() != 4 {
+        return None;
+      }
+
+      let deliveries = parts.pop().and_then(|v| v.as_u64())?;
+      let id = parts.swap_remove(0).as_bytes_str()?;
+      Some((id, deliveries))
+    })
+    .collect()
+}
+
+/// A worker that processes entries from a stream as a member of a consumer group.
+///
+/// The consumer performs the following steps:
+///
+/// 1. Creates the consumer group and stream if they do not exist (`XGROUP CREATE ... MKSTREAM`).
+/// 2. Reads batches of new entries with `XREADGROUP`, blocking for up to
+///    [block](crate::types::StreamConsumerConfig::block) on a [dedicated](crate::clients::RedisClient::dedicated)
+///    connection.
+/// 3. Calls the handler on each entry with up to
+///    [max_concurrency](crate::types::StreamConsumerConfig::max_concurrency) entries in flight, sending `XACK` when
+///    the handler succeeds. Entries that fail remain pending and are retried once they are claimed again.
+/// 4. Periodically claims entries that have been pending for longer than
+///    [claim_min_idle](crate::types::StreamConsumerConfig::claim_min_idle) with `XAUTOCLAIM`, including entries from
+///    consumers that have stopped.
+/// 5. Moves claimed entries that were delivered more than
+///    [max_deliveries](crate::types::StreamConsumerConfig::max_deliveries) times, according to `XPENDING`, to the
+///    [dead letter stream](crate::types::StreamConsumerConfig::dead_letter_key).
+///
+/// ```rust no_run
+/// # use fred::prelude::*;
+/// # use fred::{clients::StreamConsumer, types::{StreamConsumerConfig, StreamEntry}};
+/// async fn example(client: &RedisClient) -> Result<(), RedisError> {
+///   let config = StreamConsumerConfig {
+///     dead_letter_key: Some("orders:dead".into()),
+///     ..Default::default()
+///   };
+///   let consumer = StreamConsumer::new(client, "orders", "workers", "worker-1", config);
+///
+///   let _consumer = consumer.clone();
+///   let jh = tokio::spawn(async move {
+///     _consumer
+///       .run(|entry: StreamEntry| async move {
+///         println!("Processing {}: {:?}", entry.id, entry.fields);
+///         Ok(())
+///       })
+///       .await
+///   });
+///
+///   // ...
+///   consumer.shutdown();
+///   jh.await??;
+///   Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct StreamConsumer {
+  client:   RedisClient,
+  key:      RedisKey,
+  group:    Str,
+  consumer: Str,
+  config:   Arc<StreamConsumerConfig>,
+  shutdown: Arc<watch::Sender<bool>>,
+}
+
+impl fmt::Debug for StreamConsumer {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("StreamConsumer")
+      .field("id", &self.client.id())
+      .field("key", &self.key)
+      .field("group", &self.group)
+      .field("consumer", &self.consumer)
+      .finish()
+  }
+}
+
+impl StreamConsumer {
+  /// Create a new consumer without reading from the stream.
+  pub fn new<K, G, C>(client: &RedisClient, key: K, group: G, consumer: C, config: StreamConsumerConfig) -> Self
+  where
+    K: Into<RedisKey>,
+    G: Into<Str>,
+    C: Into<Str>,
+  {
+    StreamConsumer {
+      client:   client.clone(),
+      key:      key.into(),
+      group:    group.into(),
+      consumer: consumer.into(),
+      config:   Arc::new(config),
+      shutdown: Arc::new(watch::channel(false).0),
+    }
+  }
+
+  /// Stop reading new entries.
+  ///
+  /// [run](Self::run) returns after the current read finishes and all in-flight entries are processed. A consumer
+  /// cannot be restarted after it is shut down.
+  pub fn shutdown(&self) {
+    self.shutdown.send_replace(true);
+  }
+
+  /// Create the consumer group and stream if they do not already exist.
+  pub async fn create_group(&self) -> RedisResult<()> {
+    let result: RedisResult<()> = self
+      .client
+      .xgroup_create(self.key.clone(), self.group.clone(), self.config.start_id.clone(), true)
+      .await;
+
+    match result {
+      Err(e) if e.details().starts_with("BUSYGROUP") => Ok(()),
+      result => result,
+    }
+  }
+
+  /// Read and process entries until [shutdown](Self::shutdown) is called or an error occurs.
+  ///
+  /// In-flight entries are processed before this function returns in either case. Returns a `Config` error if the
+  /// [block](crate::types::StreamConsumerConfig::block) duration is zero.
+  pub async fn run<F, Fut>(&self, handler: F) -> RedisResult<()>
+  where
+    F: Fn(StreamEntry) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = RedisResult<()>> + Send + 'static,
+  {
+    if self.config.block.as_millis() == 0 {
+      return Err(RedisError::new(
+        RedisErrorKind::Config,
+        "Stream consumer block duration must be greater than zero.",
+      ));
+    }
+
+    self.create_group().await?;
+    let mut reader = self.client.dedicated().await?;
+    let handler = Arc::new(handler);
+    let max_concurrency = self.config.max_concurrency.max(1);
+    let permits = Arc::new(Semaphore::new(max_concurrency));
+    let shutdown = self.shutdown.subscribe();
+    let mut last_claim: Option<Instant> = None;
+
+    let result = loop {
+      if *shutdown.borrow() {
+        break Ok(());
+      }
+
+      if last_claim
+        .map(|t| t.elapsed() >= self.config.claim_interval)
+        .unwrap_or(true)
+      {
+        if let Err(e) = self.claim(&permits, &handler).await {
+          break Err(e);
+        }
+        last_claim = Some(Instant::now());
+      }
+
+      let entries = match self.read(&reader).await {
+        Ok(entries) => entries,
+        Err(e) if *e.kind() == RedisErrorKind::IO => {
+          // dedicated clients do not reconnect, so read with a new connection
+          let inner = self.client.inner();
+          _debug!(inner, "Replacing stream consumer connection after error: {:?}", e);
+          reader = match self.client.dedicated().await {
+            Ok(reader) => reader,
+            Err(e) => break Err(e),
+          };
+          continue;
+        },
+        Err(e) => break Err(e),
+      };
+      for entry in entries.into_iter() {
+        self.dispatch(&permits, &handler, entry).await;
+      }
+    };
+
+    let _ = permits.acquire_many(max_concurrency as u32).await;
+    result
+  }
+
+  async fn read(&self, reader: &RedisClient) -> RedisResult<Vec<StreamEntry>> {
+    let block = Some(self.config.block.as_millis() as u64);
+
+    let response: XReadResponse<RedisKey, Str, Str, RedisValue> = reader
+      .xreadgroup_map(
+        self.group.clone(),
+        self.consumer.clone(),
+        Some(self.config.batch_size),
+        block,
+        false,
+        self.key.clone(),
+        XID::NewInGroup,
+      )
+      .await?;
+
+    Ok(
+      response
+        .into_values()
+        .flatten()
+        .map(|(id, fields)| StreamEntry {
+          id,
+          fields,
+          deliveries: 1,
+        })
+        .collect(),
+    )
+  }
+
+  /// Process the entry in a new task once a permit is available.
+  async fn dispatch<F, Fut>(&self, permits: &Arc<Semaphore>, handler: &Arc<F>, entry: StreamEntry)
+  where
+    F: Fn(StreamEntry) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = RedisResult<()>> + Send + 'static,
+  {
+    let permit = match permits.clone().acquire_owned().await {
+      Ok(permit) => permit,
+      Err(_) => return,
+    };
+    let (client, key, group, handler) = (
+      self.client.clone(),
+      self.key.clone(),
+      self.group.clone(),
+      handler.clone(),
+    );
+
+    tokio::spawn(async move {
+      let inner = client.inner();
+      let id = entry.id.clone();
+
+      match handler(entry).await {
+        Ok(_) => {
+          if let Err(e) = client.xack::<(), _, _, _>(key, group, id.clone()).await {
+            _warn!(inner, "Failed to acknowledge stream entry {}: {:?}", id, e);
+          }
+        },
+        Err(e) => _debug!(inner, "Failed to process stream entry {}: {:?}", id, e),
+      };
+      drop(permit);
+    });
+  }
+
+  /// Claim stale entries, moving entries with too many deliveries to the dead letter stream.
+  async fn claim<F, Fut>(&self, permits: &Arc<Semaphore>, handler: &Arc<F>) -> RedisResult<()>
+  where
+    F: Fn(StreamEntry) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = RedisResult<()>> + Send + 'static,
+  {
+    let min_idle = self.config.claim_min_idle.as_millis() as u64;
+    let mut cursor = String::from("0-0");
+
+    loop {
+      let (next, entries): (String, Vec<XReadValue<Str, Str, RedisValue>>) = self
+        .client
+        .xautoclaim_values(
+          self.key.clone(),
+          self.group.clone(),
+          self.consumer.clone(),
+          min_idle,
+          cursor.as_str(),
+          Some(self.config.batch_size),
+          false,
+        )
+        .await?;
+
+      let deliveries = self.delivery_counts(&entries).await?;
+      for (id, fields) in entries.into_iter() {
+        let entry = StreamEntry {
+          deliveries: match deliveries.get(&id) {
+            Some(deliveries) => *deliveries,
+            None => {
+              return Err(RedisError::new(
+                RedisErrorKind::Unknown,
+                format!("Missing delivery count for stream entry {}.", id),
+              ))
+            },
+          },
+          id,
+          fields,
+        };
+
+        match self.config.dead_letter_key {
+          Some(ref key) if entry.deliveries > self.config.max_deliveries => self.dead_letter(key, entry).await?,
+          _ => self.dispatch(permits, handler, entry).await,
+        };
+      }
+
+      if next == "0-0" {
+        break;
+      }
+      cursor = next;
+    }
+
+    Ok(())
+  }
+
+  /// Read the delivery counts for the provided entries claimed by this consumer.
+  ///
+  /// Entries are read individually since the consumer's pending list may contain other entries in the same range.
+  async fn delivery_counts(&self, entries: &[XReadValue<Str, Str, RedisValue>]) -> RedisResult<HashMap<Str, u64>> {
+    let responses: Vec<RedisValue> = try_join_all(entries.iter().map(|(id, _)| {
+      let args = (
+        XID::Manual(id.clone()),
+        XID::Manual(id.clone()),
+        1,
+        self.consumer.clone(),
+      );
+      self.client.xpending(self.key.clone(), self.group.clone(), args)
+    }))
+    .await?;
+
+    Ok(responses.into_iter().flat_map(parse_delivery_counts).collect())
+  }
+
+  async fn dead_letter(&self, key: &RedisKey, entry: StreamEntry) -> Result<(), RedisError> {
+    let inner = self.client.inner();
+    _debug!(
+      inner,
+      "Moving stream entry {} to {} after {} deliveries.",
+      entry.id,
+      key.as_str_lossy(),
+      entry.deliveries
+    );
+    let fields: Vec<(Str, RedisValue)> = entry.fields.into_iter().collect();
+
+    let _: () = self.client.xadd(key.clone(), false, None, XID::Auto, fields).await?;
+    self.client.xack(self.key.clone(), self.group.clone(), entry.id).await
+  }
+}
@@ -0,0 +1,180 @@
+use crate::{
+  clients::RedisClient,
+  error::{RedisError, RedisErrorKind},
+  interfaces::{ClientLike, KeysInterface, ServerInterface},
+  types::ConnectionConfig,
+  utils,
+};
+use parking_lot::Mutex;
+use std::{fmt, ops::Deref, sync::Arc};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// A client that owns its connection(s) exclusively until it is dropped.
+///
+/// Dedicated clients are useful with commands that change or depend on connection state, such as `WATCH`, `SELECT`,
+/// `CLIENT REPLY`, or blocking commands, since these cannot safely run on a multiplexed connection shared with other
+/// tasks.
+///
+/// When the client is dropped the connection is reset with `UNWATCH` and `SELECT` and kept for the next caller.
+/// Callers that change other connection state, such as subscriptions or `CLIENT TRACKING`, should call `quit` before
+/// dropping the client so the connection is closed instead.
+///
+/// Dedicated clients do not reconnect, since any state on the connection would be lost. Commands fail with an `IO`
+/// error if the connection closes, and the connection is replaced when the client is dropped.
+///
+/// The number of dedicated clients that can be checked out at once is limited by
+/// [max_dedicated_connections](crate::types::ConnectionConfig::max_dedicated_connections).
+///
+/// ```rust
+/// # use fred::prelude::*;
+/// async fn example(pool: &RedisPool) -> Result<(), RedisError> {
+///   let client = pool.acquire().await?;
+///   let _: () = client.watch("foo").await?;
+///
+///   let trx = client.multi();
+///   let _: () = trx.incr("foo").await?;
+///   let _: () = trx.exec(true).await?;
+///   Ok(())
+/// }
+/// ```
+pub struct DedicatedClient {
+  client:  RedisClient,
+  clients: Arc<DedicatedClients>,
+  permit:  Option<OwnedSemaphorePermit>,
+}
+
+impl fmt::Debug for DedicatedClient {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("DedicatedClient")
+      .field("id", &self.client.id())
+      .finish()
+  }
+}
+
+impl Deref for DedicatedClient {
+  type Target = RedisClient;
+
+  fn deref(&self) -> &Self::Target {
+    &self.client
+  }
+}
+
+impl Drop for DedicatedClient {
+  fn drop(&mut self) {
+    let (client, clients, permit) = (self.client.clone(), self.clients.clone(), self.permit.take());
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+      handle.spawn(async move {
+        clients.release(client).await;
+        drop(permit);
+      });
+    }
+  }
+}
+
+/// The permits and idle connections shared by the dedicated clients checked out from a `RedisClient` or `RedisPool`.
+pub(crate) struct DedicatedClients {
+  permits: Arc<Semaphore>,
+  idle:    Mutex<Vec<RedisClient>>,
+}
+
+impl DedicatedClients {
+  pub fn new(connection: &ConnectionConfig) -> Self {
+    DedicatedClients {
+      permits: Arc::new(utils::dedicated_semaphore(connection)),
+      idle:    Mutex::new(Vec::new()),
+    }
+  }
+
+  /// Reset the connection state on `client` and return it to the idle list, or close it if the reset fails.
+  async fn release(&self, client: RedisClient) {
+    let timeout = client.inner().internal_command_timeout();
+    let result = if client.is_connected() {
+      utils::apply_timeout(reset(&client), timeout).await
+    } else {
+      Err(RedisError::new(RedisErrorKind::IO, "Connection closed."))
+    };
+
+    match result {
+      Ok(_) => self.idle.lock().push(client),
+      Err(e) => {
+        let inner = client.inner();
+        _debug!(inner, "Closing dedicated connection after reset error: {:?}", e);
+        let _ = client.quit().await;
+      },
+    };
+  }
+
+  /// Close the idle connections.
+  pub async fn close_idle(&self) {
+    let idle: Vec<_> = self.idle.lock().drain(..).collect();
+    for client in idle.into_iter() {
+      let _ = client.quit().await;
+    }
+  }
+}
+
+/// Undo the connection state changes that callers commonly make on dedicated connections.
+async fn reset(client: &RedisClient) -> Result<(), RedisError> {
+  if client.is_clustered() {
+    for server in client.active_connections().await?.into_iter() {
+      let _: () = client.with_cluster_node(server).unwatch().await?;
+    }
+  } else {
+    let _: () = client.unwatch().await?;
+    let _: () = client.select(client.inner().config.database.unwrap_or(0)).await?;
+  }
+
+  Ok(())
+}
+
+/// Wait for a permit and check out an idle connection, or create a new connection from the config on `client`.
+pub(crate) async fn acquire(
+  clients: &Arc<DedicatedClients>,
+  client: &RedisClient,
+) -> Result<DedicatedClient, RedisError> {
+  let timeout = client.inner().connection.dedicated_wait_timeout;
+  let permit = utils::apply_timeout(
+    async {
+      clients
+        .permits
+        .clone()
+        .acquire_owned()
+        .await
+        .map_err(|_| RedisError::new(RedisErrorKind::Canceled, "Dedicated connections closed."))
+    },
+    timeout,
+  )
+  .await?;
+
+  let idle = loop {
+    let idle = clients.idle.lock().pop();
+    match idle {
+      Some(idle) if idle.is_connected() => break Some(idle),
+      Some(idle) => {
+        let _ = idle.quit().await;
+      },
+      None => break None,
+    };
+  };
+  let client = match idle {
+    Some(client) => client,
+    None => {
+      let mut connection = client.inner().connection_config();
+      connection.max_command_attempts = 1;
+      let client = RedisClient::new(
+        client.inner().config.as_ref().clone(),
+        Some(client.inner().performance_config()),
+        Some(connection),
+        None,
+      );
+      client.init().await?;
+      client
+    },
+  };
+
+  Ok(DedicatedClient {
+    client,
+    clients: clients.clone(),
+    permit: Some(permit),
+  })
+}
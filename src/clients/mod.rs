@@ -1,8 +1,13 @@
+mod consumer;
+mod dedicated;
 mod options;
 mod pipeline;
 mod pool;
 mod redis;
 
+pub use consumer::StreamConsumer;
+pub use dedicated::DedicatedClient;
+pub(crate) use dedicated::DedicatedClients;
 pub use options::WithOptions;
 pub use pipeline::Pipeline;
 pub use pool::RedisPool;
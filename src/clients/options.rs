@@ -83,6 +83,11 @@ impl<C: ClientLike> ClientLike for WithOptions<C> {
     self.options.apply(command);
   }
 
+  #[doc(hidden)]
+  fn split_cross_slot(&self, enabled: Option<bool>) -> bool {
+    self.client.split_cross_slot(self.options.split_cross_slot.or(enabled))
+  }
+
   #[doc(hidden)]
   fn send_command<T>(&self, command: T) -> Result<(), RedisError>
   where
@@ -95,6 +100,7 @@ impl<C: ClientLike> ClientLike for WithOptions<C> {
 }
 
 impl<C: AclInterface> AclInterface for WithOptions<C> {}
+impl<C: BitmapInterface> BitmapInterface for WithOptions<C> {}
 impl<C: ClientInterface> ClientInterface for WithOptions<C> {}
 impl<C: ClusterInterface> ClusterInterface for WithOptions<C> {}
 impl<C: PubsubInterface> PubsubInterface for WithOptions<C> {}
@@ -103,6 +109,7 @@ impl<C: GeoInterface> GeoInterface for WithOptions<C> {}
 impl<C: HashesInterface> HashesInterface for WithOptions<C> {}
 impl<C: HyperloglogInterface> HyperloglogInterface for WithOptions<C> {}
 impl<C: KeysInterface> KeysInterface for WithOptions<C> {}
+impl<C: StringsInterface> StringsInterface for WithOptions<C> {}
 impl<C: ListInterface> ListInterface for WithOptions<C> {}
 impl<C: MemoryInterface> MemoryInterface for WithOptions<C> {}
 impl<C: AuthInterface> AuthInterface for WithOptions<C> {}
@@ -118,3 +125,9 @@ impl<C: RedisJsonInterface> RedisJsonInterface for WithOptions<C> {}
 #[cfg(feature = "time-series")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time-series")))]
 impl<C: TimeSeriesInterface> TimeSeriesInterface for WithOptions<C> {}
+#[cfg(feature = "redis-bloom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+impl<C: RedisBloomInterface> RedisBloomInterface for WithOptions<C> {}
+#[cfg(feature = "redis-search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+impl<C: RediSearchInterface> RediSearchInterface for WithOptions<C> {}
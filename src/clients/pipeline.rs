@@ -4,6 +4,7 @@ use crate::{
   interfaces::{
     AclInterface,
     AuthInterface,
+    BitmapInterface,
     ClientInterface,
     ClientLike,
     ClusterInterface,
@@ -22,6 +23,7 @@ use crate::{
     SlowlogInterface,
     SortedSetsInterface,
     StreamsInterface,
+    StringsInterface,
   },
   modules::{inner::RedisClientInner, response::FromRedis},
   prelude::{RedisResult, RedisValue},
@@ -36,6 +38,10 @@ use parking_lot::Mutex;
 use std::{collections::VecDeque, fmt, fmt::Formatter, sync::Arc};
 use tokio::sync::oneshot::{channel as oneshot_channel, Receiver as OneshotReceiver};
 
+#[cfg(feature = "redis-search")]
+use crate::interfaces::RediSearchInterface;
+#[cfg(feature = "redis-bloom")]
+use crate::interfaces::RedisBloomInterface;
 #[cfg(feature = "redis-json")]
 use crate::interfaces::RedisJsonInterface;
 #[cfg(feature = "time-series")]
@@ -126,6 +132,11 @@ impl<C: ClientLike> ClientLike for Pipeline<C> {
     self.client.change_command(command);
   }
 
+  #[doc(hidden)]
+  fn split_cross_slot(&self, _: Option<bool>) -> bool {
+    false
+  }
+
   #[doc(hidden)]
   fn send_command<T>(&self, command: T) -> Result<(), RedisError>
   where
@@ -149,6 +160,7 @@ impl<C: ClientLike> ClientLike for Pipeline<C> {
 }
 
 impl<C: AclInterface> AclInterface for Pipeline<C> {}
+impl<C: BitmapInterface> BitmapInterface for Pipeline<C> {}
 impl<C: ClientInterface> ClientInterface for Pipeline<C> {}
 impl<C: ClusterInterface> ClusterInterface for Pipeline<C> {}
 impl<C: PubsubInterface> PubsubInterface for Pipeline<C> {}
@@ -157,6 +169,7 @@ impl<C: GeoInterface> GeoInterface for Pipeline<C> {}
 impl<C: HashesInterface> HashesInterface for Pipeline<C> {}
 impl<C: HyperloglogInterface> HyperloglogInterface for Pipeline<C> {}
 impl<C: KeysInterface> KeysInterface for Pipeline<C> {}
+impl<C: StringsInterface> StringsInterface for Pipeline<C> {}
 impl<C: ListInterface> ListInterface for Pipeline<C> {}
 impl<C: MemoryInterface> MemoryInterface for Pipeline<C> {}
 impl<C: AuthInterface> AuthInterface for Pipeline<C> {}
@@ -172,6 +185,12 @@ impl<C: RedisJsonInterface> RedisJsonInterface for Pipeline<C> {}
 #[cfg(feature = "time-series")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time-series")))]
 impl<C: TimeSeriesInterface> TimeSeriesInterface for Pipeline<C> {}
+#[cfg(feature = "redis-bloom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+impl<C: RedisBloomInterface> RedisBloomInterface for Pipeline<C> {}
+#[cfg(feature = "redis-search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+impl<C: RediSearchInterface> RediSearchInterface for Pipeline<C> {}
 
 impl<C: ClientLike> Pipeline<C> {
   /// Send the pipeline and respond with an array of all responses.
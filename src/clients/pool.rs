@@ -1,31 +1,260 @@
 use crate::{
-  clients::RedisClient,
+  clients::{dedicated, DedicatedClient, DedicatedClients, RedisClient},
   error::{RedisError, RedisErrorKind},
   interfaces::*,
-  modules::inner::RedisClientInner,
-  types::{ConnectHandle, ConnectionConfig, PerformanceConfig, ReconnectPolicy, RedisConfig, Server},
+  modules::inner::{Notifications, RedisClientInner},
+  types::{
+    ClientState,
+    ClusterStateChange,
+    ConnectHandle,
+    ConnectionConfig,
+    ElasticPoolConfig,
+    KeyspaceEvent,
+    Message,
+    MultipleStrings,
+    PerformanceConfig,
+    PoolEvent,
+    PoolRoutingPolicy,
+    ReconnectPolicy,
+    RedisConfig,
+    RoundRobin,
+    Server,
+  },
   utils,
 };
+use bytes_utils::Str;
 use futures::future::{join_all, try_join_all};
+use parking_lot::{Mutex, RwLock};
 use std::{
   fmt,
   sync::{
     atomic::{AtomicBool, AtomicUsize},
     Arc,
+    OnceLock,
+    Weak,
+  },
+  time::{Duration, Instant},
+};
+use tokio::{
+  sync::{
+    broadcast::{
+      channel as broadcast_channel,
+      error::RecvError,
+      Receiver as BroadcastReceiver,
+      Sender as BroadcastSender,
+    },
+    Mutex as AsyncMutex,
   },
-  time::Duration,
+  task::JoinHandle,
+  time::interval as tokio_interval,
 };
-use tokio::time::interval as tokio_interval;
 
 #[cfg(feature = "replicas")]
 use crate::clients::Replicas;
 #[cfg(feature = "dns")]
 use crate::protocol::types::Resolve;
+#[cfg(feature = "metrics")]
+use crate::types::Stats;
+
+/// The number of buckets in [ClientGenerations], where bucket `i` holds `2^i` generations.
+const GENERATION_BUCKETS: usize = usize::BITS as usize;
+
+/// An append-only list of the pool's clients, where each replaced client adds a new generation.
+///
+/// Earlier generations are kept until the pool is dropped so that the references returned by the pool stay valid.
+struct ClientGenerations {
+  buckets: Vec<OnceLock<Box<[OnceLock<Vec<RedisClient>>]>>>,
+  len:     Arc<AtomicUsize>,
+}
+
+impl ClientGenerations {
+  fn new(clients: Vec<RedisClient>) -> Self {
+    let generations = ClientGenerations {
+      buckets: (0 .. GENERATION_BUCKETS).map(|_| OnceLock::new()).collect(),
+      len:     Arc::new(AtomicUsize::new(0)),
+    };
+    generations.push(clients);
+    generations
+  }
+
+  /// Read the bucket and offset of the generation at `idx`.
+  fn locate(idx: usize) -> (usize, usize) {
+    let n = idx + 1;
+    let bucket = (usize::BITS - 1 - n.leading_zeros()) as usize;
+    (bucket, n - (1 << bucket))
+  }
+
+  /// Read the latest generation.
+  fn current(&self) -> &[RedisClient] {
+    let (bucket, offset) = Self::locate(utils::read_atomic(&self.len) - 1);
+    self.buckets[bucket]
+      .get()
+      .and_then(|generations| generations[offset].get())
+      .expect("Missing pool clients.")
+  }
+
+  /// Add a new generation. Callers must not push concurrently.
+  fn push(&self, clients: Vec<RedisClient>) {
+    let idx = utils::read_atomic(&self.len);
+    let (bucket, offset) = Self::locate(idx);
+    let generations = self.buckets[bucket].get_or_init(|| (0 .. 1 << bucket).map(|_| OnceLock::new()).collect());
+    let _ = generations[offset].set(clients);
+    utils::set_atomic(&self.len, idx + 1);
+  }
+}
 
 struct RedisPoolInner {
-  clients:          Vec<RedisClient>,
-  counter:          Arc<AtomicUsize>,
+  clients:          ClientGenerations,
+  active:           Arc<AtomicUsize>,
+  last:             Arc<AtomicUsize>,
+  routing:          RwLock<Arc<dyn PoolRoutingPolicy>>,
   prefer_connected: Arc<AtomicBool>,
+  dedicated:        Arc<DedicatedClients>,
+  elastic:          Option<ElasticPoolConfig>,
+  events:           BroadcastSender<PoolEvent>,
+  notifications:    Arc<Notifications>,
+  forwarders:       Mutex<Vec<Option<JoinHandle<()>>>>,
+  resize_lock:      AsyncMutex<()>,
+  monitor:          Mutex<Option<JoinHandle<()>>>,
+  subscriber:       RedisClient,
+  subscriber_tasks: AsyncMutex<Option<(ConnectHandle, JoinHandle<()>)>>,
+}
+
+impl RedisPoolInner {
+  fn new(clients: Vec<RedisClient>, active: usize, elastic: Option<ElasticPoolConfig>) -> Self {
+    let dedicated = Arc::new(DedicatedClients::new(&clients[0].inner.connection));
+    let capacity = clients[0].inner.with_perf_config(|c| c.broadcast_channel_capacity);
+    let (events, _) = broadcast_channel(capacity);
+    let id = format!("fred-pool-{}", utils::random_string(10)).into();
+    let notifications = Arc::new(Notifications::new(&id, capacity));
+    let forwarders = Mutex::new(clients.iter().map(|_| None).collect());
+    let subscriber = clients[0].clone_new();
+
+    RedisPoolInner {
+      subscriber_tasks: AsyncMutex::new(None),
+      subscriber,
+      clients: ClientGenerations::new(clients),
+      active: Arc::new(AtomicUsize::new(active)),
+      last: Arc::new(AtomicUsize::new(0)),
+      routing: RwLock::new(Arc::new(RoundRobin::default())),
+      prefer_connected: Arc::new(AtomicBool::new(true)),
+      resize_lock: AsyncMutex::new(()),
+      monitor: Mutex::new(None),
+      dedicated,
+      elastic,
+      events,
+      notifications,
+      forwarders,
+    }
+  }
+
+  /// Read all the clients in the pool, including inactive clients.
+  fn clients(&self) -> &[RedisClient] {
+    self.clients.current()
+  }
+
+  fn active(&self) -> &[RedisClient] {
+    &self.clients()[.. utils::read_atomic(&self.active)]
+  }
+
+  fn broadcast(&self, event: PoolEvent) {
+    let _ = self.events.send(event);
+  }
+
+  /// Forward events from the client at `idx` to the pool's event receivers.
+  ///
+  /// The task exits when the client closes its event receivers via `QUIT`.
+  fn forward_events(&self, idx: usize) {
+    let task = self.spawn_forwarder(&self.clients()[idx]);
+    if let Some(old) = self.forwarders.lock()[idx].replace(task) {
+      old.abort();
+    }
+  }
+
+  /// Spawn a task that forwards events from `client` to the pool's event receivers.
+  fn spawn_forwarder(&self, client: &RedisClient) -> JoinHandle<()> {
+    let id = client.inner.id.clone();
+    let (notifications, events) = (self.notifications.clone(), self.events.clone());
+    let mut errors = client.error_rx();
+    let mut messages = client.message_rx();
+    let mut keyspace = client.keyspace_event_rx();
+    let mut reconnect = client.reconnect_rx();
+    let mut cluster_change = client.cluster_change_rx();
+    let mut unresponsive = client.unresponsive_rx();
+
+    tokio::spawn(async move {
+      loop {
+        let closed = tokio::select! {
+          result = errors.recv() => forward(result, |error: RedisError| {
+            notifications.broadcast_error(error.clone());
+            let _ = events.send(PoolEvent::Error(id.clone(), error));
+          }),
+          result = messages.recv() => forward(result, |message: Message| notifications.broadcast_pubsub(message)),
+          result = keyspace.recv() => forward(result, |event: KeyspaceEvent| notifications.broadcast_keyspace(event)),
+          result = reconnect.recv() => forward(result, |server: Server| {
+            notifications.broadcast_reconnect(server.clone());
+            let _ = events.send(PoolEvent::Reconnect(id.clone(), server));
+          }),
+          result = cluster_change.recv() => forward(result, |changes: Vec<ClusterStateChange>| {
+            notifications.broadcast_cluster_change(changes.clone());
+            let _ = events.send(PoolEvent::ClusterChange(id.clone(), changes));
+          }),
+          result = unresponsive.recv() => forward(result, |server: Server| {
+            notifications.broadcast_unresponsive(server.clone());
+            let _ = events.send(PoolEvent::Unresponsive(id.clone(), server));
+          }),
+        };
+
+        if closed {
+          break;
+        }
+      }
+    })
+  }
+
+  /// Read the client used for subscriptions, connecting it first if needed.
+  async fn subscriber(&self) -> Result<&RedisClient, RedisError> {
+    let mut tasks = self.subscriber_tasks.lock().await;
+    if tasks.as_ref().map(|(task, _)| task.is_finished()).unwrap_or(true) {
+      let forwarder = self.spawn_forwarder(&self.subscriber);
+      match self.subscriber.init().await {
+        Ok(task) => {
+          if let Some((_, old)) = tasks.replace((task, forwarder)) {
+            old.abort();
+          }
+        },
+        Err(error) => {
+          forwarder.abort();
+          return Err(error);
+        },
+      };
+    }
+
+    Ok(&self.subscriber)
+  }
+
+  /// Close the connection used for subscriptions, if it was connected.
+  async fn quit_subscriber(&self) {
+    if let Some((_, forwarder)) = self.subscriber_tasks.lock().await.take() {
+      let _ = self.subscriber.quit().await;
+      forwarder.abort();
+    }
+  }
+}
+
+/// Call `func` with the received value, returning whether the channel is closed.
+fn forward<T, F>(result: Result<T, RecvError>, func: F) -> bool
+where
+  F: FnOnce(T),
+{
+  match result {
+    Ok(value) => {
+      func(value);
+      false
+    },
+    Err(RecvError::Lagged(_)) => false,
+    Err(RecvError::Closed) => true,
+  }
 }
 
 /// A cheaply cloneable round-robin client pool.
@@ -33,16 +262,29 @@ struct RedisPoolInner {
 /// ### Restrictions
 ///
 /// The following interfaces are not implemented on `RedisPool`:
-/// * [MetricsInterface](crate::interfaces::MetricsInterface)
-/// * [PubsubInterface](crate::interfaces::PubsubInterface)
 /// * [TransactionInterface](crate::interfaces::TransactionInterface)
-/// * [EventInterface](crate::interfaces::EventInterface)
 /// * [ClientInterface](crate::interfaces::ClientInterface)
 ///
-/// In some cases, such as [publish](crate::interfaces::PubsubInterface::publish), callers can work around this by
-/// adding a call to [next](Self::next), but in other scenarios this may not work. As a general rule, any commands
-/// that change or depend on local connection state will not be implemented directly on `RedisPool`. Callers can use
-/// [clients](Self::clients), [next](Self::next), or [last](Self::last) to operate on individual clients if needed.
+/// As a general rule, any commands that change or depend on local connection state will not be implemented directly
+/// on `RedisPool`. Callers can use [clients](Self::clients), [next](Self::next), or [last](Self::last) to operate on
+/// individual clients if needed, or [acquire](Self::acquire) to check out a client with its own connection.
+///
+/// ### Events and Metrics
+///
+/// The [EventInterface](crate::interfaces::EventInterface) receivers on `RedisPool` merge the events from every
+/// client in the pool. See [pool_event_rx](Self::pool_event_rx) to also read the ID of the client that emitted each
+/// event. Functions on the [MetricsInterface](crate::interfaces::MetricsInterface) aggregate the metrics across all
+/// clients in the pool, and the per-client values can be read via [clients](Self::clients).
+///
+/// Channel subscriptions via the [PubsubInterface](crate::interfaces::PubsubInterface) use a separate connection
+/// owned by the pool, which is created on the first subscription. Messages are published with the next connected
+/// client.
+///
+/// ### Resizing
+///
+/// Pools can be resized at runtime via [resize](Self::resize), up to the size provided when the pool was created.
+/// Pools created with [new_elastic](Self::new_elastic) will also resize themselves based on load and replace clients
+/// that stay disconnected. See [pool_event_rx](Self::pool_event_rx) to observe these changes.
 #[derive(Clone)]
 pub struct RedisPool {
   inner: Arc<RedisPoolInner>,
@@ -51,7 +293,8 @@ pub struct RedisPool {
 impl fmt::Debug for RedisPool {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     f.debug_struct("RedisPool")
-      .field("size", &self.inner.clients.len())
+      .field("size", &self.size())
+      .field("max_size", &self.inner.clients().len())
       .finish()
   }
 }
@@ -62,12 +305,10 @@ impl RedisPool {
     if clients.is_empty() {
       Err(RedisError::new(RedisErrorKind::Config, "Pool cannot be empty."))
     } else {
+      let size = clients.len();
+
       Ok(RedisPool {
-        inner: Arc::new(RedisPoolInner {
-          clients,
-          counter: Arc::new(AtomicUsize::new(0)),
-          prefer_connected: Arc::new(AtomicBool::new(true)),
-        }),
+        inner: Arc::new(RedisPoolInner::new(clients, size, None)),
       })
     }
   }
@@ -96,61 +337,327 @@ impl RedisPool {
       }
 
       Ok(RedisPool {
-        inner: Arc::new(RedisPoolInner {
-          clients,
-          counter: Arc::new(AtomicUsize::new(0)),
-          prefer_connected: Arc::new(AtomicBool::new(true)),
-        }),
+        inner: Arc::new(RedisPoolInner::new(clients, size, None)),
       })
     }
   }
 
+  /// Create a new pool that resizes itself between `min_size` and `max_size` clients based on load, without
+  /// connecting to the server.
+  ///
+  /// Once connected the pool will check the number of in-flight commands on each client every `check_interval`,
+  /// adding or removing one client at a time when the average crosses the configured thresholds. Clients that stay
+  /// in a `Disconnected` state longer than `unhealthy_timeout` will be replaced with new clients.
+  ///
+  /// See [ElasticPoolConfig](crate::types::ElasticPoolConfig) for more information.
+  pub fn new_elastic(
+    config: RedisConfig,
+    perf: Option<PerformanceConfig>,
+    connection: Option<ConnectionConfig>,
+    policy: Option<ReconnectPolicy>,
+    elastic: ElasticPoolConfig,
+  ) -> Result<Self, RedisError> {
+    if elastic.min_size == 0 {
+      return Err(RedisError::new(RedisErrorKind::Config, "Pool cannot be empty."));
+    }
+    if elastic.max_size < elastic.min_size {
+      return Err(RedisError::new(
+        RedisErrorKind::Config,
+        "Max pool size must be greater than or equal to the min size.",
+      ));
+    }
+
+    let mut clients = Vec::with_capacity(elastic.max_size);
+    for _ in 0 .. elastic.max_size {
+      clients.push(RedisClient::new(
+        config.clone(),
+        perf.clone(),
+        connection.clone(),
+        policy.clone(),
+      ));
+    }
+
+    Ok(RedisPool {
+      inner: Arc::new(RedisPoolInner::new(clients, elastic.min_size, Some(elastic))),
+    })
+  }
+
   /// Set whether the client will use [next_connected](Self::next_connected) or [next](Self::next) when routing
   /// commands among the pooled clients.
   pub fn prefer_connected(&self, val: bool) -> bool {
     utils::set_bool_atomic(&self.inner.prefer_connected, val)
   }
 
+  /// Set the policy used to select the client that runs the next command.
+  ///
+  /// The default policy is [RoundRobin](crate::types::RoundRobin). See the
+  /// [PoolRoutingPolicy](crate::types::PoolRoutingPolicy) implementations for other options.
+  ///
+  /// ```rust
+  /// # use fred::prelude::*;
+  /// # use fred::types::LeastInFlight;
+  /// # use std::sync::Arc;
+  /// fn example(pool: &RedisPool) {
+  ///   pool.set_routing_policy(Arc::new(LeastInFlight));
+  /// }
+  /// ```
+  pub fn set_routing_policy(&self, policy: Arc<dyn PoolRoutingPolicy>) {
+    let mut routing = self.inner.routing.write();
+    #[cfg(feature = "metrics")]
+    for client in self.inner.clients().iter() {
+      utils::set_bool_atomic(&client.inner.sample_latency, policy.uses_latency());
+    }
+    *routing = policy;
+  }
+
   /// Read the individual clients in the pool.
+  ///
+  /// Elastic pools may replace unhealthy clients after this is called. See [pool_event_rx](Self::pool_event_rx) to
+  /// observe these changes.
   pub fn clients(&self) -> &[RedisClient] {
-    &self.inner.clients
+    self.inner.active()
   }
 
   /// Connect each client to the server, returning the task driving each connection.
   ///
   /// Use the base [connect](Self::connect) function to return one handle that drives all connections via [join](https://docs.rs/futures/latest/futures/macro.join.html).
   pub fn connect_pool(&self) -> Vec<ConnectHandle> {
-    self.inner.clients.iter().map(|c| c.connect()).collect()
+    self.spawn_monitor();
+    self
+      .inner
+      .active()
+      .iter()
+      .enumerate()
+      .map(|(idx, client)| {
+        self.inner.forward_events(idx);
+        client.connect()
+      })
+      .collect()
   }
 
   /// Read the size of the pool.
   pub fn size(&self) -> usize {
-    self.inner.clients.len()
+    utils::read_atomic(&self.inner.active)
+  }
+
+  /// Read the maximum size of the pool.
+  pub fn max_size(&self) -> usize {
+    self.inner.clients().len()
+  }
+
+  /// Listen for notifications whenever clients are added, removed, or replaced in the pool.
+  pub fn pool_event_rx(&self) -> BroadcastReceiver<PoolEvent> {
+    self.inner.events.subscribe()
+  }
+
+  /// Change the number of clients in the pool, connecting or disconnecting clients as needed.
+  ///
+  /// The new size must be between 1 and [max_size](Self::max_size). This function waits for any new clients to
+  /// connect.
+  pub async fn resize(&self, size: usize) -> Result<(), RedisError> {
+    if size == 0 || size > self.inner.clients().len() {
+      return Err(RedisError::new(
+        RedisErrorKind::Config,
+        format!("Pool size must be between 1 and {}.", self.inner.clients().len()),
+      ));
+    }
+    let _guard = self.inner.resize_lock.lock().await;
+    let current = self.size();
+
+    if size > current {
+      let added = &self.inner.clients()[current .. size];
+      let rxs: Vec<_> = added.iter().map(|c| c.wait_for_connect()).collect();
+      for (idx, client) in added.iter().enumerate() {
+        self.inner.forward_events(current + idx);
+        client.inner.reset_reconnection_attempts();
+        client.connect();
+      }
+
+      if let Some(err) = join_all(rxs).await.into_iter().find_map(|r| r.err()) {
+        for client in added.iter() {
+          utils::reset_router_task(client.inner());
+        }
+        return Err(err);
+      }
+
+      utils::set_atomic(&self.inner.active, size);
+      for client in added.iter() {
+        self.inner.broadcast(PoolEvent::Add(client.inner.id.clone()));
+      }
+    } else if size < current {
+      utils::set_atomic(&self.inner.active, size);
+      let removed = &self.inner.clients()[size .. current];
+      let _ = join_all(removed.iter().map(|c| c.quit())).await;
+
+      for client in removed.iter() {
+        self.inner.broadcast(PoolEvent::Remove(client.inner.id.clone()));
+      }
+    }
+
+    Ok(())
   }
 
   /// Read the next connected client that should run the next command.
+  ///
+  /// If the client selected by the routing policy is not connected the pool will use the next connected client
+  /// after it.
   pub fn next_connected(&self) -> &RedisClient {
-    let mut idx = utils::incr_atomic(&self.inner.counter) % self.inner.clients.len();
+    let clients = self.inner.active();
+    let mut idx = self.select(clients);
 
-    for _ in 0 .. self.inner.clients.len() {
-      let client = &self.inner.clients[idx];
+    for _ in 0 .. clients.len() {
+      let client = &clients[idx];
       if client.is_connected() {
+        utils::set_atomic(&self.inner.last, idx);
         return client;
       }
-      idx = (idx + 1) % self.inner.clients.len();
+      idx = (idx + 1) % clients.len();
     }
 
-    &self.inner.clients[idx]
+    &clients[idx]
   }
 
   /// Read the client that should run the next command.
   pub fn next(&self) -> &RedisClient {
-    &self.inner.clients[utils::incr_atomic(&self.inner.counter) % self.inner.clients.len()]
+    let clients = self.inner.active();
+    &clients[self.select(clients)]
   }
 
   /// Read the client that ran the last command.
   pub fn last(&self) -> &RedisClient {
-    &self.inner.clients[utils::read_atomic(&self.inner.counter) % self.inner.clients.len()]
+    let clients = self.inner.active();
+    &clients[utils::read_atomic(&self.inner.last) % clients.len()]
+  }
+
+  /// Select the index of the next client with the routing policy.
+  fn select(&self, clients: &[RedisClient]) -> usize {
+    let idx = self.inner.routing.read().select(clients) % clients.len();
+    utils::set_atomic(&self.inner.last, idx);
+    idx
+  }
+
+  /// Start the task that resizes the pool and replaces unhealthy clients, if the pool is elastic.
+  fn spawn_monitor(&self) {
+    let config = match self.inner.elastic {
+      Some(ref config) => config.clone(),
+      None => return,
+    };
+    let pool = Arc::downgrade(&self.inner);
+
+    let task = tokio::spawn(async move {
+      let mut interval = tokio_interval(config.check_interval);
+      let mut disconnected = vec![None; config.max_size];
+
+      loop {
+        interval.tick().await;
+        match Weak::upgrade(&pool) {
+          Some(inner) => {
+            let pool = RedisPool { inner };
+            pool.replace_unhealthy(&config, &mut disconnected).await;
+            pool.resize_from_load(&config).await;
+          },
+          None => break,
+        }
+      }
+    });
+
+    if let Some(old) = self.inner.monitor.lock().replace(task) {
+      old.abort();
+    }
+  }
+
+  /// Replace the clients that have been disconnected longer than `unhealthy_timeout` with new clients.
+  async fn replace_unhealthy(&self, config: &ElasticPoolConfig, disconnected: &mut [Option<Instant>]) {
+    for (idx, client) in self.inner.clients().iter().enumerate() {
+      if idx >= self.size() || client.state() != ClientState::Disconnected {
+        disconnected[idx] = None;
+        continue;
+      }
+
+      let since = *disconnected[idx].get_or_insert_with(Instant::now);
+      if !config.unhealthy_timeout.is_zero() && since.elapsed() >= config.unhealthy_timeout {
+        disconnected[idx] = None;
+
+        let inner = client.inner();
+        _debug!(inner, "Replacing unhealthy client in pool.");
+        match self.replace(idx).await {
+          Ok(new_id) => self
+            .inner
+            .broadcast(PoolEvent::Replace(client.inner.id.clone(), new_id)),
+          Err(error) => _warn!(inner, "Failed to replace unhealthy client: {:?}", error),
+        };
+      }
+    }
+  }
+
+  /// Connect a new client with the config of the client at `idx`, swap it into the pool, then close the old client.
+  ///
+  /// Returns the ID of the new client.
+  async fn replace(&self, idx: usize) -> Result<Str, RedisError> {
+    let _guard = self.inner.resize_lock.lock().await;
+    let old = self.inner.clients()[idx].clone();
+    if idx >= self.size() || old.state() != ClientState::Disconnected {
+      return Err(RedisError::new(RedisErrorKind::Canceled, "Client reconnected."));
+    }
+
+    let timeout = old.inner().internal_command_timeout();
+    let client = old.clone_new();
+    let forwarder = self.inner.spawn_forwarder(&client);
+    if let Err(error) = utils::apply_timeout(client.init(), timeout).await {
+      utils::reset_router_task(client.inner());
+      forwarder.abort();
+      return Err(error);
+    }
+
+    let mut clients = self.inner.clients().to_vec();
+    clients[idx] = client.clone();
+    {
+      // hold the routing lock so a concurrent policy change also applies to the new client
+      let routing = self.inner.routing.read();
+      #[cfg(feature = "metrics")]
+      utils::set_bool_atomic(&client.inner.sample_latency, routing.uses_latency());
+      #[cfg(not(feature = "metrics"))]
+      let _ = routing;
+      self.inner.clients.push(clients);
+    }
+    if let Some(old) = self.inner.forwarders.lock()[idx].replace(forwarder) {
+      old.abort();
+    }
+
+    // the old connection task may be waiting to reconnect, so QUIT may not receive a response
+    let _ = utils::apply_timeout(old.quit(), timeout).await;
+    Ok(client.inner.id.clone())
+  }
+
+  /// Add or remove a client based on the average number of in-flight commands per client.
+  async fn resize_from_load(&self, config: &ElasticPoolConfig) {
+    let clients = self.inner.active();
+    let load = clients.iter().map(|c| c.command_queue_len()).sum::<usize>() / clients.len();
+
+    let size = if load >= config.scale_up_threshold && clients.len() < config.max_size {
+      clients.len() + 1
+    } else if load <= config.scale_down_threshold && clients.len() > config.min_size {
+      clients.len() - 1
+    } else {
+      return;
+    };
+
+    if let Err(error) = self.resize(size).await {
+      let inner = self.inner.clients()[0].inner();
+      _warn!(inner, "Failed to resize pool: {:?}", error);
+    }
+  }
+
+  /// Check out a client with its own connection(s) that it owns exclusively until dropped.
+  ///
+  /// Idle dedicated connections are reused if possible, otherwise a new connection is created from the config of
+  /// the [next](Self::next) client.
+  ///
+  /// This function will wait if
+  /// [max_dedicated_connections](crate::types::ConnectionConfig::max_dedicated_connections) dedicated clients are
+  /// already checked out from the pool. See [DedicatedClient] for more information.
+  pub async fn acquire(&self) -> Result<DedicatedClient, RedisError> {
+    dedicated::acquire(&self.inner.dedicated, self.next()).await
   }
 
   /// Create a client that interacts with the replica nodes associated with the [next](Self::next) client.
@@ -175,14 +682,14 @@ impl ClientLike for RedisPool {
   /// Update the internal [PerformanceConfig](crate::types::PerformanceConfig) on each client in place with new
   /// values.
   fn update_perf_config(&self, config: PerformanceConfig) {
-    for client in self.inner.clients.iter() {
+    for client in self.inner.clients().iter() {
       client.update_perf_config(config.clone());
     }
   }
 
   /// Read the set of active connections across all clients in the pool.
   async fn active_connections(&self) -> Result<Vec<Server>, RedisError> {
-    let all_connections = try_join_all(self.inner.clients.iter().map(|c| c.active_connections())).await?;
+    let all_connections = try_join_all(self.inner.active().iter().map(|c| c.active_connections())).await?;
     let total_size = if all_connections.is_empty() {
       return Ok(Vec::new());
     } else {
@@ -200,7 +707,7 @@ impl ClientLike for RedisPool {
   #[cfg(feature = "dns")]
   #[cfg_attr(docsrs, doc(cfg(feature = "dns")))]
   async fn set_resolver(&self, resolver: Arc<dyn Resolve>) {
-    for client in self.inner.clients.iter() {
+    for client in self.inner.clients().iter() {
       client.set_resolver(resolver.clone()).await;
     }
   }
@@ -214,7 +721,11 @@ impl ClientLike for RedisPool {
   ///
   /// See [init](Self::init) for an alternative shorthand.
   fn connect(&self) -> ConnectHandle {
-    let clients = self.inner.clients.clone();
+    self.spawn_monitor();
+    let clients = self.inner.active().to_vec();
+    for idx in 0 .. clients.len() {
+      self.inner.forward_events(idx);
+    }
     tokio::spawn(async move {
       let tasks: Vec<_> = clients.iter().map(|c| c.connect()).collect();
       for result in join_all(tasks).await.into_iter() {
@@ -229,14 +740,14 @@ impl ClientLike for RedisPool {
   ///
   /// When running against a cluster this function will also refresh the cached cluster routing table.
   async fn force_reconnection(&self) -> RedisResult<()> {
-    let _ = try_join_all(self.inner.clients.iter().map(|c| c.force_reconnection())).await?;
+    let _ = try_join_all(self.inner.active().iter().map(|c| c.force_reconnection())).await?;
 
     Ok(())
   }
 
   /// Wait for all the clients to connect to the server.
   async fn wait_for_connect(&self) -> RedisResult<()> {
-    let _ = try_join_all(self.inner.clients.iter().map(|c| c.wait_for_connect())).await?;
+    let _ = try_join_all(self.inner.active().iter().map(|c| c.wait_for_connect())).await?;
 
     Ok(())
   }
@@ -264,13 +775,13 @@ impl ClientLike for RedisPool {
   /// }
   /// ```
   async fn init(&self) -> RedisResult<ConnectHandle> {
-    let rxs: Vec<_> = self.inner.clients.iter().map(|c| c.wait_for_connect()).collect();
+    let rxs: Vec<_> = self.inner.active().iter().map(|c| c.wait_for_connect()).collect();
 
     let connect_task = self.connect();
     let init_err = futures::future::join_all(rxs).await.into_iter().find_map(|r| r.err());
 
     if let Some(err) = init_err {
-      for client in self.inner.clients.iter() {
+      for client in self.inner.active().iter() {
         utils::reset_router_task(client.inner());
       }
 
@@ -288,7 +799,16 @@ impl ClientLike for RedisPool {
   /// This function will also close all error, pubsub message, and reconnection event streams on all clients in the
   /// pool.
   async fn quit(&self) -> RedisResult<()> {
-    let _ = join_all(self.inner.clients.iter().map(|c| c.quit())).await;
+    if let Some(task) = self.inner.monitor.lock().take() {
+      task.abort();
+    }
+    let _ = join_all(self.inner.active().iter().map(|c| c.quit())).await;
+    self.inner.quit_subscriber().await;
+    self.inner.dedicated.close_idle().await;
+    let capacity = self.inner.clients()[0]
+      .inner
+      .with_perf_config(|c| c.broadcast_channel_capacity);
+    self.inner.notifications.close_public_receivers(capacity);
 
     Ok(())
   }
@@ -302,7 +822,7 @@ impl HeartbeatInterface for RedisPool {
     loop {
       interval.tick().await;
 
-      if let Err(error) = try_join_all(self.inner.clients.iter().map(|c| c.ping::<()>())).await {
+      if let Err(error) = try_join_all(self.inner.active().iter().map(|c| c.ping::<()>())).await {
         if break_on_error {
           return Err(error);
         }
@@ -311,13 +831,162 @@ impl HeartbeatInterface for RedisPool {
   }
 }
 
+impl EventInterface for RedisPool {
+  fn message_rx(&self) -> BroadcastReceiver<Message> {
+    self.inner.notifications.pubsub.load().subscribe()
+  }
+
+  fn keyspace_event_rx(&self) -> BroadcastReceiver<KeyspaceEvent> {
+    self.inner.notifications.keyspace.load().subscribe()
+  }
+
+  fn reconnect_rx(&self) -> BroadcastReceiver<Server> {
+    self.inner.notifications.reconnect.load().subscribe()
+  }
+
+  fn cluster_change_rx(&self) -> BroadcastReceiver<Vec<ClusterStateChange>> {
+    self.inner.notifications.cluster_change.load().subscribe()
+  }
+
+  fn error_rx(&self) -> BroadcastReceiver<RedisError> {
+    self.inner.notifications.errors.load().subscribe()
+  }
+
+  fn unresponsive_rx(&self) -> BroadcastReceiver<Server> {
+    self.inner.notifications.unresponsive.load().subscribe()
+  }
+}
+
+impl MetricsInterface for RedisPool {
+  fn read_redelivery_count(&self) -> usize {
+    self.inner.active().iter().map(|c| c.read_redelivery_count()).sum()
+  }
+
+  fn take_redelivery_count(&self) -> usize {
+    self.inner.active().iter().map(|c| c.take_redelivery_count()).sum()
+  }
+
+  fn command_queue_len(&self) -> usize {
+    self.inner.active().iter().map(|c| c.command_queue_len()).sum()
+  }
+
+  #[cfg(feature = "metrics")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+  fn read_latency_metrics(&self) -> Stats {
+    Stats::merge(self.inner.active().iter().map(|c| c.read_latency_metrics()).collect())
+  }
+
+  #[cfg(feature = "metrics")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+  fn take_latency_metrics(&self) -> Stats {
+    Stats::merge(self.inner.active().iter().map(|c| c.take_latency_metrics()).collect())
+  }
+
+  #[cfg(feature = "metrics")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+  fn read_network_latency_metrics(&self) -> Stats {
+    Stats::merge(
+      self
+        .inner
+        .active()
+        .iter()
+        .map(|c| c.read_network_latency_metrics())
+        .collect(),
+    )
+  }
+
+  #[cfg(feature = "metrics")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+  fn take_network_latency_metrics(&self) -> Stats {
+    Stats::merge(
+      self
+        .inner
+        .active()
+        .iter()
+        .map(|c| c.take_network_latency_metrics())
+        .collect(),
+    )
+  }
+
+  #[cfg(feature = "metrics")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+  fn read_req_size_metrics(&self) -> Stats {
+    Stats::merge(self.inner.active().iter().map(|c| c.read_req_size_metrics()).collect())
+  }
+
+  #[cfg(feature = "metrics")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+  fn take_req_size_metrics(&self) -> Stats {
+    Stats::merge(self.inner.active().iter().map(|c| c.take_req_size_metrics()).collect())
+  }
+
+  #[cfg(feature = "metrics")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+  fn read_res_size_metrics(&self) -> Stats {
+    Stats::merge(self.inner.active().iter().map(|c| c.read_res_size_metrics()).collect())
+  }
+
+  #[cfg(feature = "metrics")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+  fn take_res_size_metrics(&self) -> Stats {
+    Stats::merge(self.inner.active().iter().map(|c| c.take_res_size_metrics()).collect())
+  }
+}
+
+#[async_trait]
+impl PubsubInterface for RedisPool {
+  async fn subscribe<S>(&self, channels: S) -> RedisResult<()>
+  where
+    S: Into<MultipleStrings> + Send,
+  {
+    self.inner.subscriber().await?.subscribe(channels).await
+  }
+
+  async fn unsubscribe<S>(&self, channels: S) -> RedisResult<()>
+  where
+    S: Into<MultipleStrings> + Send,
+  {
+    self.inner.subscriber().await?.unsubscribe(channels).await
+  }
+
+  async fn psubscribe<S>(&self, patterns: S) -> RedisResult<()>
+  where
+    S: Into<MultipleStrings> + Send,
+  {
+    self.inner.subscriber().await?.psubscribe(patterns).await
+  }
+
+  async fn punsubscribe<S>(&self, patterns: S) -> RedisResult<()>
+  where
+    S: Into<MultipleStrings> + Send,
+  {
+    self.inner.subscriber().await?.punsubscribe(patterns).await
+  }
+
+  async fn ssubscribe<C>(&self, channels: C) -> RedisResult<()>
+  where
+    C: Into<MultipleStrings> + Send,
+  {
+    self.inner.subscriber().await?.ssubscribe(channels).await
+  }
+
+  async fn sunsubscribe<C>(&self, channels: C) -> RedisResult<()>
+  where
+    C: Into<MultipleStrings> + Send,
+  {
+    self.inner.subscriber().await?.sunsubscribe(channels).await
+  }
+}
+
 impl AclInterface for RedisPool {}
+impl BitmapInterface for RedisPool {}
 impl ClusterInterface for RedisPool {}
 impl ConfigInterface for RedisPool {}
 impl GeoInterface for RedisPool {}
 impl HashesInterface for RedisPool {}
 impl HyperloglogInterface for RedisPool {}
 impl KeysInterface for RedisPool {}
+impl StringsInterface for RedisPool {}
 impl LuaInterface for RedisPool {}
 impl ListInterface for RedisPool {}
 impl MemoryInterface for RedisPool {}
@@ -334,3 +1003,49 @@ impl RedisJsonInterface for RedisPool {}
 #[cfg(feature = "time-series")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time-series")))]
 impl TimeSeriesInterface for RedisPool {}
+#[cfg(feature = "redis-bloom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+impl RedisBloomInterface for RedisPool {}
+#[cfg(feature = "redis-search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+impl RediSearchInterface for RedisPool {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_read_latest_client_generation() {
+    let clients: Vec<_> = (0 .. 3).map(|_| RedisClient::default()).collect();
+    let generations = ClientGenerations::new(clients.clone());
+    let first = generations.current();
+
+    for _ in 0 .. 10 {
+      let mut clients = generations.current().to_vec();
+      clients[1] = RedisClient::default();
+      generations.push(clients);
+    }
+
+    assert_eq!(utils::read_atomic(&generations.len), 11);
+    assert_eq!(first[1].id(), clients[1].id());
+    assert_ne!(generations.current()[1].id(), clients[1].id());
+    assert_eq!(generations.current()[2].id(), clients[2].id());
+  }
+
+  #[test]
+  #[cfg(feature = "metrics")]
+  fn should_sample_latency_with_latency_policy() {
+    let pool = RedisPool::new(RedisConfig::default(), None, None, None, 2).unwrap();
+    assert!(!utils::read_bool_atomic(&pool.inner.clients()[0].inner.sample_latency));
+
+    pool.set_routing_policy(Arc::new(crate::types::LowestLatency));
+    assert!(pool
+      .inner
+      .clients()
+      .iter()
+      .all(|client| utils::read_bool_atomic(&client.inner.sample_latency)));
+
+    pool.set_routing_policy(Arc::new(crate::types::LeastInFlight));
+    assert!(!utils::read_bool_atomic(&pool.inner.clients()[1].inner.sample_latency));
+  }
+}
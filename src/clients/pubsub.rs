@@ -2,12 +2,26 @@ use crate::{
   commands,
   error::RedisError,
   interfaces::*,
-  modules::inner::RedisClientInner,
+  modules::{
+    inner::RedisClientInner,
+    subscriptions::{StreamBuffer, StreamKey},
+  },
   prelude::RedisClient,
-  types::{ConnectionConfig, MultipleStrings, PerformanceConfig, ReconnectPolicy, RedisConfig, RedisKey},
+  types::{
+    ConnectionConfig,
+    LagPolicy,
+    Message,
+    MessageKind,
+    MultipleStrings,
+    PerformanceConfig,
+    ReconnectPolicy,
+    RedisConfig,
+    RedisKey,
+  },
   util::group_by_hash_slot,
 };
 use bytes_utils::Str;
+use futures::{stream, Stream};
 use parking_lot::RwLock;
 use std::{collections::BTreeSet, fmt, fmt::Formatter, mem, sync::Arc};
 use tokio::task::JoinHandle;
@@ -17,6 +31,54 @@ use crate::interfaces::TrackingInterface;
 
 type ChannelSet = Arc<RwLock<BTreeSet<Str>>>;
 
+/// Removes the stream buffer when the stream is dropped, unsubscribing if it was the last stream for the channel.
+struct StreamGuard {
+  client: SubscriberClient,
+  key:    StreamKey,
+  buffer: Arc<StreamBuffer>,
+}
+
+impl Drop for StreamGuard {
+  fn drop(&mut self) {
+    self.buffer.close();
+    if !self
+      .client
+      .inner
+      .notifications
+      .subscriptions
+      .remove(&self.key, &self.buffer)
+    {
+      return;
+    }
+
+    let (client, (kind, name)) = (self.client.clone(), self.key.clone());
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+      handle.spawn(async move {
+        // a new stream may have subscribed to the same channel since the buffer was removed
+        if client
+          .inner
+          .notifications
+          .subscriptions
+          .contains(&(kind.clone(), name.clone()))
+        {
+          return;
+        }
+
+        let result = match kind {
+          MessageKind::Message => client.unsubscribe(name).await,
+          MessageKind::PMessage => client.punsubscribe(name).await,
+          MessageKind::SMessage => client.sunsubscribe(name).await,
+        };
+
+        if let Err(error) = result {
+          let inner = client.inner();
+          _debug!(inner, "Failed to unsubscribe after dropping stream: {:?}", error);
+        }
+      });
+    }
+  }
+}
+
 /// A subscriber client that will manage subscription state to any [pubsub](https://redis.io/docs/manual/pubsub/) channels or patterns for the caller.
 ///
 /// If the connection to the server closes for any reason this struct can automatically re-subscribe to channels,
@@ -84,6 +146,7 @@ impl ClientLike for SubscriberClient {
 
 impl EventInterface for SubscriberClient {}
 impl AclInterface for SubscriberClient {}
+impl BitmapInterface for SubscriberClient {}
 impl ClientInterface for SubscriberClient {}
 impl ClusterInterface for SubscriberClient {}
 impl ConfigInterface for SubscriberClient {}
@@ -93,6 +156,8 @@ impl HyperloglogInterface for SubscriberClient {}
 impl MetricsInterface for SubscriberClient {}
 impl TransactionInterface for SubscriberClient {}
 impl KeysInterface for SubscriberClient {}
+impl KeyspaceNotificationsInterface for SubscriberClient {}
+impl StringsInterface for SubscriberClient {}
 impl LuaInterface for SubscriberClient {}
 impl ListInterface for SubscriberClient {}
 impl MemoryInterface for SubscriberClient {}
@@ -110,6 +175,12 @@ impl RedisJsonInterface for SubscriberClient {}
 #[cfg(feature = "time-series")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time-series")))]
 impl TimeSeriesInterface for SubscriberClient {}
+#[cfg(feature = "redis-bloom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+impl RedisBloomInterface for SubscriberClient {}
+#[cfg(feature = "redis-search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+impl RediSearchInterface for SubscriberClient {}
 
 #[cfg(feature = "client-tracking")]
 #[cfg_attr(docsrs, doc(cfg(feature = "client-tracking")))]
@@ -364,6 +435,100 @@ impl SubscriberClient {
     Ok(())
   }
 
+  /// Subscribe to a channel, returning a stream of the messages on that channel.
+  ///
+  /// Each stream buffers up to `capacity` messages, applying the `policy` when the buffer is full. The channel is
+  /// tracked like [subscribe](crate::interfaces::PubsubInterface::subscribe), and the client will `UNSUBSCRIBE`
+  /// from the channel when the last stream for that channel is dropped.
+  ///
+  /// Messages are also sent to [message_rx](crate::interfaces::EventInterface::message_rx).
+  ///
+  /// ```rust no_run
+  /// # use fred::{prelude::*, clients::SubscriberClient, types::LagPolicy};
+  /// # use futures::StreamExt;
+  /// async fn example(subscriber: &SubscriberClient) -> Result<(), RedisError> {
+  ///   let mut stream = subscriber.subscribe_stream("foo", 1024, LagPolicy::DropOldest).await?;
+  ///   while let Some(message) = stream.next().await {
+  ///     println!("Recv message {:?}", message?.value);
+  ///   }
+  ///   Ok(())
+  /// }
+  /// ```
+  pub async fn subscribe_stream<S>(
+    &self,
+    channel: S,
+    capacity: usize,
+    policy: LagPolicy,
+  ) -> Result<impl Stream<Item = Result<Message, RedisError>> + Unpin, RedisError>
+  where
+    S: Into<Str>,
+  {
+    self
+      .subscription_stream(MessageKind::Message, channel.into(), capacity, policy)
+      .await
+  }
+
+  /// Subscribe to a channel pattern, returning a stream of the messages that match the pattern.
+  ///
+  /// See [subscribe_stream](Self::subscribe_stream) for more information.
+  pub async fn psubscribe_stream<S>(
+    &self,
+    pattern: S,
+    capacity: usize,
+    policy: LagPolicy,
+  ) -> Result<impl Stream<Item = Result<Message, RedisError>> + Unpin, RedisError>
+  where
+    S: Into<Str>,
+  {
+    self
+      .subscription_stream(MessageKind::PMessage, pattern.into(), capacity, policy)
+      .await
+  }
+
+  /// Subscribe to a shard channel, returning a stream of the messages on that channel.
+  ///
+  /// See [subscribe_stream](Self::subscribe_stream) for more information.
+  pub async fn ssubscribe_stream<S>(
+    &self,
+    channel: S,
+    capacity: usize,
+    policy: LagPolicy,
+  ) -> Result<impl Stream<Item = Result<Message, RedisError>> + Unpin, RedisError>
+  where
+    S: Into<Str>,
+  {
+    self
+      .subscription_stream(MessageKind::SMessage, channel.into(), capacity, policy)
+      .await
+  }
+
+  async fn subscription_stream(
+    &self,
+    kind: MessageKind,
+    name: Str,
+    capacity: usize,
+    policy: LagPolicy,
+  ) -> Result<impl Stream<Item = Result<Message, RedisError>> + Unpin, RedisError> {
+    let buffer = Arc::new(StreamBuffer::new(capacity, policy));
+    let guard = StreamGuard {
+      client: self.clone(),
+      key:    (kind.clone(), name.clone()),
+      buffer: buffer.clone(),
+    };
+    // add the buffer first so messages that arrive right after the subscription are not missed
+    self.inner.notifications.subscriptions.add(guard.key.clone(), buffer);
+
+    match kind {
+      MessageKind::Message => self.subscribe(name).await?,
+      MessageKind::PMessage => self.psubscribe(name).await?,
+      MessageKind::SMessage => self.ssubscribe(name).await?,
+    };
+
+    Ok(Box::pin(stream::unfold(guard, |guard| async move {
+      guard.buffer.recv().await.map(|item| (item, guard))
+    })))
+  }
+
   /// Create a new `RedisClient`, reusing the existing connection(s).
   ///
   /// Note: most non-pubsub commands are only supported when using RESP3.
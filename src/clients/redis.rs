@@ -1,6 +1,6 @@
 use crate::{
-  clients::{Pipeline, WithOptions},
-  commands,
+  clients::{dedicated, DedicatedClient, Pipeline, WithOptions},
+  commands::{self, scan::BulkOperation},
   error::{RedisError, RedisErrorKind},
   interfaces::*,
   modules::inner::RedisClientInner,
@@ -15,6 +15,13 @@ use std::{fmt, fmt::Formatter, sync::Arc};
 use crate::clients::Replicas;
 #[cfg(feature = "client-tracking")]
 use crate::interfaces::TrackingInterface;
+#[cfg(feature = "transactions")]
+use crate::{
+  clients::{transaction, Transaction},
+  utils,
+};
+#[cfg(feature = "transactions")]
+use std::future::Future;
 
 /// A cheaply cloneable Redis client struct.
 #[derive(Clone)]
@@ -59,6 +66,7 @@ impl ClientLike for RedisClient {
 
 impl EventInterface for RedisClient {}
 impl AclInterface for RedisClient {}
+impl BitmapInterface for RedisClient {}
 impl ClientInterface for RedisClient {}
 impl ClusterInterface for RedisClient {}
 impl PubsubInterface for RedisClient {}
@@ -71,6 +79,8 @@ impl MetricsInterface for RedisClient {}
 #[cfg_attr(docsrs, doc(cfg(feature = "transactions")))]
 impl TransactionInterface for RedisClient {}
 impl KeysInterface for RedisClient {}
+impl KeyspaceNotificationsInterface for RedisClient {}
+impl StringsInterface for RedisClient {}
 impl LuaInterface for RedisClient {}
 impl ListInterface for RedisClient {}
 impl MemoryInterface for RedisClient {}
@@ -88,6 +98,12 @@ impl RedisJsonInterface for RedisClient {}
 #[cfg(feature = "time-series")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time-series")))]
 impl TimeSeriesInterface for RedisClient {}
+#[cfg(feature = "redis-bloom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+impl RedisBloomInterface for RedisClient {}
+#[cfg(feature = "redis-search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+impl RediSearchInterface for RedisClient {}
 #[cfg(feature = "client-tracking")]
 #[cfg_attr(docsrs, doc(cfg(feature = "client-tracking")))]
 impl TrackingInterface for RedisClient {}
@@ -159,7 +175,24 @@ impl RedisClient {
   where
     P: Into<Str>,
   {
-    commands::scan::scan(&self.inner, pattern.into(), count, r#type, None)
+    commands::scan::scan(&self.inner, pattern.into(), count, r#type, None, None)
+  }
+
+  /// Resume a [scan](Self::scan) operation from a [checkpoint](crate::types::ScanCheckpoint).
+  ///
+  /// The stream closes without any results if the scan already finished, and returns an error if the checkpoint
+  /// came from [scan_cluster](Self::scan_cluster).
+  pub fn scan_from<P>(
+    &self,
+    pattern: P,
+    count: Option<u32>,
+    r#type: Option<ScanType>,
+    checkpoint: ScanCheckpoint,
+  ) -> impl Stream<Item = Result<ScanResult, RedisError>>
+  where
+    P: Into<Str>,
+  {
+    commands::scan::scan(&self.inner, pattern.into(), count, r#type, None, Some(checkpoint))
   }
 
   /// Run the `SCAN` command on each primary/main node in a cluster concurrently.
@@ -180,7 +213,154 @@ impl RedisClient {
   where
     P: Into<Str>,
   {
-    commands::scan::scan_cluster(&self.inner, pattern.into(), count, r#type)
+    commands::scan::scan_cluster(&self.inner, pattern.into(), count, r#type, None, ScanOptions::default())
+  }
+
+  /// Resume a [scan_cluster](Self::scan_cluster) operation from a [checkpoint](crate::types::ScanCheckpoint).
+  ///
+  /// Only nodes with an unfinished scan in the checkpoint are scanned. The stream returns an error if the primary
+  /// nodes in the cluster changed since the checkpoint was created, in which case callers should start a new scan.
+  pub fn scan_cluster_from<P>(
+    &self,
+    pattern: P,
+    count: Option<u32>,
+    r#type: Option<ScanType>,
+    checkpoint: ScanCheckpoint,
+  ) -> impl Stream<Item = Result<ScanResult, RedisError>>
+  where
+    P: Into<Str>,
+  {
+    commands::scan::scan_cluster(
+      &self.inner,
+      pattern.into(),
+      count,
+      r#type,
+      Some(checkpoint),
+      ScanOptions::default(),
+    )
+  }
+
+  /// Run the `SCAN` command on each primary/main node in a cluster, optionally resuming from a
+  /// [checkpoint](crate::types::ScanCheckpoint), with [options](crate::types::ScanOptions) that limit the load on the
+  /// cluster.
+  ///
+  /// See [scan_cluster](Self::scan_cluster) and [scan_cluster_from](Self::scan_cluster_from) for more information.
+  ///
+  /// ```rust no_run
+  /// # use fred::prelude::*;
+  /// # use fred::types::{ScanOptions, Scanner};
+  /// # use futures::TryStreamExt;
+  /// async fn example(client: &RedisClient) -> Result<(), RedisError> {
+  ///   let options = ScanOptions {
+  ///     max_concurrency: Some(2),
+  ///     max_pages_per_second: Some(10),
+  ///     ..Default::default()
+  ///   };
+  ///   let handle = options.handle.clone();
+  ///
+  ///   let mut scan_stream = client.scan_cluster_with_options("foo*", Some(100), None, None, options);
+  ///   while let Some(mut page) = scan_stream.try_next().await? {
+  ///     // call `handle.pause()` and `handle.resume()` to stop and restart the scan from another task
+  ///     let _keys = page.take_results();
+  ///     page.next()?;
+  ///   }
+  ///   Ok(())
+  /// }
+  /// ```
+  pub fn scan_cluster_with_options<P>(
+    &self,
+    pattern: P,
+    count: Option<u32>,
+    r#type: Option<ScanType>,
+    checkpoint: Option<ScanCheckpoint>,
+    options: ScanOptions,
+  ) -> impl Stream<Item = Result<ScanResult, RedisError>>
+  where
+    P: Into<Str>,
+  {
+    commands::scan::scan_cluster(&self.inner, pattern.into(), count, r#type, checkpoint, options)
+  }
+
+  /// Unlink the keys matching `pattern`, returning the number of keys that were scanned and removed.
+  ///
+  /// Keys are scanned on the server, or on each primary node in a cluster, and removed with a
+  /// [pipeline](Self::pipeline) of `UNLINK` commands after each page. See [BulkOptions](crate::types::BulkOptions) to
+  /// configure the batch size, report progress, or scan the keys without removing them.
+  ///
+  /// ```rust no_run
+  /// # use fred::prelude::*;
+  /// # use fred::types::{BulkOptions, BulkProgress};
+  /// async fn example(client: &RedisClient) -> Result<(), RedisError> {
+  ///   let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<BulkProgress>();
+  ///   tokio::spawn(async move {
+  ///     while let Some(progress) = rx.recv().await {
+  ///       println!("Removed {} of {} keys", progress.processed, progress.scanned);
+  ///     }
+  ///   });
+  ///
+  ///   let options = BulkOptions {
+  ///     batch_size: 500,
+  ///     progress: Some(tx),
+  ///     ..Default::default()
+  ///   };
+  ///   let progress = client.unlink_matching("session:*", options).await?;
+  ///   println!("Removed {} keys", progress.processed);
+  ///   Ok(())
+  /// }
+  /// ```
+  pub async fn unlink_matching<P>(&self, pattern: P, options: BulkOptions) -> Result<BulkProgress, RedisError>
+  where
+    P: Into<Str>,
+  {
+    commands::scan::process_matching(self, pattern.into(), options, BulkOperation::Unlink).await
+  }
+
+  /// Set a timeout of `seconds` on the keys matching `pattern`, returning the number of keys that were scanned and
+  /// updated.
+  ///
+  /// See [unlink_matching](Self::unlink_matching) for more information.
+  pub async fn expire_matching<P>(
+    &self,
+    pattern: P,
+    seconds: i64,
+    options: BulkOptions,
+  ) -> Result<BulkProgress, RedisError>
+  where
+    P: Into<Str>,
+  {
+    commands::scan::process_matching(self, pattern.into(), options, BulkOperation::Expire(seconds)).await
+  }
+
+  /// Copy the keys matching `pattern` to `destination` with `DUMP` and `RESTORE`, returning the number of keys that
+  /// were scanned and copied.
+  ///
+  /// The remaining TTL on each key is preserved. Keys that already exist on `destination` are skipped unless
+  /// `replace` is `true`. See [unlink_matching](Self::unlink_matching) for more information.
+  pub async fn copy_matching<P>(
+    &self,
+    pattern: P,
+    destination: &RedisClient,
+    replace: bool,
+    options: BulkOptions,
+  ) -> Result<BulkProgress, RedisError>
+  where
+    P: Into<Str>,
+  {
+    let operation = BulkOperation::Copy { destination, replace };
+    commands::scan::process_matching(self, pattern.into(), options, operation).await
+  }
+
+  /// Serialize the keys matching `pattern` with `DUMP`.
+  ///
+  /// The results are buffered in memory, so callers should prefer [copy_matching](Self::copy_matching) or a smaller
+  /// pattern for large keyspaces. See [unlink_matching](Self::unlink_matching) for more information.
+  pub async fn dump_matching<P>(&self, pattern: P, options: BulkOptions) -> Result<Vec<KeyDump>, RedisError>
+  where
+    P: Into<Str>,
+  {
+    let mut dumps = Vec::new();
+    commands::scan::process_matching(self, pattern.into(), options, BulkOperation::Dump(&mut dumps)).await?;
+    Ok(dumps)
   }
 
   /// Incrementally iterate over pages of the hash map stored at `key`, returning `count` results per page, if
@@ -197,7 +377,22 @@ impl RedisClient {
     K: Into<RedisKey>,
     P: Into<Str>,
   {
-    commands::scan::hscan(&self.inner, key.into(), pattern.into(), count)
+    commands::scan::hscan(&self.inner, key.into(), pattern.into(), count, None)
+  }
+
+  /// Resume a [hscan](Self::hscan) operation from a [checkpoint](crate::types::ScanCheckpoint).
+  pub fn hscan_from<K, P>(
+    &self,
+    key: K,
+    pattern: P,
+    count: Option<u32>,
+    checkpoint: ScanCheckpoint,
+  ) -> impl Stream<Item = Result<HScanResult, RedisError>>
+  where
+    K: Into<RedisKey>,
+    P: Into<Str>,
+  {
+    commands::scan::hscan(&self.inner, key.into(), pattern.into(), count, Some(checkpoint))
   }
 
   /// Incrementally iterate over pages of the set stored at `key`, returning `count` results per page, if specified.
@@ -213,7 +408,22 @@ impl RedisClient {
     K: Into<RedisKey>,
     P: Into<Str>,
   {
-    commands::scan::sscan(&self.inner, key.into(), pattern.into(), count)
+    commands::scan::sscan(&self.inner, key.into(), pattern.into(), count, None)
+  }
+
+  /// Resume a [sscan](Self::sscan) operation from a [checkpoint](crate::types::ScanCheckpoint).
+  pub fn sscan_from<K, P>(
+    &self,
+    key: K,
+    pattern: P,
+    count: Option<u32>,
+    checkpoint: ScanCheckpoint,
+  ) -> impl Stream<Item = Result<SScanResult, RedisError>>
+  where
+    K: Into<RedisKey>,
+    P: Into<Str>,
+  {
+    commands::scan::sscan(&self.inner, key.into(), pattern.into(), count, Some(checkpoint))
   }
 
   /// Incrementally iterate over pages of the sorted set stored at `key`, returning `count` results per page, if
@@ -230,7 +440,150 @@ impl RedisClient {
     K: Into<RedisKey>,
     P: Into<Str>,
   {
-    commands::scan::zscan(&self.inner, key.into(), pattern.into(), count)
+    commands::scan::zscan(&self.inner, key.into(), pattern.into(), count, None)
+  }
+
+  /// Resume a [zscan](Self::zscan) operation from a [checkpoint](crate::types::ScanCheckpoint).
+  pub fn zscan_from<K, P>(
+    &self,
+    key: K,
+    pattern: P,
+    count: Option<u32>,
+    checkpoint: ScanCheckpoint,
+  ) -> impl Stream<Item = Result<ZScanResult, RedisError>>
+  where
+    K: Into<RedisKey>,
+    P: Into<Str>,
+  {
+    commands::scan::zscan(&self.inner, key.into(), pattern.into(), count, Some(checkpoint))
+  }
+
+  /// Run `FT.AGGREGATE` with a cursor, reading pages of results with `FT.CURSOR READ` until the cursor is exhausted.
+  ///
+  /// A `WITHCURSOR` argument will be added if one is not provided. Each page is requested when the stream is polled,
+  /// and the cursor is deleted on the server if the stream is dropped before all the results are read.
+  ///
+  /// <https://redis.io/docs/interact/search-and-query/search/aggregations/#cursor-api>
+  #[cfg(feature = "redis-search")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+  pub fn ft_aggregate_stream<I, Q>(
+    &self,
+    index: I,
+    query: Q,
+    options: FtAggregateOptions,
+  ) -> impl Stream<Item = Result<AggregateResults, RedisError>>
+  where
+    I: Into<Str>,
+    Q: Into<Str>,
+  {
+    commands::redisearch::ft_aggregate_stream(self, index.into(), query.into(), options)
+  }
+
+  /// Incrementally save the bloom filter stored at `key` with `BF.SCANDUMP`, returning each `(iterator, data)` chunk.
+  ///
+  /// The chunks can be restored in order with [bf_loadchunk](crate::interfaces::RedisBloomInterface::bf_loadchunk).
+  /// Each chunk is requested when the stream is polled, and the filter should not be modified while the stream is
+  /// being read.
+  ///
+  /// ```rust no_run
+  /// # use fred::prelude::*;
+  /// # use futures::TryStreamExt;
+  /// async fn example(source: &RedisClient, destination: &RedisClient) -> Result<(), RedisError> {
+  ///   let mut chunks = source.bf_scandump_stream("filter");
+  ///   while let Some((iterator, data)) = chunks.try_next().await? {
+  ///     let _: () = destination.bf_loadchunk("filter", iterator, data).await?;
+  ///   }
+  ///   Ok(())
+  /// }
+  /// ```
+  ///
+  /// <https://redis.io/commands/bf.scandump/>
+  #[cfg(feature = "redis-bloom")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+  pub fn bf_scandump_stream<K>(&self, key: K) -> impl Stream<Item = Result<(i64, bytes::Bytes), RedisError>>
+  where
+    K: Into<RedisKey>,
+  {
+    commands::redis_bloom::bf_scandump_stream(self, key.into())
+  }
+
+  /// Incrementally save the cuckoo filter stored at `key` with `CF.SCANDUMP`, returning each `(iterator, data)`
+  /// chunk.
+  ///
+  /// The chunks can be restored in order with [cf_loadchunk](crate::interfaces::RedisBloomInterface::cf_loadchunk).
+  /// Each chunk is requested when the stream is polled, and the filter should not be modified while the stream is
+  /// being read.
+  ///
+  /// <https://redis.io/commands/cf.scandump/>
+  #[cfg(feature = "redis-bloom")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+  pub fn cf_scandump_stream<K>(&self, key: K) -> impl Stream<Item = Result<(i64, bytes::Bytes), RedisError>>
+  where
+    K: Into<RedisKey>,
+  {
+    commands::redis_bloom::cf_scandump_stream(self, key.into())
+  }
+
+  /// Run an optimistic locking transaction, retrying with the provided policy if `EXEC` aborts because a watched key
+  /// changed or the connection closed before `EXEC` was sent.
+  ///
+  /// Each attempt checks out a [dedicated](Self::dedicated) connection that calls `WATCH` with the provided keys,
+  /// then calls `func` with a client that uses the dedicated connection and an empty transaction. `func` should
+  /// read values with the provided client and queue writes on the transaction.
+  ///
+  /// If the connection closes after `EXEC` is sent the transaction may have run, so the error is returned instead of
+  /// calling `func` again.
+  ///
+  /// Cluster clients require that all the keys belong to the same hash slot.
+  ///
+  /// ```rust no_run
+  /// # use fred::prelude::*;
+  /// async fn example(client: &RedisClient) -> Result<(), RedisError> {
+  ///   let policy = ReconnectPolicy::new_exponential(10, 10, 1000, 2);
+  ///
+  ///   let (_, balance): ((), i64) = client
+  ///     .transaction_with_retry("balance{1}", policy, |client, trx| async move {
+  ///       let balance: i64 = client.get("balance{1}").await?;
+  ///       let _: () = trx.set("balance{1}", balance + 10, None, None, false).await?;
+  ///       let _: () = trx.get("balance{1}").await?;
+  ///       Ok(())
+  ///     })
+  ///     .await?;
+  ///
+  ///   println!("New balance: {}", balance);
+  ///   Ok(())
+  /// }
+  /// ```
+  #[cfg(feature = "transactions")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "transactions")))]
+  pub async fn transaction_with_retry<K, F, Fut, R>(
+    &self,
+    keys: K,
+    policy: ReconnectPolicy,
+    func: F,
+  ) -> Result<R, RedisError>
+  where
+    K: Into<MultipleKeys>,
+    F: FnMut(RedisClient, Transaction) -> Fut,
+    Fut: Future<Output = Result<(), RedisError>>,
+    R: FromRedis,
+  {
+    let keys = keys.into();
+    utils::check_empty_keys(&keys)?;
+
+    transaction::exec_with_retry(self, keys, policy, func).await?.convert()
+  }
+
+  /// Check out a client with its own connection(s) that it owns exclusively until dropped.
+  ///
+  /// Idle dedicated connections are reused if possible, otherwise a new connection is created from this client's
+  /// config.
+  ///
+  /// This function will wait if
+  /// [max_dedicated_connections](crate::types::ConnectionConfig::max_dedicated_connections) dedicated clients are
+  /// already checked out from this client. See [DedicatedClient] for more information.
+  pub async fn dedicated(&self) -> Result<DedicatedClient, RedisError> {
+    dedicated::acquire(&self.inner.dedicated, self).await
   }
 
   /// Send a series of commands in a [pipeline](https://redis.io/docs/manual/pipelining/).
@@ -48,11 +48,13 @@ impl ClientLike for Replicas {
   }
 }
 
+impl BitmapInterface for Replicas {}
 impl GeoInterface for Replicas {}
 impl HashesInterface for Replicas {}
 impl HyperloglogInterface for Replicas {}
 impl MetricsInterface for Replicas {}
 impl KeysInterface for Replicas {}
+impl StringsInterface for Replicas {}
 impl LuaInterface for Replicas {}
 impl FunctionInterface for Replicas {}
 impl ListInterface for Replicas {}
@@ -69,6 +71,12 @@ impl RedisJsonInterface for Replicas {}
 #[cfg(feature = "time-series")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time-series")))]
 impl TimeSeriesInterface for Replicas {}
+#[cfg(feature = "redis-bloom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+impl RedisBloomInterface for Replicas {}
+#[cfg(feature = "redis-search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+impl RediSearchInterface for Replicas {}
 
 impl Replicas {
   /// Read a mapping of replica server IDs to primary server IDs.
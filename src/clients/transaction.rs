@@ -1,4 +1,5 @@
 use crate::{
+  clients::RedisClient,
   error::{RedisError, RedisErrorKind},
   interfaces,
   interfaces::*,
@@ -10,12 +11,12 @@ use crate::{
     responders::ResponseKind,
     utils as protocol_utils,
   },
-  types::{FromRedis, MultipleKeys, Options, RedisKey, Server},
+  types::{FromRedis, MultipleKeys, Options, ReconnectPolicy, RedisKey, Server},
   utils,
 };
 use parking_lot::Mutex;
-use std::{collections::VecDeque, fmt, sync::Arc};
-use tokio::sync::oneshot::channel as oneshot_channel;
+use std::{collections::VecDeque, fmt, future::Future, sync::Arc, time::Duration};
+use tokio::{sync::oneshot::channel as oneshot_channel, time::sleep};
 
 /// A cheaply cloneable transaction block.
 #[derive(Clone)]
@@ -54,6 +55,11 @@ impl ClientLike for Transaction {
     &self.inner
   }
 
+  #[doc(hidden)]
+  fn split_cross_slot(&self, _: Option<bool>) -> bool {
+    false
+  }
+
   #[doc(hidden)]
   fn send_command<C>(&self, command: C) -> Result<(), RedisError>
   where
@@ -79,6 +85,7 @@ impl ClientLike for Transaction {
 }
 
 impl AclInterface for Transaction {}
+impl BitmapInterface for Transaction {}
 impl ClientInterface for Transaction {}
 impl PubsubInterface for Transaction {}
 impl ConfigInterface for Transaction {}
@@ -87,6 +94,7 @@ impl HashesInterface for Transaction {}
 impl HyperloglogInterface for Transaction {}
 impl MetricsInterface for Transaction {}
 impl KeysInterface for Transaction {}
+impl StringsInterface for Transaction {}
 impl ListInterface for Transaction {}
 impl MemoryInterface for Transaction {}
 impl AuthInterface for Transaction {}
@@ -101,6 +109,12 @@ impl RedisJsonInterface for Transaction {}
 #[cfg(feature = "time-series")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time-series")))]
 impl TimeSeriesInterface for Transaction {}
+#[cfg(feature = "redis-bloom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+impl RedisBloomInterface for Transaction {}
+#[cfg(feature = "redis-search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+impl RediSearchInterface for Transaction {}
 
 impl Transaction {
   /// Create a new transaction.
@@ -313,3 +327,86 @@ async fn exec(
   let frame = utils::apply_timeout(rx, timeout_dur).await??;
   protocol_utils::frame_to_results(frame)
 }
+
+/// Run `func` with `WATCH` on the provided keys, retrying with the policy if `EXEC` aborts or the connection closes
+/// before `EXEC` is sent.
+///
+/// Each attempt uses a [dedicated](crate::clients::RedisClient::dedicated) connection, which does not reconnect, so
+/// the transaction cannot run on a new connection without the `WATCH`. Errors from `EXEC` are returned to the caller
+/// since the transaction may have run.
+pub(crate) async fn exec_with_retry<F, Fut>(
+  client: &RedisClient,
+  keys: MultipleKeys,
+  mut policy: ReconnectPolicy,
+  mut func: F,
+) -> Result<RedisValue, RedisError>
+where
+  F: FnMut(RedisClient, Transaction) -> Fut,
+  Fut: Future<Output = Result<(), RedisError>>,
+{
+  let inner = client.inner();
+  let keys = keys.inner();
+  let watch_cmd = RedisCommand::new(RedisCommandKind::Watch, keys.iter().map(|k| k.clone().into()).collect());
+
+  loop {
+    let dedicated = client.dedicated().await?;
+    match queue_watched(&dedicated, &keys, &watch_cmd, &mut func).await {
+      Ok(Some(trx)) => {
+        let result: RedisValue = trx.exec(true).await?;
+        if !result.is_null() {
+          return Ok(result);
+        }
+        _debug!(inner, "Watched transaction aborted.");
+      },
+      Ok(None) => return Ok(RedisValue::Null),
+      Err(e) if *e.kind() == RedisErrorKind::IO => _debug!(inner, "Watched transaction failed with {:?}", e),
+      Err(e) => return Err(e),
+    };
+    drop(dedicated);
+
+    match policy.next_delay() {
+      Some(delay) => {
+        _debug!(inner, "Retrying transaction after {} ms", delay);
+        sleep(Duration::from_millis(delay)).await;
+      },
+      None => {
+        return Err(RedisError::new(
+          RedisErrorKind::Canceled,
+          "Max transaction attempts reached.",
+        ))
+      },
+    }
+  }
+}
+
+/// Call `WATCH` and `func` on `client`, returning the transaction to send, or `None` if `func` did not queue any
+/// commands.
+///
+/// Returns an `IO` error if the connection closed before the transaction could be sent.
+async fn queue_watched<F, Fut>(
+  client: &RedisClient,
+  keys: &[RedisKey],
+  watch_cmd: &RedisCommand,
+  func: &mut F,
+) -> Result<Option<Transaction>, RedisError>
+where
+  F: FnMut(RedisClient, Transaction) -> Fut,
+  Fut: Future<Output = Result<(), RedisError>>,
+{
+  let _: () = client.watch(keys.to_vec()).await?;
+  let trx = client.multi();
+  // pin the transaction to the watched keys' hash slot
+  trx.update_hash_slot(watch_cmd)?;
+
+  func(client.clone(), trx.clone()).await?;
+  if trx.len() == 0 {
+    let _: () = client.unwatch().await?;
+    return Ok(None);
+  }
+
+  if client.is_connected() {
+    Ok(Some(trx))
+  } else {
+    Err(RedisError::new(RedisErrorKind::IO, "Connection closed before EXEC."))
+  }
+}
@@ -0,0 +1,202 @@
+use super::*;
+use crate::{
+  error::RedisErrorKind,
+  protocol::{
+    command::{RedisCommand, RedisCommandKind},
+    hashers::ClusterHash,
+    utils as protocol_utils,
+  },
+  types::*,
+  utils,
+};
+use std::convert::TryInto;
+
+fn check_bit(bit: u8) -> Result<(), RedisError> {
+  if bit > 1 {
+    Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "Bit value must be 0 or 1.",
+    ))
+  } else {
+    Ok(())
+  }
+}
+
+pub async fn setbit<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  offset: u64,
+  value: u8,
+) -> Result<RedisValue, RedisError> {
+  check_bit(value)?;
+
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::Setbit, vec![
+      key.into(),
+      offset.try_into()?,
+      value.into(),
+    ]))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn getbit<C: ClientLike>(client: &C, key: RedisKey, offset: u64) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::GetBit, vec![key.into(), offset.try_into()?]))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn bitcount<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  range: Option<(i64, i64)>,
+  unit: Option<BitUnit>,
+) -> Result<RedisValue, RedisError> {
+  if range.is_none() && unit.is_some() {
+    return Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "The BYTE|BIT argument requires a range.",
+    ));
+  }
+
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(4);
+    args.push(key.into());
+
+    if let Some((start, end)) = range {
+      args.push(start.into());
+      args.push(end.into());
+    }
+    if let Some(unit) = unit {
+      args.push(unit.to_str().into());
+    }
+
+    Ok((RedisCommandKind::BitCount, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn bitpos<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  bit: u8,
+  start: Option<i64>,
+  end: Option<i64>,
+  unit: Option<BitUnit>,
+) -> Result<RedisValue, RedisError> {
+  check_bit(bit)?;
+  if start.is_none() && end.is_some() {
+    return Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "The end argument requires a start argument.",
+    ));
+  }
+  if end.is_none() && unit.is_some() {
+    return Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "The BYTE|BIT argument requires an end argument.",
+    ));
+  }
+
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(5);
+    args.push(key.into());
+    args.push(bit.into());
+
+    if let Some(start) = start {
+      args.push(start.into());
+    }
+    if let Some(end) = end {
+      args.push(end.into());
+    }
+    if let Some(unit) = unit {
+      args.push(unit.to_str().into());
+    }
+
+    Ok((RedisCommandKind::BitPos, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn bitop<C: ClientLike>(
+  client: &C,
+  operation: BitOperation,
+  destination: RedisKey,
+  sources: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  utils::check_empty_keys(&sources)?;
+  if operation == BitOperation::Not && sources.len() != 1 {
+    return Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "BITOP NOT requires exactly one source key.",
+    ));
+  }
+
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(2 + sources.len());
+    args.push(operation.to_str().into());
+    args.push(destination.into());
+
+    for source in sources.inner().into_iter() {
+      args.push(source.into());
+    }
+
+    let mut command: RedisCommand = (RedisCommandKind::BitOp, args).into();
+    // the first argument is the operation, so hash the destination key instead
+    command.hasher = ClusterHash::Offset(1);
+    Ok(command)
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn bitfield<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  operations: BitfieldOperations,
+) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(1 + operations.args_len());
+    args.push(key.into());
+    operations.append_args(&mut args)?;
+
+    Ok((RedisCommandKind::BitField, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn bitfield_ro<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  operations: BitfieldOperations,
+) -> Result<RedisValue, RedisError> {
+  if !operations.is_read_only() {
+    return Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "BITFIELD_RO only supports GET operations.",
+    ));
+  }
+
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(1 + operations.args_len());
+    args.push(key.into());
+    operations.append_args(&mut args)?;
+
+    Ok((RedisCommandKind::BitFieldRO, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
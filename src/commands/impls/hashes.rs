@@ -179,3 +179,192 @@ pub async fn hstrlen<C: ClientLike>(client: &C, key: RedisKey, field: RedisKey)
 pub async fn hvals<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
   one_arg_values_cmd(client, RedisCommandKind::HVals, key.into()).await
 }
+
+fn add_fields(args: &mut Vec<RedisValue>, fields: MultipleKeys) -> Result<(), RedisError> {
+  args.push(static_val!(FIELDS));
+  args.push(fields.len().try_into()?);
+  args.extend(fields.inner().into_iter().map(|f| f.into()));
+  Ok(())
+}
+
+async fn hexpire_cmd<C: ClientLike>(
+  client: &C,
+  kind: RedisCommandKind,
+  key: RedisKey,
+  expiration: i64,
+  options: Option<ExpireOptions>,
+  fields: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(5 + fields.len());
+    args.push(key.into());
+    args.push(expiration.into());
+    if let Some(options) = options {
+      args.push(options.to_str().into());
+    }
+    add_fields(&mut args, fields)?;
+
+    Ok((kind, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+async fn hfields_cmd<C: ClientLike>(
+  client: &C,
+  kind: RedisCommandKind,
+  key: RedisKey,
+  fields: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(3 + fields.len());
+    args.push(key.into());
+    add_fields(&mut args, fields)?;
+
+    Ok((kind, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn hexpire<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  seconds: i64,
+  options: Option<ExpireOptions>,
+  fields: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  hexpire_cmd(client, RedisCommandKind::HExpire, key, seconds, options, fields).await
+}
+
+pub async fn hpexpire<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  milliseconds: i64,
+  options: Option<ExpireOptions>,
+  fields: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  hexpire_cmd(client, RedisCommandKind::HPExpire, key, milliseconds, options, fields).await
+}
+
+pub async fn hexpire_at<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  timestamp: i64,
+  options: Option<ExpireOptions>,
+  fields: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  hexpire_cmd(client, RedisCommandKind::HExpireAt, key, timestamp, options, fields).await
+}
+
+pub async fn hpexpire_at<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  timestamp: i64,
+  options: Option<ExpireOptions>,
+  fields: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  hexpire_cmd(client, RedisCommandKind::HPExpireAt, key, timestamp, options, fields).await
+}
+
+pub async fn httl<C: ClientLike>(client: &C, key: RedisKey, fields: MultipleKeys) -> Result<RedisValue, RedisError> {
+  hfields_cmd(client, RedisCommandKind::HTtl, key, fields).await
+}
+
+pub async fn hpttl<C: ClientLike>(client: &C, key: RedisKey, fields: MultipleKeys) -> Result<RedisValue, RedisError> {
+  hfields_cmd(client, RedisCommandKind::HPTtl, key, fields).await
+}
+
+pub async fn hexpire_time<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  fields: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  hfields_cmd(client, RedisCommandKind::HExpireTime, key, fields).await
+}
+
+pub async fn hpexpire_time<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  fields: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  hfields_cmd(client, RedisCommandKind::HPExpireTime, key, fields).await
+}
+
+pub async fn hpersist<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  fields: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  hfields_cmd(client, RedisCommandKind::HPersist, key, fields).await
+}
+
+pub async fn hgetdel<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  fields: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  hfields_cmd(client, RedisCommandKind::HGetDel, key, fields).await
+}
+
+pub async fn hgetex<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  expire: Option<GetExpiration>,
+  fields: MultipleKeys,
+) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(5 + fields.len());
+    args.push(key.into());
+    if let Some(expire) = expire {
+      let (k, v) = expire.into_args();
+      args.push(k.into());
+      if let Some(v) = v {
+        args.push(v.into());
+      }
+    }
+    add_fields(&mut args, fields)?;
+
+    Ok((RedisCommandKind::HGetEx, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn hsetex<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  options: Option<HSetExOptions>,
+  expire: Option<Expiration>,
+  values: RedisMap,
+) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(6 + (values.len() * 2));
+    args.push(key.into());
+    if let Some(options) = options {
+      args.push(options.to_str().into());
+    }
+    if let Some(expire) = expire {
+      let (k, v) = expire.into_args();
+      args.push(k.into());
+      if let Some(v) = v {
+        args.push(v.into());
+      }
+    }
+
+    args.push(static_val!(FIELDS));
+    args.push(values.len().try_into()?);
+    for (field, value) in values.inner().into_iter() {
+      args.push(field.into());
+      args.push(value);
+    }
+
+    Ok((RedisCommandKind::HSetEx, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
@@ -1,55 +1,27 @@
 use super::*;
 use crate::{
   error::*,
-  protocol::{command::RedisCommandKind, utils as protocol_utils},
+  protocol::{
+    command::{RedisCommand, RedisCommandKind},
+    utils as protocol_utils,
+  },
   types::*,
   utils,
 };
-use std::convert::TryInto;
+use bytes_utils::Str;
 
 value_cmd!(randomkey, Randomkey);
 
-pub async fn get<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
-  one_arg_values_cmd(client, RedisCommandKind::Get, key.into()).await
-}
-
-pub async fn set<C: ClientLike>(
-  client: &C,
-  key: RedisKey,
-  value: RedisValue,
-  expire: Option<Expiration>,
-  options: Option<SetOptions>,
-  get: bool,
-) -> Result<RedisValue, RedisError> {
-  let frame = utils::request_response(client, move || {
-    let mut args = Vec::with_capacity(6);
-    args.push(key.into());
-    args.push(value);
-
-    if let Some(expire) = expire {
-      let (k, v) = expire.into_args();
-      args.push(k.into());
-      if let Some(v) = v {
-        args.push(v.into());
-      }
-    }
-    if let Some(options) = options {
-      args.push(options.to_str().into());
-    }
-    if get {
-      args.push(static_val!(GET));
-    }
-
-    Ok((RedisCommandKind::Set, args))
-  })
-  .await?;
-
-  protocol_utils::frame_to_results(frame)
-}
-
 pub async fn del<C: ClientLike>(client: &C, keys: MultipleKeys) -> Result<RedisValue, RedisError> {
   utils::check_empty_keys(&keys)?;
 
+  if client.split_cross_slot(None) {
+    let args = keys.inner().into_iter().map(|k| (k, None)).collect();
+    return Ok(sum_slot_results(
+      split_by_slot_cmd(client, RedisCommandKind::Del, args).await?,
+    ));
+  }
+
   let args: Vec<RedisValue> = keys.inner().drain(..).map(|k| k.into()).collect();
   let frame = utils::request_response(client, move || Ok((RedisCommandKind::Del, args))).await?;
   protocol_utils::frame_to_results(frame)
@@ -58,84 +30,105 @@ pub async fn del<C: ClientLike>(client: &C, keys: MultipleKeys) -> Result<RedisV
 pub async fn unlink<C: ClientLike>(client: &C, keys: MultipleKeys) -> Result<RedisValue, RedisError> {
   utils::check_empty_keys(&keys)?;
 
+  if client.split_cross_slot(None) {
+    let args = keys.inner().into_iter().map(|k| (k, None)).collect();
+    return Ok(sum_slot_results(
+      split_by_slot_cmd(client, RedisCommandKind::Unlink, args).await?,
+    ));
+  }
+
   let args: Vec<RedisValue> = keys.inner().drain(..).map(|k| k.into()).collect();
   let frame = utils::request_response(client, move || Ok((RedisCommandKind::Unlink, args))).await?;
   protocol_utils::frame_to_results(frame)
 }
 
-pub async fn append<C: ClientLike>(client: &C, key: RedisKey, value: RedisValue) -> Result<RedisValue, RedisError> {
-  args_value_cmd(client, RedisCommandKind::Append, vec![key.into(), value]).await
-}
-
-pub async fn incr<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
-  one_arg_value_cmd(client, RedisCommandKind::Incr, key.into()).await
+pub async fn ttl<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_value_cmd(client, RedisCommandKind::Ttl, key.into()).await
 }
 
-pub async fn decr<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
-  one_arg_value_cmd(client, RedisCommandKind::Decr, key.into()).await
+pub async fn pttl<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_value_cmd(client, RedisCommandKind::Pttl, key.into()).await
 }
 
-pub async fn incr_by<C: ClientLike>(client: &C, key: RedisKey, val: i64) -> Result<RedisValue, RedisError> {
-  let frame = utils::request_response(client, move || {
-    Ok((RedisCommandKind::IncrBy, vec![key.into(), val.into()]))
-  })
-  .await?;
-
-  protocol_utils::frame_to_results(frame)
+pub async fn persist<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_value_cmd(client, RedisCommandKind::Persist, key.into()).await
 }
 
-pub async fn decr_by<C: ClientLike>(client: &C, key: RedisKey, val: i64) -> Result<RedisValue, RedisError> {
-  let frame = utils::request_response(client, move || {
-    Ok((RedisCommandKind::DecrBy, vec![key.into(), val.into()]))
-  })
-  .await?;
+fn expire_args(key: RedisKey, value: i64, options: Option<ExpireOptions>) -> Vec<RedisValue> {
+  let mut args = Vec::with_capacity(3);
+  args.push(key.into());
+  args.push(value.into());
+  if let Some(options) = options {
+    args.push(options.to_str().into());
+  }
 
-  protocol_utils::frame_to_results(frame)
+  args
 }
 
-pub async fn incr_by_float<C: ClientLike>(client: &C, key: RedisKey, val: f64) -> Result<RedisValue, RedisError> {
-  let val: RedisValue = val.try_into()?;
-  let frame = utils::request_response(client, move || {
-    Ok((RedisCommandKind::IncrByFloat, vec![key.into(), val]))
-  })
-  .await?;
-
-  protocol_utils::frame_to_results(frame)
+pub async fn expire<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  seconds: i64,
+  options: Option<ExpireOptions>,
+) -> Result<RedisValue, RedisError> {
+  args_value_cmd(client, RedisCommandKind::Expire, expire_args(key, seconds, options)).await
 }
 
-pub async fn ttl<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
-  one_arg_value_cmd(client, RedisCommandKind::Ttl, key.into()).await
+pub async fn expire_at<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  timestamp: i64,
+  options: Option<ExpireOptions>,
+) -> Result<RedisValue, RedisError> {
+  args_value_cmd(client, RedisCommandKind::ExpireAt, expire_args(key, timestamp, options)).await
 }
 
-pub async fn pttl<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
-  one_arg_value_cmd(client, RedisCommandKind::Pttl, key.into()).await
+pub async fn pexpire<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  milliseconds: i64,
+  options: Option<ExpireOptions>,
+) -> Result<RedisValue, RedisError> {
+  args_value_cmd(
+    client,
+    RedisCommandKind::Pexpire,
+    expire_args(key, milliseconds, options),
+  )
+  .await
 }
 
-pub async fn persist<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
-  one_arg_value_cmd(client, RedisCommandKind::Persist, key.into()).await
+pub async fn pexpire_at<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  timestamp: i64,
+  options: Option<ExpireOptions>,
+) -> Result<RedisValue, RedisError> {
+  args_value_cmd(
+    client,
+    RedisCommandKind::Pexpireat,
+    expire_args(key, timestamp, options),
+  )
+  .await
 }
 
-pub async fn expire<C: ClientLike>(client: &C, key: RedisKey, seconds: i64) -> Result<RedisValue, RedisError> {
-  let frame = utils::request_response(client, move || {
-    Ok((RedisCommandKind::Expire, vec![key.into(), seconds.into()]))
-  })
-  .await?;
-
-  protocol_utils::frame_to_results(frame)
+pub async fn expire_time<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_value_cmd(client, RedisCommandKind::ExpireTime, key.into()).await
 }
 
-pub async fn expire_at<C: ClientLike>(client: &C, key: RedisKey, timestamp: i64) -> Result<RedisValue, RedisError> {
-  let frame = utils::request_response(client, move || {
-    Ok((RedisCommandKind::ExpireAt, vec![key.into(), timestamp.into()]))
-  })
-  .await?;
-
-  protocol_utils::frame_to_results(frame)
+pub async fn pexpire_time<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_value_cmd(client, RedisCommandKind::PexpireTime, key.into()).await
 }
 
 pub async fn exists<C: ClientLike>(client: &C, keys: MultipleKeys) -> Result<RedisValue, RedisError> {
   utils::check_empty_keys(&keys)?;
 
+  if client.split_cross_slot(None) {
+    let args = keys.inner().into_iter().map(|k| (k, None)).collect();
+    return Ok(sum_slot_results(
+      split_by_slot_cmd(client, RedisCommandKind::Exists, args).await?,
+    ));
+  }
+
   let frame = utils::request_response(client, move || {
     let mut args = Vec::with_capacity(keys.len());
 
@@ -192,42 +185,6 @@ pub async fn restore<C: ClientLike>(
   protocol_utils::frame_to_results(frame)
 }
 
-pub async fn getrange<C: ClientLike>(
-  client: &C,
-  key: RedisKey,
-  start: usize,
-  end: usize,
-) -> Result<RedisValue, RedisError> {
-  let frame = utils::request_response(client, move || {
-    Ok((RedisCommandKind::GetRange, vec![
-      key.into(),
-      start.try_into()?,
-      end.try_into()?,
-    ]))
-  })
-  .await?;
-
-  protocol_utils::frame_to_results(frame)
-}
-
-pub async fn setrange<C: ClientLike>(
-  client: &C,
-  key: RedisKey,
-  offset: u32,
-  value: RedisValue,
-) -> Result<RedisValue, RedisError> {
-  let frame = utils::request_response(client, move || {
-    Ok((RedisCommandKind::Setrange, vec![key.into(), offset.into(), value]))
-  })
-  .await?;
-
-  protocol_utils::frame_to_results(frame)
-}
-
-pub async fn getset<C: ClientLike>(client: &C, key: RedisKey, value: RedisValue) -> Result<RedisValue, RedisError> {
-  args_values_cmd(client, RedisCommandKind::GetSet, vec![key.into(), value]).await
-}
-
 pub async fn rename<C: ClientLike>(
   client: &C,
   source: RedisKey,
@@ -252,140 +209,226 @@ pub async fn renamenx<C: ClientLike>(
   .await
 }
 
-pub async fn getdel<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
-  one_arg_values_cmd(client, RedisCommandKind::GetDel, key.into()).await
+pub async fn copy<C: ClientLike>(
+  client: &C,
+  source: RedisKey,
+  destination: RedisKey,
+  db: Option<u8>,
+  replace: bool,
+) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(5);
+    args.push(source.into());
+    args.push(destination.into());
+
+    if let Some(db) = db {
+      args.push(static_val!(DB));
+      args.push(db.into());
+    }
+    if replace {
+      args.push(static_val!(REPLACE));
+    }
+
+    Ok((RedisCommandKind::Copy, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
 }
 
-pub async fn strlen<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
-  one_arg_value_cmd(client, RedisCommandKind::Strlen, key.into()).await
+pub async fn watch<C: ClientLike>(client: &C, keys: MultipleKeys) -> Result<(), RedisError> {
+  let args = keys.inner().into_iter().map(|k| k.into()).collect();
+  args_ok_cmd(client, RedisCommandKind::Watch, args).await
+}
+
+ok_cmd!(unwatch, Unwatch);
+
+pub async fn r#type<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_value_cmd(client, RedisCommandKind::Type, key.into()).await
 }
 
-pub async fn mget<C: ClientLike>(client: &C, keys: MultipleKeys) -> Result<RedisValue, RedisError> {
+pub async fn touch<C: ClientLike>(client: &C, keys: MultipleKeys) -> Result<RedisValue, RedisError> {
   utils::check_empty_keys(&keys)?;
 
-  let frame = utils::request_response(client, move || {
-    let mut args = Vec::with_capacity(keys.len());
+  if client.split_cross_slot(None) {
+    let args = keys.inner().into_iter().map(|k| (k, None)).collect();
+    return Ok(sum_slot_results(
+      split_by_slot_cmd(client, RedisCommandKind::Touch, args).await?,
+    ));
+  }
 
-    for key in keys.inner().into_iter() {
-      args.push(key.into());
-    }
+  let args: Vec<RedisValue> = keys.inner().into_iter().map(|k| k.into()).collect();
+  args_value_cmd(client, RedisCommandKind::Touch, args).await
+}
 
-    Ok((RedisCommandKind::Mget, args))
+async fn object_cmd<C: ClientLike>(
+  client: &C,
+  subcommand: &'static str,
+  key: RedisKey,
+) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    let mut command: RedisCommand = (RedisCommandKind::Object, vec![static_val!(subcommand), key.into()]).into();
+    command.hasher = ClusterHash::Offset(1);
+    Ok(command)
   })
   .await?;
 
   protocol_utils::frame_to_results(frame)
 }
 
-pub async fn mset<C: ClientLike>(client: &C, values: RedisMap) -> Result<RedisValue, RedisError> {
-  if values.len() == 0 {
-    return Err(RedisError::new(
-      RedisErrorKind::InvalidArgument,
-      "Values cannot be empty.",
-    ));
+pub async fn object_encoding<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  object_cmd(client, "ENCODING", key).await
+}
+
+pub async fn object_freq<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  object_cmd(client, FREQ, key).await
+}
+
+pub async fn object_idletime<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  object_cmd(client, IDLE_TIME, key).await
+}
+
+pub async fn object_refcount<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  object_cmd(client, "REFCOUNT", key).await
+}
+
+pub async fn r#move<C: ClientLike>(client: &C, key: RedisKey, db: u8) -> Result<RedisValue, RedisError> {
+  args_value_cmd(client, RedisCommandKind::Move, vec![key.into(), db.into()]).await
+}
+
+fn sort_args(key: RedisKey, options: SortOptions) -> Vec<RedisValue> {
+  let mut args = Vec::with_capacity(8 + options.get.len() * 2);
+  args.push(key.into());
+
+  if let Some(pattern) = options.by {
+    args.push(static_val!(BY));
+    args.push(pattern.into());
+  }
+  if let Some((offset, count)) = options.limit {
+    args.push(static_val!(LIMIT));
+    args.push(offset.into());
+    args.push(count.into());
+  }
+  for pattern in options.get.into_iter() {
+    args.push(static_val!(GET));
+    args.push(pattern.into());
+  }
+  if let Some(order) = options.order {
+    args.push(order.to_str().into());
+  }
+  if options.alpha {
+    args.push(static_val!(ALPHA));
+  }
+  if let Some(destination) = options.store {
+    args.push(static_val!(STORE));
+    args.push(destination.into());
   }
 
-  let frame = utils::request_response(client, move || {
-    let mut args = Vec::with_capacity(values.len() * 2);
+  args
+}
 
-    for (key, value) in values.inner().into_iter() {
-      args.push(key.into());
-      args.push(value);
+pub async fn sort<C: ClientLike>(client: &C, key: RedisKey, options: SortOptions) -> Result<RedisValue, RedisError> {
+  if let Some(ref destination) = options.store {
+    if client.is_clustered() && key.cluster_hash() != destination.cluster_hash() {
+      return Err(RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        "Source and destination keys must map to the same hash slot.",
+      ));
     }
+  }
 
-    Ok((RedisCommandKind::Mset, args))
-  })
-  .await?;
-
-  protocol_utils::frame_to_results(frame)
+  args_values_cmd(client, RedisCommandKind::Sort, sort_args(key, options)).await
 }
 
-pub async fn msetnx<C: ClientLike>(client: &C, values: RedisMap) -> Result<RedisValue, RedisError> {
-  if values.len() == 0 {
+pub async fn sort_ro<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  options: SortOptions,
+) -> Result<RedisValue, RedisError> {
+  if options.store.is_some() {
     return Err(RedisError::new(
       RedisErrorKind::InvalidArgument,
-      "Values cannot be empty.",
+      "SORT_RO does not support STORE.",
     ));
   }
 
-  let frame = utils::request_response(client, move || {
-    let mut args = Vec::with_capacity(values.len() * 2);
-
-    for (key, value) in values.inner().into_iter() {
-      args.push(key.into());
-      args.push(value);
-    }
+  args_values_cmd(client, RedisCommandKind::SortRo, sort_args(key, options)).await
+}
 
-    Ok((RedisCommandKind::Msetnx, args))
+pub async fn keys<C: ClientLike>(client: &C, pattern: Str) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    let mut command: RedisCommand = (RedisCommandKind::Keys, vec![pattern.into()]).into();
+    command.hasher = ClusterHash::Random;
+    Ok(command)
   })
   .await?;
 
   protocol_utils::frame_to_results(frame)
 }
 
-pub async fn copy<C: ClientLike>(
+pub async fn waitaof<C: ClientLike>(
   client: &C,
-  source: RedisKey,
-  destination: RedisKey,
-  db: Option<u8>,
-  replace: bool,
+  numlocal: i64,
+  numreplicas: i64,
+  timeout: i64,
 ) -> Result<RedisValue, RedisError> {
   let frame = utils::request_response(client, move || {
-    let mut args = Vec::with_capacity(5);
-    args.push(source.into());
-    args.push(destination.into());
-
-    if let Some(db) = db {
-      args.push(static_val!(DB));
-      args.push(db.into());
-    }
-    if replace {
-      args.push(static_val!(REPLACE));
-    }
-
-    Ok((RedisCommandKind::Copy, args))
+    Ok((RedisCommandKind::WaitAof, vec![
+      numlocal.into(),
+      numreplicas.into(),
+      timeout.into(),
+    ]))
   })
   .await?;
 
   protocol_utils::frame_to_results(frame)
 }
 
-pub async fn watch<C: ClientLike>(client: &C, keys: MultipleKeys) -> Result<(), RedisError> {
-  let args = keys.inner().into_iter().map(|k| k.into()).collect();
-  args_ok_cmd(client, RedisCommandKind::Watch, args).await
-}
-
-ok_cmd!(unwatch, Unwatch);
-
-pub async fn lcs<C: ClientLike>(
+pub async fn migrate<C: ClientLike>(
   client: &C,
-  key1: RedisKey,
-  key2: RedisKey,
-  len: bool,
-  idx: bool,
-  minmatchlen: Option<i64>,
-  withmatchlen: bool,
+  host: Str,
+  port: u16,
+  keys: MultipleKeys,
+  db: u8,
+  timeout: i64,
+  options: MigrateOptions,
 ) -> Result<RedisValue, RedisError> {
+  utils::check_empty_keys(&keys)?;
+
   let frame = utils::request_response(client, move || {
-    let mut args = Vec::with_capacity(7);
-    args.push(key1.into());
-    args.push(key2.into());
+    let mut keys = keys.inner();
+    let mut args = Vec::with_capacity(10 + keys.len());
+    args.push(host.into());
+    args.push(port.into());
+
+    // use the single key form when possible, otherwise hash the first key after `KEYS` rather than the host
+    let mut hasher = ClusterHash::Offset(2);
+    if keys.len() == 1 {
+      args.push(keys.pop().unwrap().into());
+    } else {
+      args.push(static_val!(""));
+    }
+    args.push(db.into());
+    args.push(timeout.into());
 
-    if len {
-      args.push(static_val!(LEN));
+    if options.copy {
+      args.push(static_val!(COPY));
     }
-    if idx {
-      args.push(static_val!(IDX));
+    if options.replace {
+      args.push(static_val!(REPLACE));
     }
-    if let Some(minmatchlen) = minmatchlen {
-      args.push(static_val!(MINMATCHLEN));
-      args.push(minmatchlen.into());
+    if let Some(auth) = options.auth {
+      args.extend(auth.into_args());
     }
-    if withmatchlen {
-      args.push(static_val!(WITHMATCHLEN));
+    if !keys.is_empty() {
+      args.push(static_val!(KEYS));
+      hasher = ClusterHash::Offset(args.len());
+      args.extend(keys.into_iter().map(|k| k.into()));
     }
 
-    Ok((RedisCommandKind::Lcs, args))
+    let mut command: RedisCommand = (RedisCommandKind::Migrate, args).into();
+    command.hasher = hasher;
+    Ok(command)
   })
   .await?;
 
@@ -0,0 +1,70 @@
+use super::*;
+use crate::{
+  protocol::{command::RedisCommandKind, utils as protocol_utils},
+  types::*,
+  utils,
+};
+use bytes_utils::Str;
+use std::mem;
+
+static NOTIFY_KEYSPACE_EVENTS: &str = "notify-keyspace-events";
+
+pub async fn configure_notifications<C: ClientLike>(client: &C, flags: KeyspaceEventFlags) -> Result<(), RedisError> {
+  let args = vec![static_val!(NOTIFY_KEYSPACE_EVENTS), flags.to_str().into()];
+
+  for server in utils::primary_nodes(client).into_iter() {
+    let response = utils::node_request(client, server, RedisCommandKind::ConfigSet, args.clone()).await?;
+    protocol_utils::expect_ok(&response)?;
+  }
+  Ok(())
+}
+
+/// Subscribe to the tracked keyspace channels on `server`, or on all primary nodes if `None`.
+pub async fn resubscribe<C: ClientLike>(client: &C, server: Option<Server>) -> Result<(), RedisError> {
+  let args: Vec<RedisValue> = client
+    .inner()
+    .keyspace_channels
+    .read()
+    .iter()
+    .map(|c| c.clone().into())
+    .collect();
+  if args.is_empty() {
+    return Ok(());
+  }
+
+  let servers = match server {
+    Some(server) if client.is_clustered() => vec![Some(server)],
+    _ => utils::primary_nodes(client),
+  };
+  for server in servers.into_iter() {
+    let _ = utils::node_request(client, server, RedisCommandKind::Psubscribe, args.clone()).await?;
+  }
+  Ok(())
+}
+
+pub async fn subscribe<C: ClientLike>(client: &C, channel: Str) -> Result<(), RedisError> {
+  for server in utils::primary_nodes(client).into_iter() {
+    let _ = utils::node_request(client, server, RedisCommandKind::Psubscribe, vec![channel
+      .clone()
+      .into()])
+    .await?;
+  }
+
+  client.inner().keyspace_channels.write().insert(channel);
+  Ok(())
+}
+
+pub async fn unsubscribe_all<C: ClientLike>(client: &C) -> Result<(), RedisError> {
+  let channels: Vec<RedisValue> = mem::take(&mut *client.inner().keyspace_channels.write())
+    .into_iter()
+    .map(|c| c.into())
+    .collect();
+  if channels.is_empty() {
+    return Ok(());
+  }
+
+  for server in utils::primary_nodes(client).into_iter() {
+    let _ = utils::node_request(client, server, RedisCommandKind::Punsubscribe, channels.clone()).await?;
+  }
+  Ok(())
+}
@@ -1,10 +1,12 @@
 use crate::{
-  error::RedisError,
+  error::{RedisError, SlotError},
   interfaces::ClientLike,
-  protocol::{command::RedisCommandKind, utils as protocol_utils},
-  types::RedisValue,
+  protocol::{command::RedisCommandKind, types::ClusterRouting, utils as protocol_utils},
+  types::{RedisKey, RedisValue},
   utils,
 };
+use futures::future::join_all;
+use std::collections::BTreeMap;
 
 pub static MATCH: &str = "MATCH";
 pub static COUNT: &str = "COUNT";
@@ -54,6 +56,11 @@ pub static WITHCODE: &str = "WITHCODE";
 pub static IDX: &str = "IDX";
 pub static MINMATCHLEN: &str = "MINMATCHLEN";
 pub static WITHMATCHLEN: &str = "WITHMATCHLEN";
+pub static FIELDS: &str = "FIELDS";
+pub static BY: &str = "BY";
+pub static ALPHA: &str = "ALPHA";
+pub static KEYS: &str = "KEYS";
+pub static COPY: &str = "COPY";
 
 /// Macro to generate a command function that takes no arguments and expects an OK response - returning `()` to the
 /// caller.
@@ -162,7 +169,76 @@ pub async fn args_ok_cmd<C: ClientLike>(
   protocol_utils::expect_ok(&response)
 }
 
+/// A function that issues a multi-key command once per cluster hash slot, concurrently.
+///
+/// Each key is followed by its optional value in the arguments. The response from each hash slot is returned with
+/// the original indexes of the keys sent to that hash slot.
+pub async fn split_by_slot_cmd<C: ClientLike>(
+  client: &C,
+  kind: RedisCommandKind,
+  args: Vec<(RedisKey, Option<RedisValue>)>,
+) -> Result<Vec<(Vec<usize>, RedisValue)>, RedisError> {
+  let mut slots: BTreeMap<u16, Vec<(usize, RedisKey, Option<RedisValue>)>> = BTreeMap::new();
+  for (idx, (key, value)) in args.into_iter().enumerate() {
+    let slot = ClusterRouting::hash_key(key.as_bytes());
+    slots.entry(slot).or_default().push((idx, key, value));
+  }
+
+  let total = slots.len();
+  let tasks = slots.into_iter().map(|(slot, keys)| {
+    let kind = kind.clone();
+    async move {
+      let mut indexes = Vec::with_capacity(keys.len());
+      let mut slot_keys = Vec::with_capacity(keys.len());
+      let mut args = Vec::with_capacity(keys.len() * 2);
+      for (idx, key, value) in keys.into_iter() {
+        indexes.push(idx);
+        slot_keys.push(key.clone());
+        args.push(key.into());
+        if let Some(value) = value {
+          args.push(value);
+        }
+      }
+
+      (slot, indexes, slot_keys, args_values_cmd(client, kind, args).await)
+    }
+  });
+
+  let mut results = Vec::with_capacity(total);
+  let mut errors = Vec::new();
+  for (slot, indexes, keys, result) in join_all(tasks).await.into_iter() {
+    match result {
+      Ok(value) => results.push((indexes, value)),
+      Err(error) => errors.push(SlotError { slot, keys, error }),
+    }
+  }
+
+  if errors.is_empty() {
+    Ok(results)
+  } else {
+    Err(RedisError::new_slot_errors(errors, total))
+  }
+}
+
+/// Add the integer responses from a split multi-key command.
+pub fn sum_slot_results(results: Vec<(Vec<usize>, RedisValue)>) -> RedisValue {
+  RedisValue::Integer(results.into_iter().filter_map(|(_, value)| value.as_i64()).sum())
+}
+
+/// Combine the array responses from a split multi-key command in the original key order.
+pub fn merge_slot_results(len: usize, results: Vec<(Vec<usize>, RedisValue)>) -> RedisValue {
+  let mut values = vec![RedisValue::Null; len];
+  for (indexes, value) in results.into_iter() {
+    for (idx, value) in indexes.into_iter().zip(value.into_array()) {
+      values[idx] = value;
+    }
+  }
+
+  RedisValue::Array(values)
+}
+
 pub mod acl;
+pub mod bitmaps;
 pub mod client;
 pub mod cluster;
 pub mod config;
@@ -170,6 +246,7 @@ pub mod geo;
 pub mod hashes;
 pub mod hyperloglog;
 pub mod keys;
+pub mod keyspace;
 pub mod lists;
 pub mod lua;
 pub mod memory;
@@ -182,8 +259,12 @@ pub mod sorted_sets;
 pub mod streams;
 pub mod strings;
 
+#[cfg(feature = "redis-bloom")]
+pub mod redis_bloom;
 #[cfg(feature = "redis-json")]
 pub mod redis_json;
+#[cfg(feature = "redis-search")]
+pub mod redisearch;
 #[cfg(feature = "sentinel-client")]
 pub mod sentinel;
 #[cfg(feature = "time-series")]
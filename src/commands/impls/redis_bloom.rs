@@ -0,0 +1,599 @@
+use super::*;
+use crate::{
+  error::{RedisError, RedisErrorKind},
+  interfaces::{ClientLike, RedisResult},
+  protocol::{command::RedisCommandKind, utils as protocol_utils},
+  types::{
+    BfInfoField,
+    BfInsertOptions,
+    CfReserveOptions,
+    MultipleKeys,
+    MultipleValues,
+    RedisKey,
+    RedisValue,
+    TopKDimensions,
+  },
+  utils,
+};
+use bytes::Bytes;
+use futures::stream::{self, Stream};
+
+static CAPACITY: &str = "CAPACITY";
+static ERROR: &str = "ERROR";
+static EXPANSION: &str = "EXPANSION";
+static NOCREATE: &str = "NOCREATE";
+static NONSCALING: &str = "NONSCALING";
+static ITEMS: &str = "ITEMS";
+static BUCKETSIZE: &str = "BUCKETSIZE";
+static MAXITERATIONS: &str = "MAXITERATIONS";
+static WEIGHTS: &str = "WEIGHTS";
+static WITHCOUNT: &str = "WITHCOUNT";
+static COMPRESSION: &str = "COMPRESSION";
+static OVERRIDE: &str = "OVERRIDE";
+
+fn key_and_values(key: RedisKey, values: MultipleValues) -> Vec<RedisValue> {
+  let values = values.into_multiple_values();
+  let mut args = Vec::with_capacity(1 + values.len());
+  args.push(key.into());
+  args.extend(values);
+  args
+}
+
+fn key_and_floats(key: RedisKey, values: Vec<f64>) -> Vec<RedisValue> {
+  let mut args = Vec::with_capacity(1 + values.len());
+  args.push(key.into());
+  args.extend(values.into_iter().map(|v| v.into()));
+  args
+}
+
+fn add_increments(args: &mut Vec<RedisValue>, increments: Vec<(RedisValue, i64)>) {
+  for (item, increment) in increments.into_iter() {
+    args.push(item);
+    args.push(increment.into());
+  }
+}
+
+fn add_items(args: &mut Vec<RedisValue>, items: MultipleValues) -> Result<(), RedisError> {
+  let items = items.into_multiple_values();
+  if items.is_empty() {
+    return Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "Expected at least one item.",
+    ));
+  }
+
+  args.push(static_val!(ITEMS));
+  args.extend(items);
+  Ok(())
+}
+
+fn parse_scandump_chunk(value: RedisValue) -> Result<(i64, Option<Bytes>), RedisError> {
+  let mut values = value.into_array().into_iter();
+  let iterator = match values.next().and_then(|v| v.as_i64()) {
+    Some(iterator) => iterator,
+    None => return Err(RedisError::new_parse("Expected SCANDUMP iterator.")),
+  };
+
+  Ok((iterator, values.next().and_then(|v| v.into_bytes())))
+}
+
+/// Read chunks from a `BF.SCANDUMP` or `CF.SCANDUMP` iterator, sending the next command when the stream is polled.
+fn scandump_stream<C>(
+  client: &C,
+  kind: RedisCommandKind,
+  key: RedisKey,
+) -> impl Stream<Item = Result<(i64, Bytes), RedisError>>
+where
+  C: ClientLike + Sync + 'static,
+{
+  let state = (client.clone(), kind, key, 0);
+
+  Box::pin(stream::try_unfold(state, |(client, kind, key, iterator)| async move {
+    let args = vec![key.clone().into(), iterator.into()];
+    let chunk = args_values_cmd(&client, kind.clone(), args)
+      .await
+      .and_then(parse_scandump_chunk)?;
+
+    match chunk {
+      (0, _) => Ok(None),
+      (next, data) => Ok(Some(((next, data.unwrap_or_default()), (client, kind, key, next)))),
+    }
+  }))
+}
+
+pub async fn bf_add<C: ClientLike>(client: &C, key: RedisKey, item: RedisValue) -> RedisResult<RedisValue> {
+  args_value_cmd(client, RedisCommandKind::BfAdd, vec![key.into(), item]).await
+}
+
+pub async fn bf_card<C: ClientLike>(client: &C, key: RedisKey) -> RedisResult<RedisValue> {
+  one_arg_value_cmd(client, RedisCommandKind::BfCard, key.into()).await
+}
+
+pub async fn bf_exists<C: ClientLike>(client: &C, key: RedisKey, item: RedisValue) -> RedisResult<RedisValue> {
+  args_value_cmd(client, RedisCommandKind::BfExists, vec![key.into(), item]).await
+}
+
+pub async fn bf_info<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  field: Option<BfInfoField>,
+) -> RedisResult<RedisValue> {
+  let mut args = Vec::with_capacity(2);
+  args.push(key.into());
+  if let Some(field) = field {
+    args.push(field.to_str().into());
+  }
+
+  args_values_cmd(client, RedisCommandKind::BfInfo, args).await
+}
+
+pub async fn bf_insert<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  options: BfInsertOptions,
+  items: MultipleValues,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(10);
+    args.push(key.into());
+
+    if let Some(capacity) = options.capacity {
+      args.push(static_val!(CAPACITY));
+      args.push(capacity.try_into()?);
+    }
+    if let Some(error) = options.error {
+      args.push(static_val!(ERROR));
+      args.push(error.into());
+    }
+    if let Some(expansion) = options.expansion {
+      args.push(static_val!(EXPANSION));
+      args.push(expansion.try_into()?);
+    }
+    if options.nocreate {
+      args.push(static_val!(NOCREATE));
+    }
+    if options.nonscaling {
+      args.push(static_val!(NONSCALING));
+    }
+    add_items(&mut args, items)?;
+
+    Ok((RedisCommandKind::BfInsert, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn bf_loadchunk<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  iterator: i64,
+  data: Bytes,
+) -> RedisResult<RedisValue> {
+  let args = vec![key.into(), iterator.into(), RedisValue::Bytes(data)];
+  args_value_cmd(client, RedisCommandKind::BfLoadChunk, args).await
+}
+
+pub async fn bf_madd<C: ClientLike>(client: &C, key: RedisKey, items: MultipleValues) -> RedisResult<RedisValue> {
+  args_values_cmd(client, RedisCommandKind::BfMAdd, key_and_values(key, items)).await
+}
+
+pub async fn bf_mexists<C: ClientLike>(client: &C, key: RedisKey, items: MultipleValues) -> RedisResult<RedisValue> {
+  args_values_cmd(client, RedisCommandKind::BfMExists, key_and_values(key, items)).await
+}
+
+pub async fn bf_reserve<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  error_rate: f64,
+  capacity: u64,
+  expansion: Option<u64>,
+  nonscaling: bool,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(6);
+    args.push(key.into());
+    args.push(error_rate.into());
+    args.push(capacity.try_into()?);
+
+    if let Some(expansion) = expansion {
+      args.push(static_val!(EXPANSION));
+      args.push(expansion.try_into()?);
+    }
+    if nonscaling {
+      args.push(static_val!(NONSCALING));
+    }
+
+    Ok((RedisCommandKind::BfReserve, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn bf_scandump<C: ClientLike>(client: &C, key: RedisKey, iterator: i64) -> RedisResult<RedisValue> {
+  args_values_cmd(client, RedisCommandKind::BfScanDump, vec![key.into(), iterator.into()]).await
+}
+
+pub fn bf_scandump_stream<C>(client: &C, key: RedisKey) -> impl Stream<Item = Result<(i64, Bytes), RedisError>>
+where
+  C: ClientLike + Sync + 'static,
+{
+  scandump_stream(client, RedisCommandKind::BfScanDump, key)
+}
+
+pub async fn cf_add<C: ClientLike>(client: &C, key: RedisKey, item: RedisValue) -> RedisResult<RedisValue> {
+  args_value_cmd(client, RedisCommandKind::CfAdd, vec![key.into(), item]).await
+}
+
+pub async fn cf_addnx<C: ClientLike>(client: &C, key: RedisKey, item: RedisValue) -> RedisResult<RedisValue> {
+  args_value_cmd(client, RedisCommandKind::CfAddNx, vec![key.into(), item]).await
+}
+
+pub async fn cf_count<C: ClientLike>(client: &C, key: RedisKey, item: RedisValue) -> RedisResult<RedisValue> {
+  args_value_cmd(client, RedisCommandKind::CfCount, vec![key.into(), item]).await
+}
+
+pub async fn cf_del<C: ClientLike>(client: &C, key: RedisKey, item: RedisValue) -> RedisResult<RedisValue> {
+  args_value_cmd(client, RedisCommandKind::CfDel, vec![key.into(), item]).await
+}
+
+pub async fn cf_exists<C: ClientLike>(client: &C, key: RedisKey, item: RedisValue) -> RedisResult<RedisValue> {
+  args_value_cmd(client, RedisCommandKind::CfExists, vec![key.into(), item]).await
+}
+
+pub async fn cf_info<C: ClientLike>(client: &C, key: RedisKey) -> RedisResult<RedisValue> {
+  one_arg_values_cmd(client, RedisCommandKind::CfInfo, key.into()).await
+}
+
+pub async fn cf_insert<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  nx: bool,
+  capacity: Option<u64>,
+  nocreate: bool,
+  items: MultipleValues,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(5);
+    args.push(key.into());
+
+    if let Some(capacity) = capacity {
+      args.push(static_val!(CAPACITY));
+      args.push(capacity.try_into()?);
+    }
+    if nocreate {
+      args.push(static_val!(NOCREATE));
+    }
+    add_items(&mut args, items)?;
+
+    let kind = if nx {
+      RedisCommandKind::CfInsertNx
+    } else {
+      RedisCommandKind::CfInsert
+    };
+    Ok((kind, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn cf_loadchunk<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  iterator: i64,
+  data: Bytes,
+) -> RedisResult<RedisValue> {
+  let args = vec![key.into(), iterator.into(), RedisValue::Bytes(data)];
+  args_value_cmd(client, RedisCommandKind::CfLoadChunk, args).await
+}
+
+pub async fn cf_mexists<C: ClientLike>(client: &C, key: RedisKey, items: MultipleValues) -> RedisResult<RedisValue> {
+  args_values_cmd(client, RedisCommandKind::CfMExists, key_and_values(key, items)).await
+}
+
+pub async fn cf_reserve<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  capacity: u64,
+  options: CfReserveOptions,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(8);
+    args.push(key.into());
+    args.push(capacity.try_into()?);
+
+    if let Some(bucket_size) = options.bucket_size {
+      args.push(static_val!(BUCKETSIZE));
+      args.push(bucket_size.try_into()?);
+    }
+    if let Some(max_iterations) = options.max_iterations {
+      args.push(static_val!(MAXITERATIONS));
+      args.push(max_iterations.try_into()?);
+    }
+    if let Some(expansion) = options.expansion {
+      args.push(static_val!(EXPANSION));
+      args.push(expansion.try_into()?);
+    }
+
+    Ok((RedisCommandKind::CfReserve, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn cf_scandump<C: ClientLike>(client: &C, key: RedisKey, iterator: i64) -> RedisResult<RedisValue> {
+  args_values_cmd(client, RedisCommandKind::CfScanDump, vec![key.into(), iterator.into()]).await
+}
+
+pub fn cf_scandump_stream<C>(client: &C, key: RedisKey) -> impl Stream<Item = Result<(i64, Bytes), RedisError>>
+where
+  C: ClientLike + Sync + 'static,
+{
+  scandump_stream(client, RedisCommandKind::CfScanDump, key)
+}
+
+pub async fn cms_incrby<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  increments: Vec<(RedisValue, i64)>,
+) -> RedisResult<RedisValue> {
+  let mut args = Vec::with_capacity(1 + increments.len() * 2);
+  args.push(key.into());
+  add_increments(&mut args, increments);
+
+  args_values_cmd(client, RedisCommandKind::CmsIncrBy, args).await
+}
+
+pub async fn cms_info<C: ClientLike>(client: &C, key: RedisKey) -> RedisResult<RedisValue> {
+  one_arg_values_cmd(client, RedisCommandKind::CmsInfo, key.into()).await
+}
+
+pub async fn cms_initbydim<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  width: u64,
+  depth: u64,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::CmsInitByDim, vec![
+      key.into(),
+      width.try_into()?,
+      depth.try_into()?,
+    ]))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn cms_initbyprob<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  error: f64,
+  probability: f64,
+) -> RedisResult<RedisValue> {
+  let args = vec![key.into(), error.into(), probability.into()];
+  args_value_cmd(client, RedisCommandKind::CmsInitByProb, args).await
+}
+
+pub async fn cms_merge<C: ClientLike>(
+  client: &C,
+  destination: RedisKey,
+  sources: MultipleKeys,
+  weights: Vec<i64>,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    if !weights.is_empty() && weights.len() != sources.len() {
+      return Err(RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        "Expected one weight for each source key.",
+      ));
+    }
+
+    let mut args = Vec::with_capacity(3 + sources.len() + weights.len());
+    args.push(destination.into());
+    args.push(sources.len().try_into()?);
+    args.extend(sources.inner().into_iter().map(|k| k.into()));
+    if !weights.is_empty() {
+      args.push(static_val!(WEIGHTS));
+      args.extend(weights.into_iter().map(|w| w.into()));
+    }
+
+    Ok((RedisCommandKind::CmsMerge, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn cms_query<C: ClientLike>(client: &C, key: RedisKey, items: MultipleValues) -> RedisResult<RedisValue> {
+  args_values_cmd(client, RedisCommandKind::CmsQuery, key_and_values(key, items)).await
+}
+
+pub async fn topk_add<C: ClientLike>(client: &C, key: RedisKey, items: MultipleValues) -> RedisResult<RedisValue> {
+  args_values_cmd(client, RedisCommandKind::TopKAdd, key_and_values(key, items)).await
+}
+
+pub async fn topk_incrby<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  increments: Vec<(RedisValue, i64)>,
+) -> RedisResult<RedisValue> {
+  let mut args = Vec::with_capacity(1 + increments.len() * 2);
+  args.push(key.into());
+  add_increments(&mut args, increments);
+
+  args_values_cmd(client, RedisCommandKind::TopKIncrBy, args).await
+}
+
+pub async fn topk_info<C: ClientLike>(client: &C, key: RedisKey) -> RedisResult<RedisValue> {
+  one_arg_values_cmd(client, RedisCommandKind::TopKInfo, key.into()).await
+}
+
+pub async fn topk_list<C: ClientLike>(client: &C, key: RedisKey, withcount: bool) -> RedisResult<RedisValue> {
+  let mut args = Vec::with_capacity(2);
+  args.push(key.into());
+  if withcount {
+    args.push(static_val!(WITHCOUNT));
+  }
+
+  args_values_cmd(client, RedisCommandKind::TopKList, args).await
+}
+
+pub async fn topk_query<C: ClientLike>(client: &C, key: RedisKey, items: MultipleValues) -> RedisResult<RedisValue> {
+  args_values_cmd(client, RedisCommandKind::TopKQuery, key_and_values(key, items)).await
+}
+
+pub async fn topk_reserve<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  topk: u64,
+  dimensions: Option<TopKDimensions>,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(5);
+    args.push(key.into());
+    args.push(topk.try_into()?);
+
+    if let Some(dimensions) = dimensions {
+      args.push(dimensions.width.try_into()?);
+      args.push(dimensions.depth.try_into()?);
+      args.push(dimensions.decay.into());
+    }
+
+    Ok((RedisCommandKind::TopKReserve, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn tdigest_add<C: ClientLike>(client: &C, key: RedisKey, values: Vec<f64>) -> RedisResult<RedisValue> {
+  args_value_cmd(client, RedisCommandKind::TDigestAdd, key_and_floats(key, values)).await
+}
+
+pub async fn tdigest_byrank<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  ranks: Vec<i64>,
+  rev: bool,
+) -> RedisResult<RedisValue> {
+  let mut args = Vec::with_capacity(1 + ranks.len());
+  args.push(key.into());
+  args.extend(ranks.into_iter().map(|r| r.into()));
+
+  let kind = if rev {
+    RedisCommandKind::TDigestByRevRank
+  } else {
+    RedisCommandKind::TDigestByRank
+  };
+  args_values_cmd(client, kind, args).await
+}
+
+pub async fn tdigest_cdf<C: ClientLike>(client: &C, key: RedisKey, values: Vec<f64>) -> RedisResult<RedisValue> {
+  args_values_cmd(client, RedisCommandKind::TDigestCdf, key_and_floats(key, values)).await
+}
+
+pub async fn tdigest_create<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  compression: Option<u64>,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(3);
+    args.push(key.into());
+    if let Some(compression) = compression {
+      args.push(static_val!(COMPRESSION));
+      args.push(compression.try_into()?);
+    }
+
+    Ok((RedisCommandKind::TDigestCreate, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn tdigest_info<C: ClientLike>(client: &C, key: RedisKey) -> RedisResult<RedisValue> {
+  one_arg_values_cmd(client, RedisCommandKind::TDigestInfo, key.into()).await
+}
+
+pub async fn tdigest_max<C: ClientLike>(client: &C, key: RedisKey) -> RedisResult<RedisValue> {
+  one_arg_value_cmd(client, RedisCommandKind::TDigestMax, key.into()).await
+}
+
+pub async fn tdigest_merge<C: ClientLike>(
+  client: &C,
+  destination: RedisKey,
+  sources: MultipleKeys,
+  compression: Option<u64>,
+  r#override: bool,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(5 + sources.len());
+    args.push(destination.into());
+    args.push(sources.len().try_into()?);
+    args.extend(sources.inner().into_iter().map(|k| k.into()));
+
+    if let Some(compression) = compression {
+      args.push(static_val!(COMPRESSION));
+      args.push(compression.try_into()?);
+    }
+    if r#override {
+      args.push(static_val!(OVERRIDE));
+    }
+
+    Ok((RedisCommandKind::TDigestMerge, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn tdigest_min<C: ClientLike>(client: &C, key: RedisKey) -> RedisResult<RedisValue> {
+  one_arg_value_cmd(client, RedisCommandKind::TDigestMin, key.into()).await
+}
+
+pub async fn tdigest_quantile<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  quantiles: Vec<f64>,
+) -> RedisResult<RedisValue> {
+  args_values_cmd(
+    client,
+    RedisCommandKind::TDigestQuantile,
+    key_and_floats(key, quantiles),
+  )
+  .await
+}
+
+pub async fn tdigest_rank<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  values: Vec<f64>,
+  rev: bool,
+) -> RedisResult<RedisValue> {
+  let kind = if rev {
+    RedisCommandKind::TDigestRevRank
+  } else {
+    RedisCommandKind::TDigestRank
+  };
+  args_values_cmd(client, kind, key_and_floats(key, values)).await
+}
+
+pub async fn tdigest_reset<C: ClientLike>(client: &C, key: RedisKey) -> RedisResult<RedisValue> {
+  one_arg_value_cmd(client, RedisCommandKind::TDigestReset, key.into()).await
+}
+
+pub async fn tdigest_trimmed_mean<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  low_cut_quantile: f64,
+  high_cut_quantile: f64,
+) -> RedisResult<RedisValue> {
+  let args = vec![key.into(), low_cut_quantile.into(), high_cut_quantile.into()];
+  args_value_cmd(client, RedisCommandKind::TDigestTrimmedMean, args).await
+}
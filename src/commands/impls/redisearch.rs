@@ -0,0 +1,961 @@
+use crate::{
+  error::{RedisError, RedisErrorKind},
+  interfaces::{ClientLike, RedisResult},
+  protocol::{command::RedisCommandKind, utils as protocol_utils},
+  types::{
+    AggregateOperation,
+    AggregateResults,
+    FtAggregateOptions,
+    FtCreateOptions,
+    FtSearchOptions,
+    Load,
+    MultipleStrings,
+    RedisValue,
+    SearchField,
+    SearchParameter,
+    SearchSchema,
+    SearchSchemaKind,
+    SearchSortBy,
+    SortOrder,
+    SpellcheckTerms,
+    VectorAlgorithm,
+    VectorField,
+    KNN_SCORE_FIELD,
+  },
+  utils,
+};
+use bytes::Bytes;
+use bytes_utils::Str;
+use futures::stream::{self, Stream};
+
+static DD: &str = "DD";
+static DIALECT: &str = "DIALECT";
+static DISTANCE: &str = "DISTANCE";
+static INCLUDE: &str = "INCLUDE";
+static EXCLUDE: &str = "EXCLUDE";
+static TERMS: &str = "TERMS";
+static COUNT: &str = "COUNT";
+static SKIPINITIALSCAN: &str = "SKIPINITIALSCAN";
+static SCHEMA: &str = "SCHEMA";
+static ADD: &str = "ADD";
+static AS: &str = "AS";
+static ON: &str = "ON";
+static PREFIX: &str = "PREFIX";
+static FILTER: &str = "FILTER";
+static LANGUAGE: &str = "LANGUAGE";
+static LANGUAGE_FIELD: &str = "LANGUAGE_FIELD";
+static SCORE: &str = "SCORE";
+static SCORE_FIELD: &str = "SCORE_FIELD";
+static PAYLOAD_FIELD: &str = "PAYLOAD_FIELD";
+static MAXTEXTFIELDS: &str = "MAXTEXTFIELDS";
+static TEMPORARY: &str = "TEMPORARY";
+static NOOFFSETS: &str = "NOOFFSETS";
+static NOHL: &str = "NOHL";
+static NOFIELDS: &str = "NOFIELDS";
+static NOFREQS: &str = "NOFREQS";
+static STOPWORDS: &str = "STOPWORDS";
+static TEXT: &str = "TEXT";
+static TAG: &str = "TAG";
+static TAGS: &str = "TAGS";
+static NUMERIC: &str = "NUMERIC";
+static GEO: &str = "GEO";
+static VECTOR: &str = "VECTOR";
+static SORTABLE: &str = "SORTABLE";
+static UNF: &str = "UNF";
+static NOSTEM: &str = "NOSTEM";
+static PHONETIC: &str = "PHONETIC";
+static WEIGHT: &str = "WEIGHT";
+static WITHSUFFIXTRIE: &str = "WITHSUFFIXTRIE";
+static NOINDEX: &str = "NOINDEX";
+static SEPARATOR: &str = "SEPARATOR";
+static CASESENSITIVE: &str = "CASESENSITIVE";
+static NOCONTENT: &str = "NOCONTENT";
+static VERBATIM: &str = "VERBATIM";
+static NOSTOPWORDS: &str = "NOSTOPWORDS";
+static WITHSCORES: &str = "WITHSCORES";
+static WITHPAYLOADS: &str = "WITHPAYLOADS";
+static WITHSORTKEYS: &str = "WITHSORTKEYS";
+static GEOFILTER: &str = "GEOFILTER";
+static INKEYS: &str = "INKEYS";
+static INFIELDS: &str = "INFIELDS";
+static RETURN: &str = "RETURN";
+static SUMMARIZE: &str = "SUMMARIZE";
+static FIELDS: &str = "FIELDS";
+static FRAGS: &str = "FRAGS";
+static LEN: &str = "LEN";
+static HIGHLIGHT: &str = "HIGHLIGHT";
+static SLOP: &str = "SLOP";
+static TIMEOUT: &str = "TIMEOUT";
+static INORDER: &str = "INORDER";
+static EXPANDER: &str = "EXPANDER";
+static SCORER: &str = "SCORER";
+static EXPLAINSCORE: &str = "EXPLAINSCORE";
+static PAYLOAD: &str = "PAYLOAD";
+static SORTBY: &str = "SORTBY";
+static WITHCOUNT: &str = "WITHCOUNT";
+static LIMIT: &str = "LIMIT";
+static PARAMS: &str = "PARAMS";
+static LOAD: &str = "LOAD";
+static GROUPBY: &str = "GROUPBY";
+static REDUCE: &str = "REDUCE";
+static APPLY: &str = "APPLY";
+static MAX: &str = "MAX";
+static WITHCURSOR: &str = "WITHCURSOR";
+static MAXIDLE: &str = "MAXIDLE";
+static TYPE: &str = "TYPE";
+static DIM: &str = "DIM";
+static DISTANCE_METRIC: &str = "DISTANCE_METRIC";
+static INITIAL_CAP: &str = "INITIAL_CAP";
+static BLOCK_SIZE: &str = "BLOCK_SIZE";
+static M: &str = "M";
+static EF_CONSTRUCTION: &str = "EF_CONSTRUCTION";
+static EF_RUNTIME: &str = "EF_RUNTIME";
+static EPSILON: &str = "EPSILON";
+static KNN_VECTOR_PARAM: &str = "__knn_vector";
+static KNN_DEFAULT_DIALECT: i64 = 2;
+
+fn bool_args(args: &mut Vec<RedisValue>, flags: &[(bool, &'static str)]) {
+  for (flag, name) in flags.iter() {
+    if *flag {
+      args.push(static_val!(*name));
+    }
+  }
+}
+
+fn add_dialect(args: &mut Vec<RedisValue>, dialect: Option<i64>) {
+  if let Some(dialect) = dialect {
+    args.push(static_val!(DIALECT));
+    args.push(dialect.into());
+  }
+}
+
+fn add_params(args: &mut Vec<RedisValue>, params: Vec<SearchParameter>) -> Result<(), RedisError> {
+  if !params.is_empty() {
+    args.push(static_val!(PARAMS));
+    args.push((params.len() * 2).try_into()?);
+    for param in params.into_iter() {
+      args.push(param.name.into());
+      args.push(param.value);
+    }
+  }
+  Ok(())
+}
+
+fn add_search_fields(args: &mut Vec<RedisValue>, fields: Vec<SearchField>) -> Result<(), RedisError> {
+  let count: usize = fields.iter().map(|f| f.args_len()).sum();
+  args.push(count.try_into()?);
+
+  for field in fields.into_iter() {
+    args.push(field.identifier.into());
+    if let Some(property) = field.property {
+      args.push(static_val!(AS));
+      args.push(property.into());
+    }
+  }
+  Ok(())
+}
+
+fn add_vector_field(args: &mut Vec<RedisValue>, field: VectorField) -> Result<(), RedisError> {
+  args.push(static_val!(VECTOR));
+  args.push(field.algorithm.to_str().into());
+  args.push(field.args_len().try_into()?);
+
+  args.push(static_val!(TYPE));
+  args.push(field.data_type.to_str().into());
+  args.push(static_val!(DIM));
+  args.push(field.dim.try_into()?);
+  args.push(static_val!(DISTANCE_METRIC));
+  args.push(field.distance_metric.to_str().into());
+  if let Some(initial_cap) = field.initial_cap {
+    args.push(static_val!(INITIAL_CAP));
+    args.push(initial_cap.try_into()?);
+  }
+
+  match field.algorithm {
+    VectorAlgorithm::Flat { block_size } => {
+      if let Some(block_size) = block_size {
+        args.push(static_val!(BLOCK_SIZE));
+        args.push(block_size.try_into()?);
+      }
+    },
+    VectorAlgorithm::Hnsw {
+      m,
+      ef_construction,
+      ef_runtime,
+      epsilon,
+    } => {
+      if let Some(m) = m {
+        args.push(static_val!(M));
+        args.push(m.try_into()?);
+      }
+      if let Some(ef_construction) = ef_construction {
+        args.push(static_val!(EF_CONSTRUCTION));
+        args.push(ef_construction.try_into()?);
+      }
+      if let Some(ef_runtime) = ef_runtime {
+        args.push(static_val!(EF_RUNTIME));
+        args.push(ef_runtime.try_into()?);
+      }
+      if let Some(epsilon) = epsilon {
+        args.push(static_val!(EPSILON));
+        args.push(epsilon.into());
+      }
+    },
+  };
+  bool_args(args, &[(field.noindex, NOINDEX)]);
+
+  Ok(())
+}
+
+fn add_schema(args: &mut Vec<RedisValue>, schema: SearchSchema) -> Result<(), RedisError> {
+  args.push(schema.field_name.into());
+  if let Some(alias) = schema.alias {
+    args.push(static_val!(AS));
+    args.push(alias.into());
+  }
+
+  match schema.kind {
+    SearchSchemaKind::Text {
+      sortable,
+      unf,
+      nostem,
+      phonetic,
+      weight,
+      withsuffixtrie,
+      noindex,
+    } => {
+      args.push(static_val!(TEXT));
+      bool_args(args, &[(nostem, NOSTEM)]);
+      if let Some(weight) = weight {
+        args.push(static_val!(WEIGHT));
+        args.push(weight.into());
+      }
+      if let Some(phonetic) = phonetic {
+        args.push(static_val!(PHONETIC));
+        args.push(phonetic.into());
+      }
+      bool_args(args, &[
+        (withsuffixtrie, WITHSUFFIXTRIE),
+        (sortable, SORTABLE),
+        (sortable && unf, UNF),
+        (noindex, NOINDEX),
+      ]);
+    },
+    SearchSchemaKind::Tag {
+      sortable,
+      unf,
+      separator,
+      casesensitive,
+      withsuffixtrie,
+      noindex,
+    } => {
+      args.push(static_val!(TAG));
+      if let Some(separator) = separator {
+        args.push(static_val!(SEPARATOR));
+        args.push(separator.to_string().into());
+      }
+      bool_args(args, &[
+        (casesensitive, CASESENSITIVE),
+        (withsuffixtrie, WITHSUFFIXTRIE),
+        (sortable, SORTABLE),
+        (sortable && unf, UNF),
+        (noindex, NOINDEX),
+      ]);
+    },
+    SearchSchemaKind::Numeric { sortable, unf, noindex } => {
+      args.push(static_val!(NUMERIC));
+      bool_args(args, &[
+        (sortable, SORTABLE),
+        (sortable && unf, UNF),
+        (noindex, NOINDEX),
+      ]);
+    },
+    SearchSchemaKind::Geo { sortable, unf, noindex } => {
+      args.push(static_val!(GEO));
+      bool_args(args, &[
+        (sortable, SORTABLE),
+        (sortable && unf, UNF),
+        (noindex, NOINDEX),
+      ]);
+    },
+    SearchSchemaKind::Vector(field) => add_vector_field(args, field)?,
+    SearchSchemaKind::Custom { name, arguments } => {
+      args.push(name.into());
+      args.extend(arguments);
+    },
+  };
+
+  Ok(())
+}
+
+fn gen_ft_create_args(
+  index: Str,
+  options: FtCreateOptions,
+  schema: Vec<SearchSchema>,
+) -> Result<Vec<RedisValue>, RedisError> {
+  let schema_len: usize = schema.iter().map(|s| s.args_len()).sum();
+  let mut args = Vec::with_capacity(28 + options.prefixes.len() + schema_len);
+  args.push(index.into());
+
+  if let Some(kind) = options.on {
+    args.push(static_val!(ON));
+    args.push(kind.to_str().into());
+  }
+  if !options.prefixes.is_empty() {
+    args.push(static_val!(PREFIX));
+    args.push(options.prefixes.len().try_into()?);
+    args.extend(options.prefixes.into_iter().map(|p| p.into()));
+  }
+  if let Some(filter) = options.filter {
+    args.push(static_val!(FILTER));
+    args.push(filter.into());
+  }
+  if let Some(language) = options.language {
+    args.push(static_val!(LANGUAGE));
+    args.push(language.into());
+  }
+  if let Some(field) = options.language_field {
+    args.push(static_val!(LANGUAGE_FIELD));
+    args.push(field.into());
+  }
+  if let Some(score) = options.score {
+    args.push(static_val!(SCORE));
+    args.push(score.into());
+  }
+  if let Some(field) = options.score_field {
+    args.push(static_val!(SCORE_FIELD));
+    args.push(field.into());
+  }
+  if let Some(field) = options.payload_field {
+    args.push(static_val!(PAYLOAD_FIELD));
+    args.push(field.into());
+  }
+  bool_args(&mut args, &[(options.maxtextfields, MAXTEXTFIELDS)]);
+  if let Some(temporary) = options.temporary {
+    args.push(static_val!(TEMPORARY));
+    args.push(temporary.try_into()?);
+  }
+  bool_args(&mut args, &[
+    (options.nooffsets, NOOFFSETS),
+    (options.nohl, NOHL),
+    (options.nofields, NOFIELDS),
+    (options.nofreqs, NOFREQS),
+  ]);
+  if let Some(stopwords) = options.stopwords {
+    args.push(static_val!(STOPWORDS));
+    args.push(stopwords.len().try_into()?);
+    args.extend(stopwords.into_iter().map(|s| s.into()));
+  }
+  bool_args(&mut args, &[(options.skipinitialscan, SKIPINITIALSCAN)]);
+
+  args.push(static_val!(SCHEMA));
+  for field in schema.into_iter() {
+    add_schema(&mut args, field)?;
+  }
+  Ok(args)
+}
+
+fn gen_ft_search_args(index: Str, query: Str, options: FtSearchOptions) -> Result<Vec<RedisValue>, RedisError> {
+  let mut args = Vec::with_capacity(64);
+  args.push(index.into());
+  args.push(query.into());
+
+  bool_args(&mut args, &[
+    (options.nocontent, NOCONTENT),
+    (options.verbatim, VERBATIM),
+    (options.nostopwords, NOSTOPWORDS),
+    (options.withscores, WITHSCORES),
+    (options.withpayloads, WITHPAYLOADS),
+    (options.withsortkeys, WITHSORTKEYS),
+  ]);
+  for filter in options.filters.into_iter() {
+    args.push(static_val!(FILTER));
+    args.push(filter.attribute.into());
+    args.push(filter.min.into_arg());
+    args.push(filter.max.into_arg());
+  }
+  for filter in options.geofilters.into_iter() {
+    args.push(static_val!(GEOFILTER));
+    args.push(filter.attribute.into());
+    args.push(filter.position.longitude.into());
+    args.push(filter.position.latitude.into());
+    args.push(filter.radius.into_arg());
+    args.push(filter.units.to_str().into());
+  }
+  if !options.inkeys.is_empty() {
+    args.push(static_val!(INKEYS));
+    args.push(options.inkeys.len().try_into()?);
+    args.extend(options.inkeys.into_iter().map(|k| k.into()));
+  }
+  if !options.infields.is_empty() {
+    args.push(static_val!(INFIELDS));
+    args.push(options.infields.len().try_into()?);
+    args.extend(options.infields.into_iter().map(|f| f.into()));
+  }
+  if !options.r#return.is_empty() {
+    args.push(static_val!(RETURN));
+    add_search_fields(&mut args, options.r#return)?;
+  }
+  if let Some(summarize) = options.summarize {
+    args.push(static_val!(SUMMARIZE));
+    if !summarize.fields.is_empty() {
+      args.push(static_val!(FIELDS));
+      args.push(summarize.fields.len().try_into()?);
+      args.extend(summarize.fields.into_iter().map(|f| f.into()));
+    }
+    if let Some(frags) = summarize.frags {
+      args.push(static_val!(FRAGS));
+      args.push(frags.try_into()?);
+    }
+    if let Some(len) = summarize.len {
+      args.push(static_val!(LEN));
+      args.push(len.try_into()?);
+    }
+    if let Some(separator) = summarize.separator {
+      args.push(static_val!(SEPARATOR));
+      args.push(separator.into());
+    }
+  }
+  if let Some(highlight) = options.highlight {
+    args.push(static_val!(HIGHLIGHT));
+    if !highlight.fields.is_empty() {
+      args.push(static_val!(FIELDS));
+      args.push(highlight.fields.len().try_into()?);
+      args.extend(highlight.fields.into_iter().map(|f| f.into()));
+    }
+    if let Some((open, close)) = highlight.tags {
+      args.push(static_val!(TAGS));
+      args.push(open.into());
+      args.push(close.into());
+    }
+  }
+  if let Some(slop) = options.slop {
+    args.push(static_val!(SLOP));
+    args.push(slop.into());
+  }
+  if let Some(timeout) = options.timeout {
+    args.push(static_val!(TIMEOUT));
+    args.push(timeout.into());
+  }
+  bool_args(&mut args, &[(options.inorder, INORDER)]);
+  if let Some(language) = options.language {
+    args.push(static_val!(LANGUAGE));
+    args.push(language.into());
+  }
+  if let Some(expander) = options.expander {
+    args.push(static_val!(EXPANDER));
+    args.push(expander.into());
+  }
+  if let Some(scorer) = options.scorer {
+    args.push(static_val!(SCORER));
+    args.push(scorer.into());
+  }
+  bool_args(&mut args, &[(options.explainscore, EXPLAINSCORE)]);
+  if let Some(payload) = options.payload {
+    args.push(static_val!(PAYLOAD));
+    args.push(payload.into());
+  }
+  if let Some(sortby) = options.sortby {
+    args.push(static_val!(SORTBY));
+    args.push(sortby.attribute.into());
+    if let Some(order) = sortby.order {
+      args.push(order.to_str().into());
+    }
+    bool_args(&mut args, &[(sortby.withcount, WITHCOUNT)]);
+  }
+  if let Some((offset, num)) = options.limit {
+    args.push(static_val!(LIMIT));
+    args.push(offset.into());
+    args.push(num.into());
+  }
+  add_params(&mut args, options.params)?;
+  add_dialect(&mut args, options.dialect);
+
+  Ok(args)
+}
+
+fn gen_ft_aggregate_args(index: Str, query: Str, options: FtAggregateOptions) -> Result<Vec<RedisValue>, RedisError> {
+  let mut args = Vec::with_capacity(32);
+  args.push(index.into());
+  args.push(query.into());
+
+  bool_args(&mut args, &[(options.verbatim, VERBATIM)]);
+  if let Some(load) = options.load {
+    args.push(static_val!(LOAD));
+    match load {
+      Load::All => args.push(static_val!("*")),
+      Load::Some(fields) => add_search_fields(&mut args, fields)?,
+    };
+  }
+  if let Some(timeout) = options.timeout {
+    args.push(static_val!(TIMEOUT));
+    args.push(timeout.into());
+  }
+
+  for operation in options.pipeline.into_iter() {
+    match operation {
+      AggregateOperation::GroupBy { fields, reducers } => {
+        args.push(static_val!(GROUPBY));
+        args.push(fields.len().try_into()?);
+        args.extend(fields.into_iter().map(|f| f.into()));
+
+        for reducer in reducers.into_iter() {
+          args.push(static_val!(REDUCE));
+          args.push(reducer.func.to_str().into());
+          args.push(reducer.args.len().try_into()?);
+          args.extend(reducer.args.into_iter().map(|a| a.into()));
+          if let Some(name) = reducer.name {
+            args.push(static_val!(AS));
+            args.push(name.into());
+          }
+        }
+      },
+      AggregateOperation::SortBy { properties, max } => {
+        args.push(static_val!(SORTBY));
+        args.push((properties.len() * 2).try_into()?);
+        for (property, order) in properties.into_iter() {
+          args.push(property.into());
+          args.push(order.to_str().into());
+        }
+        if let Some(max) = max {
+          args.push(static_val!(MAX));
+          args.push(max.try_into()?);
+        }
+      },
+      AggregateOperation::Apply { expression, name } => {
+        args.push(static_val!(APPLY));
+        args.push(expression.into());
+        args.push(static_val!(AS));
+        args.push(name.into());
+      },
+      AggregateOperation::Limit { offset, num } => {
+        args.push(static_val!(LIMIT));
+        args.push(offset.into());
+        args.push(num.into());
+      },
+      AggregateOperation::Filter { expression } => {
+        args.push(static_val!(FILTER));
+        args.push(expression.into());
+      },
+    }
+  }
+
+  if let Some(cursor) = options.cursor {
+    args.push(static_val!(WITHCURSOR));
+    if let Some(count) = cursor.count {
+      args.push(static_val!(COUNT));
+      args.push(count.try_into()?);
+    }
+    if let Some(idle) = cursor.max_idle {
+      args.push(static_val!(MAXIDLE));
+      args.push(idle.try_into()?);
+    }
+  }
+  add_params(&mut args, options.params)?;
+  add_dialect(&mut args, options.dialect);
+
+  Ok(args)
+}
+
+pub async fn ft_list<C: ClientLike>(client: &C) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || Ok((RedisCommandKind::FtList, vec![]))).await?;
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_create<C: ClientLike>(
+  client: &C,
+  index: Str,
+  options: FtCreateOptions,
+  schema: Vec<SearchSchema>,
+) -> RedisResult<RedisValue> {
+  if schema.is_empty() {
+    return Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "FT.CREATE requires at least one schema field.",
+    ));
+  }
+
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::FtCreate, gen_ft_create_args(index, options, schema)?))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_search<C: ClientLike>(
+  client: &C,
+  index: Str,
+  query: Str,
+  options: FtSearchOptions,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::FtSearch, gen_ft_search_args(index, query, options)?))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_search_knn<C: ClientLike>(
+  client: &C,
+  index: Str,
+  field: Str,
+  vector: Bytes,
+  k: u64,
+  prefilter: Str,
+  mut options: FtSearchOptions,
+) -> RedisResult<RedisValue> {
+  let query = format!(
+    "({})=>[KNN {} @{} ${} AS {}]",
+    prefilter, k, field, KNN_VECTOR_PARAM, KNN_SCORE_FIELD
+  );
+  options.params.push(SearchParameter {
+    name:  utils::static_str(KNN_VECTOR_PARAM),
+    value: RedisValue::Bytes(vector),
+  });
+  if !options.r#return.is_empty() && !options.r#return.iter().any(|f| f.identifier == KNN_SCORE_FIELD) {
+    options.r#return.push(SearchField::from(KNN_SCORE_FIELD));
+  }
+  if options.sortby.is_none() {
+    options.sortby = Some(SearchSortBy {
+      attribute: utils::static_str(KNN_SCORE_FIELD),
+      order:     Some(SortOrder::Asc),
+      withcount: false,
+    });
+  }
+  if options.limit.is_none() {
+    options.limit = Some((0, k as i64));
+  }
+  if options.dialect.is_none() {
+    options.dialect = Some(KNN_DEFAULT_DIALECT);
+  }
+
+  ft_search(client, index, query.into(), options).await
+}
+
+pub async fn ft_aggregate<C: ClientLike>(
+  client: &C,
+  index: Str,
+  query: Str,
+  options: FtAggregateOptions,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    Ok((
+      RedisCommandKind::FtAggregate,
+      gen_ft_aggregate_args(index, query, options)?,
+    ))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+/// The state of an `FT.AGGREGATE` cursor read by [ft_aggregate_stream].
+struct AggregateCursor<C: ClientLike + Sync + 'static> {
+  client: C,
+  index:  Str,
+  query:  Option<(Str, FtAggregateOptions)>,
+  count:  Option<u64>,
+  cursor: u64,
+  done:   bool,
+}
+
+impl<C: ClientLike + Sync + 'static> Drop for AggregateCursor<C> {
+  fn drop(&mut self) {
+    // the caller dropped the stream or an error ended it, so clean up the cursor on the server
+    if self.cursor == 0 {
+      return;
+    }
+
+    let (client, index, cursor) = (self.client.clone(), self.index.clone(), self.cursor);
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+      handle.spawn(async move {
+        let _ = ft_cursor_del(&client, index, cursor).await;
+      });
+    }
+  }
+}
+
+/// Read pages of aggregation results, sending `FT.AGGREGATE` or the next `FT.CURSOR READ` when the stream is polled.
+pub fn ft_aggregate_stream<C>(
+  client: &C,
+  index: Str,
+  query: Str,
+  mut options: FtAggregateOptions,
+) -> impl Stream<Item = Result<AggregateResults, RedisError>>
+where
+  C: ClientLike + Sync + 'static,
+{
+  let count = options.cursor.get_or_insert_with(Default::default).count;
+  let state = AggregateCursor {
+    client: client.clone(),
+    index,
+    query: Some((query, options)),
+    count,
+    cursor: 0,
+    done: false,
+  };
+
+  Box::pin(stream::unfold(state, |mut state| async move {
+    if state.done {
+      return None;
+    }
+
+    let response = match state.query.take() {
+      Some((query, options)) => ft_aggregate(&state.client, state.index.clone(), query, options).await,
+      None => ft_cursor_read(&state.client, state.index.clone(), state.cursor, state.count).await,
+    };
+    match response.and_then(AggregateResults::try_from) {
+      Ok(page) => {
+        state.cursor = page.cursor.unwrap_or(0);
+        state.done = state.cursor == 0;
+        Some((Ok(page), state))
+      },
+      Err(e) => {
+        state.done = true;
+        Some((Err(e), state))
+      },
+    }
+  }))
+}
+
+pub async fn ft_explain<C: ClientLike>(
+  client: &C,
+  index: Str,
+  query: Str,
+  dialect: Option<i64>,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(4);
+    args.push(index.into());
+    args.push(query.into());
+    add_dialect(&mut args, dialect);
+
+    Ok((RedisCommandKind::FtExplain, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_info<C: ClientLike>(client: &C, index: Str) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || Ok((RedisCommandKind::FtInfo, vec![index.into()]))).await?;
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_alter<C: ClientLike>(
+  client: &C,
+  index: Str,
+  skipinitialscan: bool,
+  schema: SearchSchema,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(4 + schema.args_len());
+    args.push(index.into());
+    if skipinitialscan {
+      args.push(static_val!(SKIPINITIALSCAN));
+    }
+    args.push(static_val!(SCHEMA));
+    args.push(static_val!(ADD));
+    add_schema(&mut args, schema)?;
+
+    Ok((RedisCommandKind::FtAlter, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_dropindex<C: ClientLike>(client: &C, index: Str, dd: bool) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(2);
+    args.push(index.into());
+    if dd {
+      args.push(static_val!(DD));
+    }
+
+    Ok((RedisCommandKind::FtDropIndex, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_cursor_read<C: ClientLike>(
+  client: &C,
+  index: Str,
+  cursor: u64,
+  count: Option<u64>,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(4);
+    args.push(index.into());
+    args.push(cursor.try_into()?);
+    if let Some(count) = count {
+      args.push(static_val!(COUNT));
+      args.push(count.try_into()?);
+    }
+
+    Ok((RedisCommandKind::FtCursorRead, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_cursor_del<C: ClientLike>(client: &C, index: Str, cursor: u64) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::FtCursorDel, vec![index.into(), cursor.try_into()?]))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_aliasadd<C: ClientLike>(client: &C, alias: Str, index: Str) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::FtAliasAdd, vec![alias.into(), index.into()]))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_aliasdel<C: ClientLike>(client: &C, alias: Str) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || Ok((RedisCommandKind::FtAliasDel, vec![alias.into()]))).await?;
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_aliasupdate<C: ClientLike>(client: &C, alias: Str, index: Str) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::FtAliasUpdate, vec![alias.into(), index.into()]))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_syndump<C: ClientLike>(client: &C, index: Str) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || Ok((RedisCommandKind::FtSynDump, vec![index.into()]))).await?;
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_synupdate<C: ClientLike>(
+  client: &C,
+  index: Str,
+  synonym_group_id: Str,
+  skipinitialscan: bool,
+  terms: MultipleStrings,
+) -> RedisResult<RedisValue> {
+  if terms.len() == 0 {
+    return Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "FT.SYNUPDATE requires at least one term.",
+    ));
+  }
+
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(3 + terms.len());
+    args.push(index.into());
+    args.push(synonym_group_id.into());
+    if skipinitialscan {
+      args.push(static_val!(SKIPINITIALSCAN));
+    }
+    args.extend(terms.inner().into_iter().map(|t| t.into()));
+
+    Ok((RedisCommandKind::FtSynUpdate, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn ft_spellcheck<C: ClientLike>(
+  client: &C,
+  index: Str,
+  query: Str,
+  distance: Option<u8>,
+  terms: Option<SpellcheckTerms>,
+  dialect: Option<i64>,
+) -> RedisResult<RedisValue> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(9);
+    args.push(index.into());
+    args.push(query.into());
+
+    if let Some(distance) = distance {
+      args.push(static_val!(DISTANCE));
+      args.push(distance.into());
+    }
+    if let Some(terms) = terms {
+      args.push(static_val!(TERMS));
+      let (kind, dictionary, terms) = match terms {
+        SpellcheckTerms::Include { dictionary, terms } => (INCLUDE, dictionary, terms),
+        SpellcheckTerms::Exclude { dictionary, terms } => (EXCLUDE, dictionary, terms),
+      };
+      args.push(static_val!(kind));
+      args.push(dictionary.into());
+      args.extend(terms.into_iter().map(|t| t.into()));
+    }
+    add_dialect(&mut args, dialect);
+
+    Ok((RedisCommandKind::FtSpellCheck, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::types::{DistanceMetric, SearchHighlight, VectorType};
+
+  #[test]
+  fn should_gen_search_highlight_tags() {
+    let options = FtSearchOptions {
+      highlight: Some(SearchHighlight {
+        fields: vec!["title".into()],
+        tags:   Some(("<b>".into(), "</b>".into())),
+      }),
+      ..Default::default()
+    };
+    let args = gen_ft_search_args("idx".into(), "foo".into(), options).unwrap();
+
+    let expected: Vec<RedisValue> = vec![
+      "idx".into(),
+      "foo".into(),
+      "HIGHLIGHT".into(),
+      "FIELDS".into(),
+      1.into(),
+      "title".into(),
+      "TAGS".into(),
+      "<b>".into(),
+      "</b>".into(),
+    ];
+    assert_eq!(args, expected);
+  }
+
+  #[test]
+  fn should_gen_vector_field_noindex() {
+    let mut field = VectorField::flat(VectorType::Float32, 2, DistanceMetric::L2);
+    field.noindex = true;
+    let mut args = Vec::new();
+    add_vector_field(&mut args, field).unwrap();
+
+    let expected: Vec<RedisValue> = vec![
+      "VECTOR".into(),
+      "FLAT".into(),
+      6.into(),
+      "TYPE".into(),
+      "FLOAT32".into(),
+      "DIM".into(),
+      2.into(),
+      "DISTANCE_METRIC".into(),
+      "L2".into(),
+      "NOINDEX".into(),
+    ];
+    assert_eq!(args, expected);
+  }
+}
@@ -1,7 +1,8 @@
 use super::*;
 use crate::{
+  clients::RedisClient,
   error::*,
-  interfaces,
+  interfaces::{self, KeysInterface},
   modules::inner::RedisClientInner,
   protocol::{
     command::{RedisCommand, RedisCommandKind},
@@ -13,16 +14,96 @@ use crate::{
 };
 use bytes_utils::Str;
 use futures::stream::{Stream, TryStreamExt};
-use std::sync::Arc;
+use parking_lot::Mutex;
+use std::{collections::VecDeque, pin::Pin, sync::Arc, time::Instant};
 use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
 static STARTING_CURSOR: &str = "0";
 
-fn values_args(key: RedisKey, pattern: Str, count: Option<u32>) -> Vec<RedisValue> {
+/// Read the starting cursor from an optional checkpoint, or `None` if the scan already finished.
+fn starting_cursor(checkpoint: Option<ScanCheckpoint>) -> Result<Option<Str>, RedisError> {
+  match checkpoint {
+    None => Ok(Some(utils::static_str(STARTING_CURSOR))),
+    Some(ScanCheckpoint::Cursor(cursor)) => Ok(cursor),
+    Some(ScanCheckpoint::Cluster(_)) => Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "Cannot resume a single node scan from a cluster scan checkpoint.",
+    )),
+  }
+}
+
+/// Read the primary nodes, one hash slot on each node, and the starting cursor on each node for a `scan_cluster`
+/// operation.
+fn cluster_scan_nodes(
+  inner: &Arc<RedisClientInner>,
+  checkpoint: Option<ScanCheckpoint>,
+) -> Result<Vec<(Server, u16, Option<Str>)>, RedisError> {
+  let nodes = inner.with_cluster_state(|state| {
+    Ok(
+      state
+        .unique_hash_slots()
+        .into_iter()
+        .filter_map(|slot| state.get_server(slot).map(|server| (server.clone(), slot)))
+        .collect::<Vec<_>>(),
+    )
+  })?;
+
+  match checkpoint {
+    None => Ok(
+      nodes
+        .into_iter()
+        .map(|(server, slot)| (server, slot, Some(utils::static_str(STARTING_CURSOR))))
+        .collect(),
+    ),
+    Some(ScanCheckpoint::Cluster(mut cursors)) => {
+      let changed = cursors.len() != nodes.len() || nodes.iter().any(|(server, _)| !cursors.contains_key(server));
+      if changed {
+        return Err(RedisError::new(
+          RedisErrorKind::Cluster,
+          "Cluster primary nodes changed since the scan checkpoint was created.",
+        ));
+      }
+
+      Ok(
+        nodes
+          .into_iter()
+          .map(|(server, slot)| {
+            let cursor = cursors.remove(&server).flatten();
+            (server, slot, cursor)
+          })
+          .collect(),
+      )
+    },
+    Some(ScanCheckpoint::Cursor(_)) => Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "Cannot resume a cluster scan from a single node scan checkpoint.",
+    )),
+  }
+}
+
+fn key_args(cursor: Str, pattern: Str, count: Option<u32>, r#type: Option<&ScanType>) -> Vec<RedisValue> {
+  let mut args = Vec::with_capacity(7);
+  args.push(cursor.into());
+  args.push(static_val!(MATCH));
+  args.push(pattern.into());
+
+  if let Some(count) = count {
+    args.push(static_val!(COUNT));
+    args.push(count.into());
+  }
+  if let Some(r#type) = r#type {
+    args.push(static_val!(TYPE));
+    args.push(r#type.to_str().into());
+  }
+
+  args
+}
+
+fn values_args(key: RedisKey, cursor: Str, pattern: Str, count: Option<u32>) -> Vec<RedisValue> {
   let mut args = Vec::with_capacity(6);
   args.push(key.into());
-  args.push(static_val!(STARTING_CURSOR));
+  args.push(cursor.into());
   args.push(static_val!(MATCH));
   args.push(pattern.into());
 
@@ -41,49 +122,139 @@ fn early_error<T: Send + 'static>(tx: &UnboundedSender<Result<T, RedisError>>, e
   });
 }
 
+/// Request the next page in a key scan operation, waiting if a `scan_cluster` operation is paused or rate limited.
+pub fn send_next_page(inner: &Arc<RedisClientInner>, scanner: KeyScanInner) -> Result<(), RedisError> {
+  let (handle, interval) = match scanner.cluster {
+    Some(ref state) => (state.options.handle.clone(), state.options.page_interval()),
+    None => return send_key_scan(inner, scanner),
+  };
+  let ready_at = scanner
+    .requested
+    .and_then(|requested| interval.map(|interval| requested + interval));
+
+  if !handle.is_paused() && ready_at.map(|t| t <= Instant::now()).unwrap_or(true) {
+    send_key_scan(inner, scanner)
+  } else {
+    let inner = inner.clone();
+    tokio::spawn(async move {
+      handle.wait_for_resume().await;
+      if let Some(ready_at) = ready_at {
+        tokio::time::sleep_until(ready_at.into()).await;
+      }
+
+      let tx = scanner.tx.clone();
+      if let Err(e) = send_key_scan(&inner, scanner) {
+        let _ = tx.send(Err(e));
+      }
+    });
+
+    Ok(())
+  }
+}
+
+fn send_key_scan(inner: &Arc<RedisClientInner>, mut scanner: KeyScanInner) -> Result<(), RedisError> {
+  scanner.requested = Some(Instant::now());
+  let cluster_node = scanner.server.clone();
+  #[cfg(feature = "replicas")]
+  let use_replica = scanner
+    .cluster
+    .as_ref()
+    .map(|state| state.options.replicas)
+    .unwrap_or(false);
+  #[cfg(feature = "replicas")]
+  let replica_node = scanner.replica.clone();
+
+  let response = ResponseKind::KeyScan(scanner);
+  let mut command: RedisCommand = (RedisCommandKind::Scan, Vec::new(), response).into();
+  command.cluster_node = cluster_node;
+  #[cfg(feature = "replicas")]
+  {
+    command.use_replica = use_replica;
+    command.replica_node = replica_node;
+  }
+
+  interfaces::default_send_command(inner, command)
+}
+
+/// Start scanning the next node waiting on the concurrency limit in a `scan_cluster` operation, if any.
+pub fn start_pending_cluster_node(inner: &Arc<RedisClientInner>, state: &Arc<ClusterScanState>) {
+  if state.tx.is_closed() {
+    state.pending.lock().clear();
+    return;
+  }
+  let (slot, cursor) = match state.pending.lock().pop_front() {
+    Some(node) => node,
+    None => return,
+  };
+
+  _trace!(inner, "Starting cluster scan with hash slot {}", slot);
+  if let Err(e) = send_next_page(inner, cluster_scanner(state, slot, cursor)) {
+    let _ = state.tx.send(Err(e));
+  }
+}
+
+fn cluster_scanner(state: &Arc<ClusterScanState>, slot: u16, cursor: Str) -> KeyScanInner {
+  let mut args = state.args.clone();
+  args[0] = cursor.into();
+
+  KeyScanInner {
+    hash_slot: Some(slot),
+    args,
+    cursor_idx: 0,
+    tx: state.tx.clone(),
+    server: None,
+    cluster: Some(state.clone()),
+    requested: None,
+    #[cfg(feature = "replicas")]
+    replica: None,
+  }
+}
+
 pub fn scan_cluster(
   inner: &Arc<RedisClientInner>,
   pattern: Str,
   count: Option<u32>,
   r#type: Option<ScanType>,
+  checkpoint: Option<ScanCheckpoint>,
+  options: ScanOptions,
 ) -> impl Stream<Item = Result<ScanResult, RedisError>> {
   let (tx, rx) = unbounded_channel();
 
-  let hash_slots = inner.with_cluster_state(|state| Ok(state.unique_hash_slots()));
-  let hash_slots = match hash_slots {
-    Ok(slots) => slots,
+  let nodes = match cluster_scan_nodes(inner, checkpoint) {
+    Ok(nodes) => nodes,
     Err(e) => {
       early_error(&tx, e);
       return UnboundedReceiverStream::new(rx);
     },
   };
+  let cursors = nodes
+    .iter()
+    .map(|(server, _, cursor)| (server.clone(), cursor.clone()))
+    .collect();
+  let mut pending: VecDeque<_> = nodes
+    .iter()
+    .filter_map(|(_, slot, cursor)| cursor.clone().map(|cursor| (*slot, cursor)))
+    .collect();
+  let nodes = nodes.into_iter().map(|(server, slot, _)| (slot, server)).collect();
+  let max_concurrency = options
+    .max_concurrency
+    .filter(|max| *max > 0)
+    .unwrap_or(pending.len())
+    .min(pending.len());
+  let active: Vec<_> = pending.drain(.. max_concurrency).collect();
 
-  let mut args = Vec::with_capacity(7);
-  args.push(static_val!(STARTING_CURSOR));
-  args.push(static_val!(MATCH));
-  args.push(pattern.into());
-
-  if let Some(count) = count {
-    args.push(static_val!(COUNT));
-    args.push(count.into());
-  }
-  if let Some(r#type) = r#type {
-    args.push(static_val!(TYPE));
-    args.push(r#type.to_str().into());
-  }
+  let state = Arc::new(ClusterScanState {
+    cursors: Mutex::new(cursors),
+    nodes,
+    pending: Mutex::new(pending),
+    args: key_args(utils::static_str(STARTING_CURSOR), pattern, count, r#type.as_ref()),
+    tx: tx.clone(),
+    options,
+  });
 
-  for slot in hash_slots.into_iter() {
+  for (slot, cursor) in active.into_iter() {
     _trace!(inner, "Scan cluster hash slot server: {}", slot);
-    let response = ResponseKind::KeyScan(KeyScanInner {
-      hash_slot:  Some(slot),
-      args:       args.clone(),
-      cursor_idx: 0,
-      tx:         tx.clone(),
-      server:     None,
-    });
-    let command: RedisCommand = (RedisCommandKind::Scan, Vec::new(), response).into();
-
-    if let Err(e) = interfaces::default_send_command(inner, command) {
+    if let Err(e) = send_next_page(inner, cluster_scanner(&state, slot, cursor)) {
       early_error(&tx, e);
       break;
     }
@@ -98,8 +269,17 @@ pub fn scan(
   count: Option<u32>,
   r#type: Option<ScanType>,
   server: Option<Server>,
+  checkpoint: Option<ScanCheckpoint>,
 ) -> impl Stream<Item = Result<ScanResult, RedisError>> {
   let (tx, rx) = unbounded_channel();
+  let cursor = match starting_cursor(checkpoint) {
+    Ok(Some(cursor)) => cursor,
+    Ok(None) => return UnboundedReceiverStream::new(rx),
+    Err(e) => {
+      early_error(&tx, e);
+      return UnboundedReceiverStream::new(rx);
+    },
+  };
 
   let hash_slot = if inner.config.server.is_clustered() {
     if utils::clustered_scan_pattern_has_hash_tag(inner, &pattern) {
@@ -111,30 +291,19 @@ pub fn scan(
     None
   };
 
-  let mut args = Vec::with_capacity(7);
-  args.push(static_val!(STARTING_CURSOR));
-  args.push(static_val!(MATCH));
-  args.push(pattern.into());
-
-  if let Some(count) = count {
-    args.push(static_val!(COUNT));
-    args.push(count.into());
-  }
-  if let Some(r#type) = r#type {
-    args.push(static_val!(TYPE));
-    args.push(r#type.to_str().into());
-  }
-
-  let response = ResponseKind::KeyScan(KeyScanInner {
+  let scanner = KeyScanInner {
     hash_slot,
-    args,
     server,
+    args: key_args(cursor, pattern, count, r#type.as_ref()),
     cursor_idx: 0,
     tx: tx.clone(),
-  });
-  let command: RedisCommand = (RedisCommandKind::Scan, Vec::new(), response).into();
+    cluster: None,
+    requested: None,
+    #[cfg(feature = "replicas")]
+    replica: None,
+  };
 
-  if let Err(e) = interfaces::default_send_command(inner, command) {
+  if let Err(e) = send_key_scan(inner, scanner) {
     early_error(&tx, e);
   }
 
@@ -146,20 +315,25 @@ pub fn hscan(
   key: RedisKey,
   pattern: Str,
   count: Option<u32>,
+  checkpoint: Option<ScanCheckpoint>,
 ) -> impl Stream<Item = Result<HScanResult, RedisError>> {
   let (tx, rx) = unbounded_channel();
-  let args = values_args(key, pattern, count);
+  match starting_cursor(checkpoint) {
+    Ok(Some(cursor)) => {
+      let response = ResponseKind::ValueScan(ValueScanInner {
+        tx:         tx.clone(),
+        cursor_idx: 1,
+        args:       values_args(key, cursor, pattern, count),
+      });
+      let command: RedisCommand = (RedisCommandKind::Hscan, Vec::new(), response).into();
 
-  let response = ResponseKind::ValueScan(ValueScanInner {
-    tx: tx.clone(),
-    cursor_idx: 1,
-    args,
-  });
-  let command: RedisCommand = (RedisCommandKind::Hscan, Vec::new(), response).into();
-
-  if let Err(e) = interfaces::default_send_command(inner, command) {
-    early_error(&tx, e);
-  }
+      if let Err(e) = interfaces::default_send_command(inner, command) {
+        early_error(&tx, e);
+      }
+    },
+    Ok(None) => {},
+    Err(e) => early_error(&tx, e),
+  };
 
   UnboundedReceiverStream::new(rx).try_filter_map(|result| async move {
     match result {
@@ -174,20 +348,25 @@ pub fn sscan(
   key: RedisKey,
   pattern: Str,
   count: Option<u32>,
+  checkpoint: Option<ScanCheckpoint>,
 ) -> impl Stream<Item = Result<SScanResult, RedisError>> {
   let (tx, rx) = unbounded_channel();
-  let args = values_args(key, pattern, count);
-
-  let response = ResponseKind::ValueScan(ValueScanInner {
-    tx: tx.clone(),
-    cursor_idx: 1,
-    args,
-  });
-  let command: RedisCommand = (RedisCommandKind::Sscan, Vec::new(), response).into();
+  match starting_cursor(checkpoint) {
+    Ok(Some(cursor)) => {
+      let response = ResponseKind::ValueScan(ValueScanInner {
+        tx:         tx.clone(),
+        cursor_idx: 1,
+        args:       values_args(key, cursor, pattern, count),
+      });
+      let command: RedisCommand = (RedisCommandKind::Sscan, Vec::new(), response).into();
 
-  if let Err(e) = interfaces::default_send_command(inner, command) {
-    early_error(&tx, e);
-  }
+      if let Err(e) = interfaces::default_send_command(inner, command) {
+        early_error(&tx, e);
+      }
+    },
+    Ok(None) => {},
+    Err(e) => early_error(&tx, e),
+  };
 
   UnboundedReceiverStream::new(rx).try_filter_map(|result| async move {
     match result {
@@ -202,20 +381,25 @@ pub fn zscan(
   key: RedisKey,
   pattern: Str,
   count: Option<u32>,
+  checkpoint: Option<ScanCheckpoint>,
 ) -> impl Stream<Item = Result<ZScanResult, RedisError>> {
   let (tx, rx) = unbounded_channel();
-  let args = values_args(key, pattern, count);
+  match starting_cursor(checkpoint) {
+    Ok(Some(cursor)) => {
+      let response = ResponseKind::ValueScan(ValueScanInner {
+        tx:         tx.clone(),
+        cursor_idx: 1,
+        args:       values_args(key, cursor, pattern, count),
+      });
+      let command: RedisCommand = (RedisCommandKind::Zscan, Vec::new(), response).into();
 
-  let response = ResponseKind::ValueScan(ValueScanInner {
-    tx: tx.clone(),
-    cursor_idx: 1,
-    args,
-  });
-  let command: RedisCommand = (RedisCommandKind::Zscan, Vec::new(), response).into();
-
-  if let Err(e) = interfaces::default_send_command(inner, command) {
-    early_error(&tx, e);
-  }
+      if let Err(e) = interfaces::default_send_command(inner, command) {
+        early_error(&tx, e);
+      }
+    },
+    Ok(None) => {},
+    Err(e) => early_error(&tx, e),
+  };
 
   UnboundedReceiverStream::new(rx).try_filter_map(|result| async move {
     match result {
@@ -224,3 +408,168 @@ pub fn zscan(
     }
   })
 }
+
+/// A bulk operation on each key matching a pattern.
+pub enum BulkOperation<'a> {
+  Unlink,
+  Expire(i64),
+  Copy {
+    destination: &'a RedisClient,
+    replace:     bool,
+  },
+  Dump(&'a mut Vec<KeyDump>),
+}
+
+/// Read the TTL and serialized value of each key, skipping keys that no longer exist.
+async fn dump_keys(client: &RedisClient, keys: Vec<RedisKey>) -> Result<Vec<KeyDump>, RedisError> {
+  let pipeline = client.pipeline();
+  for key in keys.iter() {
+    let _: () = pipeline.pttl(key).await?;
+    let _: () = pipeline.dump(key).await?;
+  }
+  let mut results = pipeline.all::<Vec<RedisValue>>().await?.into_iter();
+
+  let mut dumps = Vec::with_capacity(keys.len());
+  for key in keys.into_iter() {
+    let (ttl, value) = match (results.next(), results.next()) {
+      (Some(ttl), Some(value)) => (ttl, value),
+      _ => break,
+    };
+    let ttl = match ttl.as_i64() {
+      Some(ttl) if ttl != -2 => ttl,
+      _ => continue,
+    };
+    let value = match value {
+      RedisValue::Bytes(value) => value,
+      RedisValue::String(value) => value.into_inner(),
+      _ => continue,
+    };
+
+    dumps.push(KeyDump {
+      key,
+      ttl: if ttl < 0 { None } else { Some(ttl) },
+      value,
+    });
+  }
+  Ok(dumps)
+}
+
+/// Restore the keys on `destination`, returning the number of keys that were restored.
+///
+/// Keys that already exist are skipped unless `replace` is `true`.
+async fn restore_keys(destination: &RedisClient, dumps: Vec<KeyDump>, replace: bool) -> Result<u64, RedisError> {
+  if dumps.is_empty() {
+    return Ok(0);
+  }
+
+  let pipeline = destination.pipeline();
+  for dump in dumps.into_iter() {
+    let ttl = dump.ttl.unwrap_or(0);
+    let _: () = pipeline
+      .restore(dump.key, ttl, dump.value.into(), replace, false, None, None)
+      .await?;
+  }
+
+  let mut restored = 0;
+  for result in pipeline.try_all::<RedisValue>().await.into_iter() {
+    match result {
+      Ok(_) => restored += 1,
+      Err(e) if e.details().starts_with("BUSYKEY") => continue,
+      Err(e) => return Err(e),
+    };
+  }
+  Ok(restored)
+}
+
+/// Run the operation on a batch of keys, returning the number of keys that were processed.
+async fn process_batch(
+  client: &RedisClient,
+  operation: &mut BulkOperation<'_>,
+  keys: Vec<RedisKey>,
+) -> Result<u64, RedisError> {
+  match operation {
+    BulkOperation::Unlink => {
+      let pipeline = client.pipeline();
+      for key in keys.into_iter() {
+        let _: () = pipeline.unlink(key).await?;
+      }
+      Ok(pipeline.all::<Vec<u64>>().await?.into_iter().sum())
+    },
+    BulkOperation::Expire(seconds) => {
+      let pipeline = client.pipeline();
+      for key in keys.into_iter() {
+        let _: () = pipeline.expire(key, *seconds).await?;
+      }
+      Ok(pipeline.all::<Vec<u64>>().await?.into_iter().sum())
+    },
+    BulkOperation::Copy { destination, replace } => {
+      let dumps = dump_keys(client, keys).await?;
+      restore_keys(destination, dumps, *replace).await
+    },
+    BulkOperation::Dump(ref mut out) => {
+      let dumps = dump_keys(client, keys).await?;
+      let processed = dumps.len() as u64;
+      out.extend(dumps);
+      Ok(processed)
+    },
+  }
+}
+
+/// Scan the keys matching `pattern` on each primary node and run the operation on each page of keys, in batches of
+/// up to `batch_size` keys.
+pub async fn process_matching(
+  client: &RedisClient,
+  pattern: Str,
+  options: BulkOptions,
+  mut operation: BulkOperation<'_>,
+) -> Result<BulkProgress, RedisError> {
+  let inner = client.inner();
+  let batch_size = options.batch_size.max(1);
+  let mut progress = BulkProgress {
+    scanned:    0,
+    processed:  0,
+    checkpoint: options
+      .checkpoint
+      .clone()
+      .unwrap_or_else(|| ScanCheckpoint::Cursor(Some(utils::static_str(STARTING_CURSOR)))),
+  };
+
+  let mut scan_stream: Pin<Box<dyn Stream<Item = Result<ScanResult, RedisError>> + Send>> =
+    if inner.config.server.is_clustered() {
+      Box::pin(scan_cluster(
+        inner,
+        pattern,
+        Some(batch_size),
+        None,
+        options.checkpoint,
+        options.scan,
+      ))
+    } else {
+      Box::pin(scan(inner, pattern, Some(batch_size), None, None, options.checkpoint))
+    };
+
+  while let Some(mut page) = scan_stream.try_next().await? {
+    let keys = page.take_results().unwrap_or_default();
+    progress.scanned += keys.len() as u64;
+
+    if !options.dry_run {
+      for batch in keys.chunks(batch_size as usize) {
+        progress.processed += process_batch(client, &mut operation, batch.to_vec()).await?;
+      }
+    }
+    progress.checkpoint = page.checkpoint();
+    if let Some(ref tx) = options.progress {
+      let _ = tx.send(progress.clone());
+    }
+
+    page.next()?;
+  }
+
+  _debug!(
+    inner,
+    "Finished bulk operation after scanning {} keys and processing {} keys.",
+    progress.scanned,
+    progress.processed
+  );
+  Ok(progress)
+}
@@ -7,6 +7,7 @@ use crate::{
   prelude::Resp3Frame,
   protocol::{
     command::{RedisCommand, RedisCommandKind, RouterCommand},
+    hashers,
     responders::ResponseKind,
     utils as protocol_utils,
   },
@@ -14,7 +15,10 @@ use crate::{
   utils,
 };
 use bytes_utils::Str;
-use std::sync::Arc;
+use std::{
+  convert::{TryFrom, TryInto},
+  sync::Arc,
+};
 use tokio::sync::oneshot::channel as oneshot_channel;
 
 pub async fn quit<C: ClientLike>(client: &C) -> Result<(), RedisError> {
@@ -31,6 +35,8 @@ pub async fn quit<C: ClientLike>(client: &C) -> Result<(), RedisError> {
   };
   utils::set_client_state(&inner.state, ClientState::Disconnecting);
   inner.notifications.broadcast_close();
+  #[cfg(feature = "credential-provider")]
+  utils::abort_credential_refresh(&inner);
 
   let timeout_dur = utils::prepare_command(client, &mut command);
   client.send_command(command)?;
@@ -39,6 +45,7 @@ pub async fn quit<C: ClientLike>(client: &C) -> Result<(), RedisError> {
     .notifications
     .close_public_receivers(inner.with_perf_config(|c| c.broadcast_channel_capacity));
   inner.backchannel.write().await.check_and_disconnect(&inner, None).await;
+  inner.dedicated.close_idle().await;
 
   Ok(())
 }
@@ -144,7 +151,12 @@ pub async fn ping<C: ClientLike>(client: &C) -> Result<RedisValue, RedisError> {
 
 pub async fn select<C: ClientLike>(client: &C, db: u8) -> Result<RedisValue, RedisError> {
   let frame = utils::request_response(client, || Ok((RedisCommandKind::Select, vec![db.into()]))).await?;
-  protocol_utils::frame_to_results(frame)
+  let result = protocol_utils::frame_to_results(frame)?;
+
+  // cached values were read from the previous database
+  #[cfg(feature = "client-tracking")]
+  client.inner().cache.clear();
+  Ok(result)
 }
 
 pub async fn info<C: ClientLike>(client: &C, section: Option<InfoKind>) -> Result<RedisValue, RedisError> {
@@ -212,20 +224,122 @@ pub async fn auth<C: ClientLike>(client: &C, username: Option<String>, password:
   }
 }
 
+/// Read the command info used to find the keys in a custom command, preferring the cached value.
+async fn custom_command_info<C: ClientLike>(client: &C, cmd: &Str) -> Result<CommandInfo, RedisError> {
+  let name = Str::from(cmd.to_lowercase());
+  if let Some(info) = client.inner().server_state.write().cached_command_info(&name) {
+    return Ok(info);
+  }
+
+  let info = match command_info(client, name.clone().into()).await?.into_array().pop() {
+    Some(RedisValue::Null) | None => {
+      return Err(RedisError::new(
+        RedisErrorKind::InvalidCommand,
+        format!("Unknown command: {}", cmd),
+      ))
+    },
+    Some(value) => CommandInfo::try_from(value)?,
+  };
+  client
+    .inner()
+    .server_state
+    .write()
+    .cache_command_info(name, info.clone());
+
+  Ok(info)
+}
+
+/// Read the key specs for a command, falling back to the legacy key positions on servers without key specs.
+///
+/// Returns `None` if the keys can only be found via `COMMAND GETKEYS`.
+fn custom_key_specs(info: &CommandInfo) -> Option<Vec<KeySpec>> {
+  if !info.key_specs.is_empty() {
+    Some(info.key_specs.clone())
+  } else if info.flags.iter().any(|f| &**f == "movablekeys") {
+    None
+  } else if info.first_key == 0 {
+    Some(Vec::new())
+  } else {
+    let last_key = if info.last_key < 0 {
+      info.last_key
+    } else {
+      info.last_key - info.first_key
+    };
+
+    Some(vec![KeySpec {
+      begin_search: KeySpecBeginSearch::Index(info.first_key),
+      find_keys:    KeySpecFindKeys::Range {
+        last_key,
+        key_step: info.step,
+        limit: 0,
+      },
+      flags:        Vec::new(),
+      notes:        None,
+    }])
+  }
+}
+
+/// Compute the cluster hash policy for a custom command, resolving `ClusterHash::KeySpecs` to a hash slot.
+///
+/// The `COMMAND` lookups are sent with a new `RedisClient` since pipelines and transactions would queue them with the
+/// caller's commands.
+async fn custom_cluster_hash<C: ClientLike>(
+  client: &C,
+  cmd: &CustomCommand,
+  args: &[RedisValue],
+) -> Result<ClusterHash, RedisError> {
+  if cmd.cluster_hash != ClusterHash::KeySpecs || !client.is_clustered() {
+    return Ok(cmd.cluster_hash.clone());
+  }
+  let client = RedisClient::from(client.inner());
+
+  let info = custom_command_info(&client, &cmd.cmd).await?;
+  let subcommand = args.first().and_then(|arg| arg.as_bytes_str()).and_then(|arg| {
+    let name = format!("{}|{}", info.name, arg);
+    info.subcommands.iter().find(|sub| sub.name.eq_ignore_ascii_case(&name))
+  });
+  let keys = custom_key_specs(subcommand.unwrap_or(&info)).and_then(|specs| hashers::find_spec_keys(&specs, args));
+
+  let slot = match keys {
+    Some(keys) => hashers::hash_same_slot(&keys)?,
+    None => {
+      let mut getkeys_args = Vec::with_capacity(args.len() + 1);
+      getkeys_args.push(cmd.cmd.clone().into());
+      getkeys_args.extend(args.iter().cloned());
+      let keys = command_getkeys(&client, RedisValue::Array(getkeys_args))
+        .await?
+        .into_array();
+
+      hashers::hash_same_slot(&keys.iter().collect::<Vec<_>>())?
+    },
+  };
+
+  Ok(slot.map(ClusterHash::Custom).unwrap_or(ClusterHash::Random))
+}
+
 pub async fn custom<C: ClientLike>(
   client: &C,
   cmd: CustomCommand,
   args: Vec<RedisValue>,
 ) -> Result<RedisValue, RedisError> {
-  args_values_cmd(client, RedisCommandKind::_Custom(cmd), args).await
+  let frame = custom_raw(client, cmd, args).await?;
+  protocol_utils::frame_to_results(frame)
 }
 
 pub async fn custom_raw<C: ClientLike>(
   client: &C,
-  cmd: CustomCommand,
+  mut cmd: CustomCommand,
   args: Vec<RedisValue>,
 ) -> Result<Resp3Frame, RedisError> {
-  utils::request_response(client, move || Ok((RedisCommandKind::_Custom(cmd), args))).await
+  let hasher = custom_cluster_hash(client, &cmd, &args).await?;
+  cmd.cluster_hash = hasher.clone();
+
+  utils::request_response(client, move || {
+    let mut command: RedisCommand = (RedisCommandKind::_Custom(cmd), args).into();
+    command.hasher = hasher;
+    Ok(command)
+  })
+  .await
 }
 
 value_cmd!(dbsize, DBSize);
@@ -275,3 +389,86 @@ pub async fn wait<C: ClientLike>(client: &C, numreplicas: i64, timeout: i64) ->
 
   protocol_utils::frame_to_results(frame)
 }
+
+values_cmd!(command, Command);
+value_cmd!(command_count, CommandCount);
+
+pub async fn command_docs<C: ClientLike>(client: &C, names: MultipleStrings) -> Result<RedisValue, RedisError> {
+  args_values_cmd(client, RedisCommandKind::CommandDocs, names.into_values()).await
+}
+
+pub async fn command_getkeys<C: ClientLike>(client: &C, args: MultipleValues) -> Result<RedisValue, RedisError> {
+  let args = args.into_multiple_values();
+  if args.is_empty() {
+    return Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "Expected at least one argument.",
+    ));
+  }
+
+  args_values_cmd(client, RedisCommandKind::CommandGetKeys, args).await
+}
+
+pub async fn command_info<C: ClientLike>(client: &C, names: MultipleStrings) -> Result<RedisValue, RedisError> {
+  args_values_cmd(client, RedisCommandKind::CommandInfo, names.into_values()).await
+}
+
+value_cmd!(latency_doctor, LatencyDoctor);
+values_cmd!(latency_latest, LatencyLatest);
+
+pub async fn latency_histogram<C: ClientLike>(client: &C, names: MultipleStrings) -> Result<RedisValue, RedisError> {
+  args_values_cmd(client, RedisCommandKind::LatencyHistogram, names.into_values()).await
+}
+
+pub async fn latency_history<C: ClientLike>(client: &C, event: Str) -> Result<RedisValue, RedisError> {
+  one_arg_values_cmd(client, RedisCommandKind::LatencyHistory, event.into()).await
+}
+
+pub async fn latency_reset<C: ClientLike>(client: &C, events: MultipleStrings) -> Result<RedisValue, RedisError> {
+  args_value_cmd(client, RedisCommandKind::LatencyReset, events.into_values()).await
+}
+
+values_cmd!(module_list, ModuleList);
+
+pub async fn module_load<C: ClientLike>(client: &C, path: Str, args: MultipleValues) -> Result<(), RedisError> {
+  let mut args = args.into_multiple_values();
+  args.insert(0, path.into());
+
+  args_ok_cmd(client, RedisCommandKind::ModuleLoad, args).await
+}
+
+pub async fn module_loadex<C: ClientLike>(
+  client: &C,
+  path: Str,
+  configs: Vec<(Str, RedisValue)>,
+  args: MultipleValues,
+) -> Result<(), RedisError> {
+  let args = args.into_multiple_values();
+  let mut out = Vec::with_capacity(2 + configs.len() * 3 + args.len());
+  out.push(path.into());
+
+  for (name, value) in configs.into_iter() {
+    out.push(static_val!("CONFIG"));
+    out.push(name.into());
+    out.push(value);
+  }
+  if !args.is_empty() {
+    out.push(static_val!("ARGS"));
+    out.extend(args);
+  }
+
+  args_ok_cmd(client, RedisCommandKind::ModuleLoadEx, out).await
+}
+
+pub async fn module_unload<C: ClientLike>(client: &C, name: Str) -> Result<(), RedisError> {
+  args_ok_cmd(client, RedisCommandKind::ModuleUnload, vec![name.into()]).await
+}
+
+pub async fn debug_object<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_value_cmd(client, RedisCommandKind::DebugObject, key.into()).await
+}
+
+pub async fn debug_sleep<C: ClientLike>(client: &C, seconds: f64) -> Result<(), RedisError> {
+  let seconds: RedisValue = seconds.try_into()?;
+  args_ok_cmd(client, RedisCommandKind::DebugSleep, vec![seconds]).await
+}
@@ -1 +1,298 @@
+use super::*;
+use crate::{
+  error::*,
+  protocol::{command::RedisCommandKind, utils as protocol_utils},
+  types::*,
+  utils,
+};
+use std::convert::TryInto;
 
+pub async fn get<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_values_cmd(client, RedisCommandKind::Get, key.into()).await
+}
+
+pub async fn set<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  value: RedisValue,
+  expire: Option<Expiration>,
+  options: Option<SetOptions>,
+  get: bool,
+) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(6);
+    args.push(key.into());
+    args.push(value);
+
+    if let Some(expire) = expire {
+      let (k, v) = expire.into_args();
+      args.push(k.into());
+      if let Some(v) = v {
+        args.push(v.into());
+      }
+    }
+    if let Some(options) = options {
+      args.push(options.to_str().into());
+    }
+    if get {
+      args.push(static_val!(GET));
+    }
+
+    Ok((RedisCommandKind::Set, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn append<C: ClientLike>(client: &C, key: RedisKey, value: RedisValue) -> Result<RedisValue, RedisError> {
+  args_value_cmd(client, RedisCommandKind::Append, vec![key.into(), value]).await
+}
+
+pub async fn incr<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_value_cmd(client, RedisCommandKind::Incr, key.into()).await
+}
+
+pub async fn decr<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_value_cmd(client, RedisCommandKind::Decr, key.into()).await
+}
+
+pub async fn incr_by<C: ClientLike>(client: &C, key: RedisKey, val: i64) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::IncrBy, vec![key.into(), val.into()]))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn decr_by<C: ClientLike>(client: &C, key: RedisKey, val: i64) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::DecrBy, vec![key.into(), val.into()]))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn incr_by_float<C: ClientLike>(client: &C, key: RedisKey, val: f64) -> Result<RedisValue, RedisError> {
+  let val: RedisValue = val.try_into()?;
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::IncrByFloat, vec![key.into(), val]))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn getrange<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  start: usize,
+  end: usize,
+) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::GetRange, vec![
+      key.into(),
+      start.try_into()?,
+      end.try_into()?,
+    ]))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn substr<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  start: i64,
+  end: i64,
+) -> Result<RedisValue, RedisError> {
+  args_values_cmd(client, RedisCommandKind::Substr, vec![
+    key.into(),
+    start.into(),
+    end.into(),
+  ])
+  .await
+}
+
+pub async fn setrange<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  offset: u32,
+  value: RedisValue,
+) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    Ok((RedisCommandKind::Setrange, vec![key.into(), offset.into(), value]))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn getset<C: ClientLike>(client: &C, key: RedisKey, value: RedisValue) -> Result<RedisValue, RedisError> {
+  args_values_cmd(client, RedisCommandKind::GetSet, vec![key.into(), value]).await
+}
+
+pub async fn getdel<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_values_cmd(client, RedisCommandKind::GetDel, key.into()).await
+}
+
+pub async fn strlen<C: ClientLike>(client: &C, key: RedisKey) -> Result<RedisValue, RedisError> {
+  one_arg_value_cmd(client, RedisCommandKind::Strlen, key.into()).await
+}
+
+pub async fn mget<C: ClientLike>(client: &C, keys: MultipleKeys) -> Result<RedisValue, RedisError> {
+  utils::check_empty_keys(&keys)?;
+
+  if client.split_cross_slot(None) {
+    let len = keys.len();
+    let args = keys.inner().into_iter().map(|k| (k, None)).collect();
+    return Ok(merge_slot_results(
+      len,
+      split_by_slot_cmd(client, RedisCommandKind::Mget, args).await?,
+    ));
+  }
+
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(keys.len());
+
+    for key in keys.inner().into_iter() {
+      args.push(key.into());
+    }
+
+    Ok((RedisCommandKind::Mget, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn mset<C: ClientLike>(client: &C, values: RedisMap) -> Result<RedisValue, RedisError> {
+  if values.len() == 0 {
+    return Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "Values cannot be empty.",
+    ));
+  }
+
+  if client.split_cross_slot(None) {
+    let args = values.inner().into_iter().map(|(k, v)| (k, Some(v))).collect();
+    split_by_slot_cmd(client, RedisCommandKind::Mset, args).await?;
+    return Ok(RedisValue::new_ok());
+  }
+
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(values.len() * 2);
+
+    for (key, value) in values.inner().into_iter() {
+      args.push(key.into());
+      args.push(value);
+    }
+
+    Ok((RedisCommandKind::Mset, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn msetnx<C: ClientLike>(client: &C, values: RedisMap) -> Result<RedisValue, RedisError> {
+  if values.len() == 0 {
+    return Err(RedisError::new(
+      RedisErrorKind::InvalidArgument,
+      "Values cannot be empty.",
+    ));
+  }
+
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(values.len() * 2);
+
+    for (key, value) in values.inner().into_iter() {
+      args.push(key.into());
+      args.push(value);
+    }
+
+    Ok((RedisCommandKind::Msetnx, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn lcs<C: ClientLike>(
+  client: &C,
+  key1: RedisKey,
+  key2: RedisKey,
+  len: bool,
+  idx: bool,
+  minmatchlen: Option<i64>,
+  withmatchlen: bool,
+) -> Result<RedisValue, RedisError> {
+  let frame = utils::request_response(client, move || {
+    let mut args = Vec::with_capacity(7);
+    args.push(key1.into());
+    args.push(key2.into());
+
+    if len {
+      args.push(static_val!(LEN));
+    }
+    if idx {
+      args.push(static_val!(IDX));
+    }
+    if let Some(minmatchlen) = minmatchlen {
+      args.push(static_val!(MINMATCHLEN));
+      args.push(minmatchlen.into());
+    }
+    if withmatchlen {
+      args.push(static_val!(WITHMATCHLEN));
+    }
+
+    Ok((RedisCommandKind::Lcs, args))
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+pub async fn getex<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  expiration: Option<GetExpiration>,
+) -> Result<RedisValue, RedisError> {
+  let mut args = Vec::with_capacity(3);
+  args.push(key.into());
+  if let Some(expiration) = expiration {
+    let (prefix, value) = expiration.into_args();
+    args.push(prefix.into());
+    if let Some(value) = value {
+      args.push(value.into());
+    }
+  }
+
+  args_values_cmd(client, RedisCommandKind::GetEx, args).await
+}
+
+pub async fn setex<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  seconds: i64,
+  value: RedisValue,
+) -> Result<RedisValue, RedisError> {
+  args_value_cmd(client, RedisCommandKind::Setex, vec![key.into(), seconds.into(), value]).await
+}
+
+pub async fn psetex<C: ClientLike>(
+  client: &C,
+  key: RedisKey,
+  milliseconds: i64,
+  value: RedisValue,
+) -> Result<RedisValue, RedisError> {
+  args_value_cmd(client, RedisCommandKind::Psetex, vec![
+    key.into(),
+    milliseconds.into(),
+    value,
+  ])
+  .await
+}
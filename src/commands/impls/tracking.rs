@@ -1,16 +1,27 @@
 use crate::{
+  clients::RedisClient,
   error::{RedisError, RedisErrorKind},
-  interfaces::ClientLike,
+  interfaces::{ClientInterface, ClientLike, EventInterface, TrackingInterface},
+  modules::inner::RedisClientInner,
   protocol::{
     command::{RedisCommand, RedisCommandKind},
     responders::ResponseKind,
     utils as protocol_utils,
   },
-  types::{ClusterHash, MultipleStrings, RedisValue, Toggle},
+  router::responses::INVALIDATION_CHANNEL,
+  types::{ClientCacheConfig, ClusterHash, Invalidation, MultipleStrings, RedisValue, Toggle},
   utils,
 };
 use redis_protocol::redis_keyslot;
-use tokio::sync::oneshot::channel as oneshot_channel;
+use std::{
+  sync::{Arc, Weak},
+  time::Duration,
+};
+use tokio::{
+  sync::{broadcast::error::RecvError, oneshot::channel as oneshot_channel},
+  task::JoinHandle,
+  time::sleep,
+};
 
 pub static PREFIX: &'static str = "PREFIX";
 pub static REDIRECT: &'static str = "REDIRECT";
@@ -56,6 +67,184 @@ fn tracking_args(
   args
 }
 
+/// A companion connection that receives invalidation messages on the `__redis__:invalidate` channel when client
+/// tracking is used with RESP2.
+pub struct RedirectTracking {
+  client: RedisClient,
+  task:   JoinHandle<()>,
+}
+
+impl Drop for RedirectTracking {
+  fn drop(&mut self) {
+    self.task.abort();
+
+    let client = self.client.clone();
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+      handle.spawn(async move {
+        let _ = client.quit().await;
+      });
+    }
+  }
+}
+
+/// Copy the `CLIENT TRACKING ON` arguments with a `REDIRECT` to the provided client ID.
+fn redirect_args(args: &[RedisValue], id: i64) -> Vec<RedisValue> {
+  let mut out = Vec::with_capacity(args.len() + 2);
+  out.push(static_val!(Toggle::On.to_str()));
+  out.push(static_val!(REDIRECT));
+  out.push(id.into());
+  out.extend(args.iter().skip(1).cloned());
+  out
+}
+
+/// Subscribe the companion to invalidation messages on each primary node and redirect tracking on the client's
+/// connection to the companion's connection on the same node.
+async fn redirect_tracking(
+  client: &RedisClient,
+  companion: &RedisClient,
+  args: &[RedisValue],
+) -> Result<(), RedisError> {
+  let servers = utils::primary_nodes(client);
+  for server in servers.iter() {
+    let args = vec![static_val!(INVALIDATION_CHANNEL)];
+    let _ = utils::node_request(companion, server.clone(), RedisCommandKind::Subscribe, args).await?;
+  }
+
+  let ids = companion.connection_ids().await;
+  for server in servers.into_iter() {
+    let id = match server {
+      Some(ref server) => ids.get(server),
+      None => ids.values().next(),
+    };
+    let id = match id {
+      Some(id) => *id,
+      None => {
+        return Err(RedisError::new(
+          RedisErrorKind::NotFound,
+          "Failed to read invalidation connection ID.",
+        ))
+      },
+    };
+
+    let response = utils::node_request(
+      client,
+      server,
+      RedisCommandKind::ClientTracking,
+      redirect_args(args, id),
+    )
+    .await?;
+    protocol_utils::expect_ok(&response)?;
+  }
+  Ok(())
+}
+
+/// Forward invalidation messages from the companion and redirect tracking again after any reconnection or cluster
+/// change.
+async fn manage_redirect(inner: Weak<RedisClientInner>, companion: RedisClient, args: Vec<RedisValue>) {
+  let (mut reconnect_rx, mut cluster_rx) = match inner.upgrade() {
+    Some(inner) => (
+      inner.notifications.reconnect.load().subscribe(),
+      inner.notifications.cluster_change.load().subscribe(),
+    ),
+    None => return,
+  };
+  let mut companion_rx = companion.reconnect_rx();
+  let mut invalidation_rx = companion.invalidation_rx();
+
+  loop {
+    let event: Result<Option<Invalidation>, RecvError> = tokio::select! {
+      result = invalidation_rx.recv() => result.map(Some),
+      result = reconnect_rx.recv() => result.map(|_| None),
+      result = cluster_rx.recv() => result.map(|_| None),
+      result = companion_rx.recv() => result.map(|_| None),
+    };
+    let inner = match inner.upgrade() {
+      Some(inner) => inner,
+      None => break,
+    };
+
+    match event {
+      Ok(Some(invalidation)) => {
+        inner.cache.invalidate(&invalidation.keys);
+        inner.notifications.broadcast_invalidation(invalidation);
+        continue;
+      },
+      Ok(None) | Err(RecvError::Lagged(_)) => {},
+      Err(RecvError::Closed) => break,
+    };
+
+    let token = inner.cache.suspend();
+    match retry_redirect(&inner, &companion, &args).await {
+      Ok(true) => inner.cache.resume(token),
+      Ok(false) => _debug!(inner, "Stopped redirecting client tracking after the client closed."),
+      Err(e) => {
+        _error!(inner, "Failed to redirect client tracking: {:?}", e);
+        inner.notifications.broadcast_error(e);
+      },
+    };
+  }
+}
+
+/// Redirect tracking to the companion, retrying with the client's reconnection policy.
+///
+/// Returns `false` if the client closed before tracking was redirected, or the last error if the policy runs out of
+/// attempts.
+async fn retry_redirect(
+  inner: &Arc<RedisClientInner>,
+  companion: &RedisClient,
+  args: &[RedisValue],
+) -> Result<bool, RedisError> {
+  let mut close_rx = inner.notifications.close.subscribe();
+  let client = RedisClient::from(inner);
+  let mut policy = inner.reconnect_policy();
+  if let Some(policy) = policy.as_mut() {
+    policy.reset_attempts();
+  }
+
+  loop {
+    let error = match redirect_tracking(&client, companion, args).await {
+      Ok(_) => return Ok(true),
+      Err(e) => e,
+    };
+    let delay = match policy.as_mut().and_then(|policy| policy.next_delay()) {
+      Some(delay) => delay,
+      None => return Err(error),
+    };
+
+    _warn!(
+      inner,
+      "Failed to redirect client tracking. Retrying after {} ms: {:?}",
+      delay,
+      error
+    );
+    tokio::select! {
+      _ = sleep(Duration::from_millis(delay)) => {},
+      _ = close_rx.recv() => return Ok(false),
+    };
+  }
+}
+
+/// Enable tracking with a companion connection that receives invalidation messages via `REDIRECT`.
+async fn start_redirect_tracking<C: ClientLike>(client: &C, args: Vec<RedisValue>) -> Result<(), RedisError> {
+  let inner = client.inner();
+  let client = RedisClient::from(inner);
+  let companion = client.clone_new();
+  companion.init().await?;
+
+  if let Err(e) = redirect_tracking(&client, &companion, &args).await {
+    let _ = companion.quit().await;
+    return Err(e);
+  }
+
+  _debug!(inner, "Redirecting client tracking to {}", companion.id());
+  let task = tokio::spawn(manage_redirect(Arc::downgrade(inner), companion.clone(), args));
+  *inner.tracking_redirect.lock() = Some(RedirectTracking {
+    client: companion,
+    task,
+  });
+  Ok(())
+}
+
 pub async fn start_tracking<C: ClientLike>(
   client: &C,
   prefixes: MultipleStrings,
@@ -64,14 +253,11 @@ pub async fn start_tracking<C: ClientLike>(
   optout: bool,
   noloop: bool,
 ) -> Result<(), RedisError> {
+  let args = tracking_args(Toggle::On, None, prefixes, bcast, optin, optout, noloop);
   if !client.inner().is_resp3() {
-    return Err(RedisError::new(
-      RedisErrorKind::Config,
-      "Client tracking requires RESP3.",
-    ));
+    return start_redirect_tracking(client, args).await;
   }
 
-  let args = tracking_args(Toggle::On, None, prefixes, bcast, optin, optout, noloop);
   if client.inner().config.server.is_clustered() {
     if bcast {
       // only send the tracking command on one connection when in bcast mode
@@ -103,12 +289,7 @@ pub async fn start_tracking<C: ClientLike>(
 }
 
 pub async fn stop_tracking<C: ClientLike>(client: &C) -> Result<(), RedisError> {
-  if !client.inner().is_resp3() {
-    return Err(RedisError::new(
-      RedisErrorKind::Config,
-      "Client tracking requires RESP3.",
-    ));
-  }
+  let _ = client.inner().tracking_redirect.lock().take();
 
   let args = vec![static_val!(Toggle::Off.to_str())];
   if client.is_clustered() {
@@ -169,3 +350,14 @@ pub async fn client_caching<C: ClientLike>(client: &C, enabled: bool) -> Result<
     .await
     .and_then(protocol_utils::frame_to_results)
 }
+
+pub async fn enable_client_cache<C: ClientLike>(client: &C, config: ClientCacheConfig) -> Result<(), RedisError> {
+  start_tracking(client, MultipleStrings::new(), false, false, false, false).await?;
+  client.inner().cache.enable(config);
+  Ok(())
+}
+
+pub async fn disable_client_cache<C: ClientLike>(client: &C) -> Result<(), RedisError> {
+  client.inner().cache.disable();
+  stop_tracking(client).await
+}
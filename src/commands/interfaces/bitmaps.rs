@@ -0,0 +1,135 @@
+use crate::{
+  commands,
+  interfaces::{ClientLike, RedisResult},
+  types::{BitOperation, BitUnit, BitfieldOperations, FromRedis, MultipleKeys, RedisKey},
+};
+
+/// Functions that implement the [bitmap](https://redis.io/docs/data-types/bitmaps/) and
+/// [bitfield](https://redis.io/docs/data-types/bitfields/) interfaces.
+#[async_trait]
+pub trait BitmapInterface: ClientLike + Sized {
+  /// Sets or clears the bit at `offset` in the string value stored at `key`.
+  ///
+  /// Returns the original bit value stored at `offset`.
+  ///
+  /// <https://redis.io/commands/setbit>
+  async fn setbit<R, K>(&self, key: K, offset: u64, value: u8) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::bitmaps::setbit(self, key, offset, value).await?.convert()
+  }
+
+  /// Returns the bit value at `offset` in the string value stored at `key`.
+  ///
+  /// <https://redis.io/commands/getbit>
+  async fn getbit<R, K>(&self, key: K, offset: u64) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::bitmaps::getbit(self, key, offset).await?.convert()
+  }
+
+  /// Count the number of set bits in a string, optionally limited to the provided `(start, end)` range.
+  ///
+  /// The `unit` argument requires a range and was added in Redis 7.0.
+  ///
+  /// <https://redis.io/commands/bitcount>
+  async fn bitcount<R, K>(&self, key: K, range: Option<(i64, i64)>, unit: Option<BitUnit>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::bitmaps::bitcount(self, key, range, unit).await?.convert()
+  }
+
+  /// Return the position of the first bit set to 1 or 0 in a string.
+  ///
+  /// The `end` argument requires `start`, and the `unit` argument requires `end`.
+  ///
+  /// <https://redis.io/commands/bitpos>
+  async fn bitpos<R, K>(
+    &self,
+    key: K,
+    bit: u8,
+    start: Option<i64>,
+    end: Option<i64>,
+    unit: Option<BitUnit>,
+  ) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::bitmaps::bitpos(self, key, bit, start, end, unit)
+      .await?
+      .convert()
+  }
+
+  /// Perform a bitwise operation between multiple keys and store the result in the `destination` key.
+  ///
+  /// Returns the size of the string stored in the destination key.
+  ///
+  /// <https://redis.io/commands/bitop>
+  async fn bitop<R, D, S>(&self, operation: BitOperation, destination: D, sources: S) -> RedisResult<R>
+  where
+    R: FromRedis,
+    D: Into<RedisKey> + Send,
+    S: Into<MultipleKeys> + Send,
+  {
+    into!(destination, sources);
+    commands::bitmaps::bitop(self, operation, destination, sources)
+      .await?
+      .convert()
+  }
+
+  /// Treat the string stored at `key` as an array of integers and run the provided sub-operations in order.
+  ///
+  /// Returns an array with one entry for each `GET`, `SET`, or `INCRBY` operation. Entries are `nil` when an
+  /// operation is skipped by `OVERFLOW FAIL`.
+  ///
+  /// ```rust no_run
+  /// # use fred::prelude::*;
+  /// # use fred::types::{BitfieldEncoding, BitfieldOperations, BitfieldOverflow};
+  /// async fn example(client: &RedisClient) -> Result<(), RedisError> {
+  ///   let mut operations = BitfieldOperations::new();
+  ///   operations
+  ///     .overflow(BitfieldOverflow::Sat)
+  ///     .incrby(BitfieldEncoding::Unsigned(8), 0_u64, 300)
+  ///     .get(BitfieldEncoding::Unsigned(8), 0_u64);
+  ///
+  ///   let results: Vec<Option<i64>> = client.bitfield("foo", &operations).await?;
+  ///   assert_eq!(results, vec![Some(255), Some(255)]);
+  ///   Ok(())
+  /// }
+  /// ```
+  ///
+  /// <https://redis.io/commands/bitfield>
+  async fn bitfield<R, K, O>(&self, key: K, operations: O) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    O: Into<BitfieldOperations> + Send,
+  {
+    into!(key, operations);
+    commands::bitmaps::bitfield(self, key, operations).await?.convert()
+  }
+
+  /// A read-only variant of [bitfield](Self::bitfield) that only supports `GET` operations.
+  ///
+  /// <https://redis.io/commands/bitfield_ro>
+  async fn bitfield_ro<R, K, O>(&self, key: K, operations: O) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    O: Into<BitfieldOperations> + Send,
+  {
+    into!(key, operations);
+    commands::bitmaps::bitfield_ro(self, key, operations).await?.convert()
+  }
+}
@@ -2,7 +2,17 @@ use crate::{
   commands,
   error::RedisError,
   interfaces::{ClientLike, RedisResult},
-  types::{FromRedis, MultipleKeys, RedisKey, RedisMap, RedisValue},
+  types::{
+    Expiration,
+    ExpireOptions,
+    FromRedis,
+    GetExpiration,
+    HSetExOptions,
+    MultipleKeys,
+    RedisKey,
+    RedisMap,
+    RedisValue,
+  },
 };
 use std::convert::TryInto;
 
@@ -210,4 +220,218 @@ pub trait HashesInterface: ClientLike + Sized {
     into!(key);
     commands::hashes::hvals(self, key).await?.convert()
   }
+
+  /// Set an expiration, in seconds, on one or more fields in the hash stored at `key`.
+  ///
+  /// Returns one [FieldExpireResult](crate::types::FieldExpireResult) for each field.
+  ///
+  /// <https://redis.io/commands/hexpire>
+  async fn hexpire<R, K, F>(&self, key: K, seconds: i64, options: Option<ExpireOptions>, fields: F) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    F: Into<MultipleKeys> + Send,
+  {
+    into!(key, fields);
+    commands::hashes::hexpire(self, key, seconds, options, fields)
+      .await?
+      .convert()
+  }
+
+  /// Set an expiration, in milliseconds, on one or more fields in the hash stored at `key`.
+  ///
+  /// Returns one [FieldExpireResult](crate::types::FieldExpireResult) for each field.
+  ///
+  /// <https://redis.io/commands/hpexpire>
+  async fn hpexpire<R, K, F>(
+    &self,
+    key: K,
+    milliseconds: i64,
+    options: Option<ExpireOptions>,
+    fields: F,
+  ) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    F: Into<MultipleKeys> + Send,
+  {
+    into!(key, fields);
+    commands::hashes::hpexpire(self, key, milliseconds, options, fields)
+      .await?
+      .convert()
+  }
+
+  /// Set an expiration, as a UNIX timestamp in seconds, on one or more fields in the hash stored at `key`.
+  ///
+  /// Returns one [FieldExpireResult](crate::types::FieldExpireResult) for each field.
+  ///
+  /// <https://redis.io/commands/hexpireat>
+  async fn hexpire_at<R, K, F>(
+    &self,
+    key: K,
+    timestamp: i64,
+    options: Option<ExpireOptions>,
+    fields: F,
+  ) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    F: Into<MultipleKeys> + Send,
+  {
+    into!(key, fields);
+    commands::hashes::hexpire_at(self, key, timestamp, options, fields)
+      .await?
+      .convert()
+  }
+
+  /// Set an expiration, as a UNIX timestamp in milliseconds, on one or more fields in the hash stored at `key`.
+  ///
+  /// Returns one [FieldExpireResult](crate::types::FieldExpireResult) for each field.
+  ///
+  /// <https://redis.io/commands/hpexpireat>
+  async fn hpexpire_at<R, K, F>(
+    &self,
+    key: K,
+    timestamp: i64,
+    options: Option<ExpireOptions>,
+    fields: F,
+  ) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    F: Into<MultipleKeys> + Send,
+  {
+    into!(key, fields);
+    commands::hashes::hpexpire_at(self, key, timestamp, options, fields)
+      .await?
+      .convert()
+  }
+
+  /// Returns the remaining TTL, in seconds, of one or more fields in the hash stored at `key`.
+  ///
+  /// Returns `-2` for fields that do not exist and `-1` for fields without an expiration.
+  ///
+  /// <https://redis.io/commands/httl>
+  async fn httl<R, K, F>(&self, key: K, fields: F) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    F: Into<MultipleKeys> + Send,
+  {
+    into!(key, fields);
+    commands::hashes::httl(self, key, fields).await?.convert()
+  }
+
+  /// Returns the remaining TTL, in milliseconds, of one or more fields in the hash stored at `key`.
+  ///
+  /// Returns `-2` for fields that do not exist and `-1` for fields without an expiration.
+  ///
+  /// <https://redis.io/commands/hpttl>
+  async fn hpttl<R, K, F>(&self, key: K, fields: F) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    F: Into<MultipleKeys> + Send,
+  {
+    into!(key, fields);
+    commands::hashes::hpttl(self, key, fields).await?.convert()
+  }
+
+  /// Returns the expiration time, as a UNIX timestamp in seconds, of one or more fields in the hash stored at `key`.
+  ///
+  /// Returns `-2` for fields that do not exist and `-1` for fields without an expiration.
+  ///
+  /// <https://redis.io/commands/hexpiretime>
+  async fn hexpire_time<R, K, F>(&self, key: K, fields: F) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    F: Into<MultipleKeys> + Send,
+  {
+    into!(key, fields);
+    commands::hashes::hexpire_time(self, key, fields).await?.convert()
+  }
+
+  /// Returns the expiration time, as a UNIX timestamp in milliseconds, of one or more fields in the hash stored at
+  /// `key`.
+  ///
+  /// Returns `-2` for fields that do not exist and `-1` for fields without an expiration.
+  ///
+  /// <https://redis.io/commands/hpexpiretime>
+  async fn hpexpire_time<R, K, F>(&self, key: K, fields: F) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    F: Into<MultipleKeys> + Send,
+  {
+    into!(key, fields);
+    commands::hashes::hpexpire_time(self, key, fields).await?.convert()
+  }
+
+  /// Remove the expiration from one or more fields in the hash stored at `key`.
+  ///
+  /// Returns one [FieldPersistResult](crate::types::FieldPersistResult) for each field.
+  ///
+  /// <https://redis.io/commands/hpersist>
+  async fn hpersist<R, K, F>(&self, key: K, fields: F) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    F: Into<MultipleKeys> + Send,
+  {
+    into!(key, fields);
+    commands::hashes::hpersist(self, key, fields).await?.convert()
+  }
+
+  /// Returns the values of one or more fields in the hash stored at `key` and then deletes the fields.
+  ///
+  /// <https://redis.io/commands/hgetdel>
+  async fn hgetdel<R, K, F>(&self, key: K, fields: F) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    F: Into<MultipleKeys> + Send,
+  {
+    into!(key, fields);
+    commands::hashes::hgetdel(self, key, fields).await?.convert()
+  }
+
+  /// Returns the values of one or more fields in the hash stored at `key`, optionally setting or removing their
+  /// expiration.
+  ///
+  /// <https://redis.io/commands/hgetex>
+  async fn hgetex<R, K, F>(&self, key: K, expire: Option<GetExpiration>, fields: F) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    F: Into<MultipleKeys> + Send,
+  {
+    into!(key, fields);
+    commands::hashes::hgetex(self, key, expire, fields).await?.convert()
+  }
+
+  /// Sets fields in the hash stored at `key` to their provided values, optionally setting their expiration.
+  ///
+  /// Returns `1` if all the fields were set, or `0` if none were set due to the `FNX` or `FXX` condition.
+  ///
+  /// <https://redis.io/commands/hsetex>
+  async fn hsetex<R, K, V>(
+    &self,
+    key: K,
+    options: Option<HSetExOptions>,
+    expire: Option<Expiration>,
+    values: V,
+  ) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisMap> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(values);
+    commands::hashes::hsetex(self, key, options, expire, values)
+      .await?
+      .convert()
+  }
 }
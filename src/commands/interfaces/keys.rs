@@ -2,11 +2,26 @@ use crate::{
   commands,
   error::RedisError,
   interfaces::{ClientLike, RedisResult},
-  types::{Expiration, FromRedis, MultipleKeys, RedisKey, RedisMap, RedisValue, SetOptions},
+  types::{
+    Expiration,
+    ExpireOptions,
+    FromRedis,
+    MigrateOptions,
+    MultipleKeys,
+    RedisKey,
+    RedisMap,
+    RedisValue,
+    SetOptions,
+    SortOptions,
+  },
 };
+use bytes_utils::Str;
 use std::convert::TryInto;
 
 /// Functions that implement the generic [keys](https://redis.io/commands#generic) interface.
+///
+/// Newer string commands such as `GETEX` and `SETEX` are implemented by the
+/// [StringsInterface](crate::interfaces::StringsInterface).
 #[async_trait]
 pub trait KeysInterface: ClientLike + Sized {
   /// Marks the given keys to be watched for conditional execution of a transaction.
@@ -108,7 +123,7 @@ pub trait KeysInterface: ClientLike + Sized {
   {
     into!(key);
     try_into!(value);
-    commands::keys::set(self, key, value, expire, options, get)
+    commands::strings::set(self, key, value, expire, options, get)
       .await?
       .convert()
   }
@@ -122,7 +137,7 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<RedisKey> + Send,
   {
     into!(key);
-    commands::keys::get(self, key).await?.convert()
+    commands::strings::get(self, key).await?.convert()
   }
 
   /// Returns the substring of the string value stored at `key` with offsets `start` and `end` (both inclusive).
@@ -136,7 +151,7 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<RedisKey> + Send,
   {
     into!(key);
-    commands::keys::getrange(self, key, start, end).await?.convert()
+    commands::strings::getrange(self, key, start, end).await?.convert()
   }
 
   /// Overwrites part of the string stored at `key`, starting at the specified `offset`, for the entire length of
@@ -152,7 +167,7 @@ pub trait KeysInterface: ClientLike + Sized {
   {
     into!(key);
     try_into!(value);
-    commands::keys::setrange(self, key, offset, value).await?.convert()
+    commands::strings::setrange(self, key, offset, value).await?.convert()
   }
 
   /// Atomically sets `key` to `value` and returns the old value stored at `key`.
@@ -169,7 +184,7 @@ pub trait KeysInterface: ClientLike + Sized {
   {
     into!(key);
     try_into!(value);
-    commands::keys::getset(self, key, value).await?.convert()
+    commands::strings::getset(self, key, value).await?.convert()
   }
 
   /// Get the value of key and delete the key. This command is similar to GET, except for the fact that it also
@@ -182,7 +197,7 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<RedisKey> + Send,
   {
     into!(key);
-    commands::keys::getdel(self, key).await?.convert()
+    commands::strings::getdel(self, key).await?.convert()
   }
 
   /// Returns the length of the string value stored at key. An error is returned when key holds a non-string value.
@@ -194,7 +209,7 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<RedisKey> + Send,
   {
     into!(key);
-    commands::keys::strlen(self, key).await?.convert()
+    commands::strings::strlen(self, key).await?.convert()
   }
 
   /// Removes the specified keys. A key is ignored if it does not exist.
@@ -269,12 +284,15 @@ pub trait KeysInterface: ClientLike + Sized {
   {
     into!(key);
     try_into!(value);
-    commands::keys::append(self, key, value).await?.convert()
+    commands::strings::append(self, key, value).await?.convert()
   }
 
   /// Returns the values of all specified keys. For every key that does not hold a string value or does not exist, the
   /// special value nil is returned.
   ///
+  /// Cluster clients can read keys from different hash slots via
+  /// [split_cross_slot](crate::types::Options::split_cross_slot).
+  ///
   /// <https://redis.io/commands/mget>
   async fn mget<R, K>(&self, keys: K) -> RedisResult<R>
   where
@@ -282,11 +300,14 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<MultipleKeys> + Send,
   {
     into!(keys);
-    commands::keys::mget(self, keys).await?.convert()
+    commands::strings::mget(self, keys).await?.convert()
   }
 
   /// Sets the given keys to their respective values.
   ///
+  /// This is not atomic when the keys are split by hash slot via
+  /// [split_cross_slot](crate::types::Options::split_cross_slot).
+  ///
   /// <https://redis.io/commands/mset>
   async fn mset<V>(&self, values: V) -> RedisResult<()>
   where
@@ -294,7 +315,7 @@ pub trait KeysInterface: ClientLike + Sized {
     V::Error: Into<RedisError> + Send,
   {
     try_into!(values);
-    commands::keys::mset(self, values).await?.convert()
+    commands::strings::mset(self, values).await?.convert()
   }
 
   /// Sets the given keys to their respective values. MSETNX will not perform any operation at all even if just a
@@ -308,7 +329,7 @@ pub trait KeysInterface: ClientLike + Sized {
     V::Error: Into<RedisError> + Send,
   {
     try_into!(values);
-    commands::keys::msetnx(self, values).await?.convert()
+    commands::strings::msetnx(self, values).await?.convert()
   }
 
   /// Increments the number stored at `key` by one. If the key does not exist, it is set to 0 before performing the
@@ -323,7 +344,7 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<RedisKey> + Send,
   {
     into!(key);
-    commands::keys::incr(self, key).await?.convert()
+    commands::strings::incr(self, key).await?.convert()
   }
 
   /// Increments the number stored at `key` by `val`. If the key does not exist, it is set to 0 before performing the
@@ -338,7 +359,7 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<RedisKey> + Send,
   {
     into!(key);
-    commands::keys::incr_by(self, key, val).await?.convert()
+    commands::strings::incr_by(self, key, val).await?.convert()
   }
 
   /// Increment the string representing a floating point number stored at key by `val`. If the key does not exist, it
@@ -354,7 +375,7 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<RedisKey> + Send,
   {
     into!(key);
-    commands::keys::incr_by_float(self, key, val).await?.convert()
+    commands::strings::incr_by_float(self, key, val).await?.convert()
   }
 
   /// Decrements the number stored at `key` by one. If the key does not exist, it is set to 0 before performing the
@@ -369,7 +390,7 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<RedisKey> + Send,
   {
     into!(key);
-    commands::keys::decr(self, key).await?.convert()
+    commands::strings::decr(self, key).await?.convert()
   }
 
   /// Decrements the number stored at `key` by `val`. If the key does not exist, it is set to 0 before performing the
@@ -384,7 +405,7 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<RedisKey> + Send,
   {
     into!(key);
-    commands::keys::decr_by(self, key, val).await?.convert()
+    commands::strings::decr_by(self, key, val).await?.convert()
   }
 
   /// Returns the remaining time to live of a key that has a timeout, in seconds.
@@ -437,7 +458,7 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<RedisKey> + Send,
   {
     into!(key);
-    commands::keys::expire(self, key, seconds).await?.convert()
+    commands::keys::expire(self, key, seconds, None).await?.convert()
   }
 
   /// Set a timeout on a key based on a UNIX timestamp.
@@ -451,7 +472,89 @@ pub trait KeysInterface: ClientLike + Sized {
     K: Into<RedisKey> + Send,
   {
     into!(key);
-    commands::keys::expire_at(self, key, timestamp).await?.convert()
+    commands::keys::expire_at(self, key, timestamp, None).await?.convert()
+  }
+
+  /// Set a timeout on key if the provided condition is met.
+  ///
+  /// <https://redis.io/commands/expire>
+  async fn expire_with_options<R, K>(&self, key: K, seconds: i64, options: ExpireOptions) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::expire(self, key, seconds, Some(options))
+      .await?
+      .convert()
+  }
+
+  /// Set a timeout on a key based on a UNIX timestamp if the provided condition is met.
+  ///
+  /// <https://redis.io/commands/expireat>
+  async fn expire_at_with_options<R, K>(&self, key: K, timestamp: i64, options: ExpireOptions) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::expire_at(self, key, timestamp, Some(options))
+      .await?
+      .convert()
+  }
+
+  /// Set a timeout on key, in milliseconds.
+  ///
+  /// <https://redis.io/commands/pexpire>
+  async fn pexpire<R, K>(&self, key: K, milliseconds: i64, options: Option<ExpireOptions>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::pexpire(self, key, milliseconds, options)
+      .await?
+      .convert()
+  }
+
+  /// Set a timeout on a key based on a UNIX timestamp in milliseconds.
+  ///
+  /// <https://redis.io/commands/pexpireat>
+  async fn pexpire_at<R, K>(&self, key: K, timestamp: i64, options: Option<ExpireOptions>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::pexpire_at(self, key, timestamp, options)
+      .await?
+      .convert()
+  }
+
+  /// Returns the absolute UNIX timestamp, in seconds, at which the key will expire.
+  ///
+  /// Returns -1 if the key has no expiration, or -2 if the key does not exist.
+  ///
+  /// <https://redis.io/commands/expiretime>
+  async fn expire_time<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::expire_time(self, key).await?.convert()
+  }
+
+  /// Returns the absolute UNIX timestamp, in milliseconds, at which the key will expire.
+  ///
+  /// <https://redis.io/commands/pexpiretime>
+  async fn pexpire_time<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::pexpire_time(self, key).await?.convert()
   }
 
   /// Returns number of keys that exist from the `keys` arguments.
@@ -466,8 +569,178 @@ pub trait KeysInterface: ClientLike + Sized {
     commands::keys::exists(self, keys).await?.convert()
   }
 
+  /// Returns the type of the value stored at `key`.
+  ///
+  /// Callers can use [KeyType](crate::types::KeyType) as the response type.
+  ///
+  /// <https://redis.io/commands/type>
+  async fn r#type<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::r#type(self, key).await?.convert()
+  }
+
+  /// Alters the last access time of the provided keys, returning the number of keys that exist.
+  ///
+  /// <https://redis.io/commands/touch>
+  async fn touch<R, K>(&self, keys: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<MultipleKeys> + Send,
+  {
+    into!(keys);
+    commands::keys::touch(self, keys).await?.convert()
+  }
+
+  /// Returns the internal encoding used to store the value at `key`.
+  ///
+  /// Callers can use [ObjectEncoding](crate::types::ObjectEncoding) as the response type.
+  ///
+  /// <https://redis.io/commands/object-encoding>
+  async fn object_encoding<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::object_encoding(self, key).await?.convert()
+  }
+
+  /// Returns the logarithmic access frequency counter of the value at `key`.
+  ///
+  /// <https://redis.io/commands/object-freq>
+  async fn object_freq<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::object_freq(self, key).await?.convert()
+  }
+
+  /// Returns the number of seconds since the value at `key` was last accessed.
+  ///
+  /// <https://redis.io/commands/object-idletime>
+  async fn object_idletime<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::object_idletime(self, key).await?.convert()
+  }
+
+  /// Returns the reference count of the value at `key`.
+  ///
+  /// <https://redis.io/commands/object-refcount>
+  async fn object_refcount<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::object_refcount(self, key).await?.convert()
+  }
+
+  /// Move `key` from the currently selected database to the provided database.
+  ///
+  /// <https://redis.io/commands/move>
+  async fn r#move<R, K>(&self, key: K, db: u8) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::r#move(self, key, db).await?.convert()
+  }
+
+  /// Returns or stores the elements contained in the list, set or sorted set at `key`.
+  ///
+  /// In a cluster the `STORE` destination must map to the same hash slot as `key`.
+  ///
+  /// <https://redis.io/commands/sort>
+  async fn sort<R, K>(&self, key: K, options: SortOptions) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::sort(self, key, options).await?.convert()
+  }
+
+  /// Read-only variant of the `SORT` command. The `store` option is not supported.
+  ///
+  /// <https://redis.io/commands/sort_ro>
+  async fn sort_ro<R, K>(&self, key: K, options: SortOptions) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::keys::sort_ro(self, key, options).await?.convert()
+  }
+
+  /// Returns all keys matching `pattern`.
+  ///
+  /// Clustered clients send this to a random node. Use
+  /// [with_cluster_node](crate::clients::RedisClient::with_cluster_node) to target a specific node, or prefer
+  /// `SCAN` in production.
+  ///
+  /// <https://redis.io/commands/keys>
+  async fn keys<R, S>(&self, pattern: S) -> RedisResult<R>
+  where
+    R: FromRedis,
+    S: Into<Str> + Send,
+  {
+    into!(pattern);
+    commands::keys::keys(self, pattern).await?.convert()
+  }
+
+  /// Blocks the current client until all the previous write commands are fsynced to the AOF of the local Redis
+  /// and/or at least the specified number of replicas.
+  ///
+  /// <https://redis.io/commands/waitaof>
+  async fn waitaof<R>(&self, numlocal: i64, numreplicas: i64, timeout: i64) -> RedisResult<R>
+  where
+    R: FromRedis,
+  {
+    commands::keys::waitaof(self, numlocal, numreplicas, timeout)
+      .await?
+      .convert()
+  }
+
+  /// Atomically transfer one or more keys from the source instance to the destination instance.
+  ///
+  /// In a cluster all keys must map to the same hash slot.
+  ///
+  /// <https://redis.io/commands/migrate>
+  async fn migrate<R, S, K>(
+    &self,
+    host: S,
+    port: u16,
+    keys: K,
+    db: u8,
+    timeout: i64,
+    options: MigrateOptions,
+  ) -> RedisResult<R>
+  where
+    R: FromRedis,
+    S: Into<Str> + Send,
+    K: Into<MultipleKeys> + Send,
+  {
+    into!(host, keys);
+    commands::keys::migrate(self, host, port, keys, db, timeout, options)
+      .await?
+      .convert()
+  }
+
   /// Runs the longest common subsequence algorithm on two keys.
   ///
+  /// Callers can use [LcsResult](crate::types::LcsResult) as the response type when `idx` is `true`.
+  ///
   /// <https://redis.io/commands/lcs/>
   async fn lcs<R, K1, K2>(
     &self,
@@ -484,7 +757,7 @@ pub trait KeysInterface: ClientLike + Sized {
     K2: Into<RedisKey> + Send,
   {
     into!(key1, key2);
-    commands::keys::lcs(self, key1, key2, len, idx, minmatchlen, withmatchlen)
+    commands::strings::lcs(self, key1, key2, len, idx, minmatchlen, withmatchlen)
       .await?
       .convert()
   }
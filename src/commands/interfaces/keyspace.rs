@@ -0,0 +1,128 @@
+use crate::{
+  commands,
+  interfaces::{ClientLike, EventInterface},
+  prelude::RedisResult,
+  types::{ClusterStateChange, KeyspaceEvent, KeyspaceEventFlags, KeyspaceOperation},
+  utils,
+};
+use bytes_utils::Str;
+use tokio::task::JoinHandle;
+
+fn db_pattern(db: Option<u8>) -> String {
+  db.map(|db| db.to_string()).unwrap_or_else(|| "*".into())
+}
+
+/// A high level interface for [keyspace notifications](https://redis.io/docs/manual/keyspace-notifications/).
+///
+/// Keyspace notifications are published on the node that owns the key, so in a clustered deployment this interface
+/// configures and subscribes on every primary node. Callers should use
+/// [manage_keyspace_subscriptions](Self::manage_keyspace_subscriptions) to resubscribe after reconnections or
+/// cluster topology changes.
+#[async_trait]
+pub trait KeyspaceNotificationsInterface: ClientLike + EventInterface + Sized {
+  /// Set the `notify-keyspace-events` configuration parameter on all primary nodes.
+  ///
+  /// <https://redis.io/docs/manual/keyspace-notifications/#configuration>
+  async fn configure_keyspace_notifications(&self, flags: KeyspaceEventFlags) -> RedisResult<()> {
+    commands::keyspace::configure_notifications(self, flags).await
+  }
+
+  /// Subscribe to keyspace events (`__keyspace@<db>__:<pattern>`) for keys matching `key_pattern` on all primary
+  /// nodes.
+  ///
+  /// If `db` is `None` events are received from all databases.
+  async fn keyspace_subscribe<S>(&self, db: Option<u8>, key_pattern: S) -> RedisResult<()>
+  where
+    S: Into<Str> + Send,
+  {
+    into!(key_pattern);
+    let channel = format!("__keyspace@{}__:{}", db_pattern(db), key_pattern);
+    commands::keyspace::subscribe(self, channel.into()).await
+  }
+
+  /// Subscribe to keyevent events (`__keyevent@<db>__:<operation>`) on all primary nodes.
+  ///
+  /// If `db` is `None` events are received from all databases. If `operation` is `None` all operations are received.
+  async fn keyevent_subscribe(&self, db: Option<u8>, operation: Option<KeyspaceOperation>) -> RedisResult<()> {
+    let operation = operation.as_ref().map(|op| op.to_str()).unwrap_or("*");
+    let channel = format!("__keyevent@{}__:{}", db_pattern(db), operation);
+    commands::keyspace::subscribe(self, channel.into()).await
+  }
+
+  /// Unsubscribe from all keyspace and keyevent channels on all primary nodes.
+  async fn keyspace_unsubscribe_all(&self) -> RedisResult<()> {
+    commands::keyspace::unsubscribe_all(self).await
+  }
+
+  /// Spawn a task that resubscribes to keyspace and keyevent channels when a connection is reestablished or a node
+  /// is added to the cluster.
+  fn manage_keyspace_subscriptions(&self) -> JoinHandle<()>
+  where
+    Self: Sync + 'static,
+  {
+    let _self = self.clone();
+    let mut reconnect_rx = self.reconnect_rx();
+    let mut cluster_rx = self.cluster_change_rx();
+
+    tokio::spawn(async move {
+      loop {
+        let servers = tokio::select! {
+          result = reconnect_rx.recv() => match result {
+            Ok(server) => vec![server],
+            Err(_) => break,
+          },
+          result = cluster_rx.recv() => match result {
+            Ok(changes) => changes
+              .into_iter()
+              .filter_map(|change| match change {
+                ClusterStateChange::Add(server) => Some(server),
+                _ => None,
+              })
+              .collect(),
+            Err(_) => break,
+          },
+        };
+
+        for server in servers.into_iter() {
+          if let Err(error) = commands::keyspace::resubscribe(&_self, Some(server)).await {
+            error!(
+              "{}: Failed to resubscribe to keyspace channels: {:?}",
+              _self.id(),
+              error
+            );
+          }
+        }
+      }
+    })
+  }
+
+  /// Spawn a task that runs the provided function on each keyspace event with a key matching the optional
+  /// glob-style `key_pattern`.
+  ///
+  /// See [on_keyspace_event](crate::interfaces::EventInterface::on_keyspace_event) for more information.
+  fn on_keyspace_operation<F>(&self, key_pattern: Option<&str>, func: F) -> JoinHandle<RedisResult<()>>
+  where
+    F: Fn(KeyspaceOperation, KeyspaceEvent) -> RedisResult<()> + Send + 'static,
+  {
+    let key_pattern = key_pattern.map(|p| p.as_bytes().to_vec());
+    let mut keyspace_rx = self.keyspace_event_rx();
+
+    tokio::spawn(async move {
+      let mut result = Ok(());
+
+      while let Ok(event) = keyspace_rx.recv().await {
+        if let Some(ref pattern) = key_pattern {
+          if !utils::glob_match(pattern, event.key.as_bytes()) {
+            continue;
+          }
+        }
+
+        if let Err(err) = func(event.kind(), event) {
+          result = Err(err);
+          break;
+        }
+      }
+      result
+    })
+  }
+}
@@ -1,4 +1,5 @@
 pub mod acl;
+pub mod bitmaps;
 pub mod client;
 pub mod cluster;
 pub mod config;
@@ -6,6 +7,7 @@ pub mod geo;
 pub mod hashes;
 pub mod hyperloglog;
 pub mod keys;
+pub mod keyspace;
 pub mod lists;
 pub mod lua;
 pub mod memory;
@@ -28,8 +30,13 @@ pub mod tracking;
 #[cfg(feature = "sentinel-client")]
 pub mod sentinel;
 
+#[cfg(feature = "redis-bloom")]
+pub mod redis_bloom;
+
 #[cfg(feature = "redis-json")]
 pub mod redis_json;
 
+#[cfg(feature = "redis-search")]
+pub mod redisearch;
 #[cfg(feature = "time-series")]
 pub mod timeseries;
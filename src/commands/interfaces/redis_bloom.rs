@@ -0,0 +1,724 @@
+use crate::{
+  commands,
+  interfaces::{ClientLike, RedisResult},
+  prelude::RedisError,
+  types::{
+    BfInfoField,
+    BfInsertOptions,
+    CfReserveOptions,
+    FromRedis,
+    MultipleKeys,
+    MultipleValues,
+    RedisKey,
+    RedisValue,
+    TopKDimensions,
+  },
+};
+use bytes::Bytes;
+
+/// A [RedisBloom](https://redis.io/docs/data-types/probabilistic/) interface for bloom filters, cuckoo filters,
+/// count-min sketches, top-k sketches, and t-digests.
+///
+/// See [bf_scandump_stream](crate::clients::RedisClient::bf_scandump_stream) or
+/// [cf_scandump_stream](crate::clients::RedisClient::cf_scandump_stream) to migrate filters between servers.
+#[async_trait]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+pub trait RedisBloomInterface: ClientLike + Sized {
+  /// Add an item to a bloom filter, creating the filter if it does not exist.
+  ///
+  /// <https://redis.io/commands/bf.add/>
+  async fn bf_add<R, K, V>(&self, key: K, item: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisValue> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(item);
+    commands::redis_bloom::bf_add(self, key, item).await?.convert()
+  }
+
+  /// Return the cardinality of a bloom filter, or the number of items that were added and detected as unique.
+  ///
+  /// <https://redis.io/commands/bf.card/>
+  async fn bf_card<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::bf_card(self, key).await?.convert()
+  }
+
+  /// Check whether an item exists in a bloom filter.
+  ///
+  /// <https://redis.io/commands/bf.exists/>
+  async fn bf_exists<R, K, V>(&self, key: K, item: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisValue> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(item);
+    commands::redis_bloom::bf_exists(self, key, item).await?.convert()
+  }
+
+  /// Return information about a bloom filter, optionally limited to a single field.
+  ///
+  /// <https://redis.io/commands/bf.info/>
+  async fn bf_info<R, K>(&self, key: K, field: Option<BfInfoField>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::bf_info(self, key, field).await?.convert()
+  }
+
+  /// Add one or more items to a bloom filter, creating the filter with the provided options if it does not exist.
+  ///
+  /// <https://redis.io/commands/bf.insert/>
+  async fn bf_insert<R, K, V>(&self, key: K, options: BfInsertOptions, items: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<MultipleValues> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(items);
+    commands::redis_bloom::bf_insert(self, key, options, items)
+      .await?
+      .convert()
+  }
+
+  /// Restore a bloom filter previously saved with `BF.SCANDUMP`.
+  ///
+  /// <https://redis.io/commands/bf.loadchunk/>
+  async fn bf_loadchunk<R, K>(&self, key: K, iterator: i64, data: Bytes) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::bf_loadchunk(self, key, iterator, data)
+      .await?
+      .convert()
+  }
+
+  /// Add one or more items to a bloom filter, creating the filter if it does not exist.
+  ///
+  /// <https://redis.io/commands/bf.madd/>
+  async fn bf_madd<R, K, V>(&self, key: K, items: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<MultipleValues> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(items);
+    commands::redis_bloom::bf_madd(self, key, items).await?.convert()
+  }
+
+  /// Check whether one or more items exist in a bloom filter.
+  ///
+  /// <https://redis.io/commands/bf.mexists/>
+  async fn bf_mexists<R, K, V>(&self, key: K, items: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<MultipleValues> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(items);
+    commands::redis_bloom::bf_mexists(self, key, items).await?.convert()
+  }
+
+  /// Create an empty bloom filter with a single sub-filter for the initial capacity and with an upper bound error
+  /// rate.
+  ///
+  /// <https://redis.io/commands/bf.reserve/>
+  async fn bf_reserve<R, K>(
+    &self,
+    key: K,
+    error_rate: f64,
+    capacity: u64,
+    expansion: Option<u64>,
+    nonscaling: bool,
+  ) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::bf_reserve(self, key, error_rate, capacity, expansion, nonscaling)
+      .await?
+      .convert()
+  }
+
+  /// Begin or continue an incremental save of a bloom filter.
+  ///
+  /// <https://redis.io/commands/bf.scandump/>
+  async fn bf_scandump<R, K>(&self, key: K, iterator: i64) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::bf_scandump(self, key, iterator).await?.convert()
+  }
+
+  /// Add an item to a cuckoo filter, creating the filter if it does not exist.
+  ///
+  /// <https://redis.io/commands/cf.add/>
+  async fn cf_add<R, K, V>(&self, key: K, item: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisValue> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(item);
+    commands::redis_bloom::cf_add(self, key, item).await?.convert()
+  }
+
+  /// Add an item to a cuckoo filter only if it does not already exist.
+  ///
+  /// <https://redis.io/commands/cf.addnx/>
+  async fn cf_addnx<R, K, V>(&self, key: K, item: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisValue> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(item);
+    commands::redis_bloom::cf_addnx(self, key, item).await?.convert()
+  }
+
+  /// Return the number of times an item may be in a cuckoo filter.
+  ///
+  /// <https://redis.io/commands/cf.count/>
+  async fn cf_count<R, K, V>(&self, key: K, item: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisValue> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(item);
+    commands::redis_bloom::cf_count(self, key, item).await?.convert()
+  }
+
+  /// Delete an item once from a cuckoo filter.
+  ///
+  /// <https://redis.io/commands/cf.del/>
+  async fn cf_del<R, K, V>(&self, key: K, item: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisValue> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(item);
+    commands::redis_bloom::cf_del(self, key, item).await?.convert()
+  }
+
+  /// Check whether an item exists in a cuckoo filter.
+  ///
+  /// <https://redis.io/commands/cf.exists/>
+  async fn cf_exists<R, K, V>(&self, key: K, item: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisValue> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(item);
+    commands::redis_bloom::cf_exists(self, key, item).await?.convert()
+  }
+
+  /// Return information about a cuckoo filter.
+  ///
+  /// <https://redis.io/commands/cf.info/>
+  async fn cf_info<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::cf_info(self, key).await?.convert()
+  }
+
+  /// Add one or more items to a cuckoo filter, creating the filter if it does not exist and `nocreate` is `false`.
+  ///
+  /// <https://redis.io/commands/cf.insert/>
+  async fn cf_insert<R, K, V>(&self, key: K, capacity: Option<u64>, nocreate: bool, items: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<MultipleValues> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(items);
+    commands::redis_bloom::cf_insert(self, key, false, capacity, nocreate, items)
+      .await?
+      .convert()
+  }
+
+  /// Add one or more items to a cuckoo filter if they do not already exist, creating the filter if it does not exist
+  /// and `nocreate` is `false`.
+  ///
+  /// <https://redis.io/commands/cf.insertnx/>
+  async fn cf_insertnx<R, K, V>(&self, key: K, capacity: Option<u64>, nocreate: bool, items: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<MultipleValues> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(items);
+    commands::redis_bloom::cf_insert(self, key, true, capacity, nocreate, items)
+      .await?
+      .convert()
+  }
+
+  /// Restore a cuckoo filter previously saved with `CF.SCANDUMP`.
+  ///
+  /// <https://redis.io/commands/cf.loadchunk/>
+  async fn cf_loadchunk<R, K>(&self, key: K, iterator: i64, data: Bytes) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::cf_loadchunk(self, key, iterator, data)
+      .await?
+      .convert()
+  }
+
+  /// Check whether one or more items exist in a cuckoo filter.
+  ///
+  /// <https://redis.io/commands/cf.mexists/>
+  async fn cf_mexists<R, K, V>(&self, key: K, items: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<MultipleValues> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(items);
+    commands::redis_bloom::cf_mexists(self, key, items).await?.convert()
+  }
+
+  /// Create an empty cuckoo filter with the provided initial capacity.
+  ///
+  /// <https://redis.io/commands/cf.reserve/>
+  async fn cf_reserve<R, K>(&self, key: K, capacity: u64, options: CfReserveOptions) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::cf_reserve(self, key, capacity, options)
+      .await?
+      .convert()
+  }
+
+  /// Begin or continue an incremental save of a cuckoo filter.
+  ///
+  /// <https://redis.io/commands/cf.scandump/>
+  async fn cf_scandump<R, K>(&self, key: K, iterator: i64) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::cf_scandump(self, key, iterator).await?.convert()
+  }
+
+  /// Increase the count of one or more items in a count-min sketch by the associated increment.
+  ///
+  /// <https://redis.io/commands/cms.incrby/>
+  async fn cms_incrby<R, K, V>(&self, key: K, increments: Vec<(V, i64)>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisValue> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    let increments = increments
+      .into_iter()
+      .map(|(item, increment)| to!(item).map(|item| (item, increment)))
+      .collect::<Result<Vec<_>, RedisError>>()?;
+    commands::redis_bloom::cms_incrby(self, key, increments)
+      .await?
+      .convert()
+  }
+
+  /// Return the width, depth, and total count of a count-min sketch.
+  ///
+  /// <https://redis.io/commands/cms.info/>
+  async fn cms_info<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::cms_info(self, key).await?.convert()
+  }
+
+  /// Initialize a count-min sketch with the provided dimensions.
+  ///
+  /// <https://redis.io/commands/cms.initbydim/>
+  async fn cms_initbydim<R, K>(&self, key: K, width: u64, depth: u64) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::cms_initbydim(self, key, width, depth)
+      .await?
+      .convert()
+  }
+
+  /// Initialize a count-min sketch to accommodate the requested tolerances.
+  ///
+  /// <https://redis.io/commands/cms.initbyprob/>
+  async fn cms_initbyprob<R, K>(&self, key: K, error: f64, probability: f64) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::cms_initbyprob(self, key, error, probability)
+      .await?
+      .convert()
+  }
+
+  /// Merge several count-min sketches into one sketch, optionally multiplying each source by the associated weight.
+  ///
+  /// <https://redis.io/commands/cms.merge/>
+  async fn cms_merge<R, D, S>(&self, destination: D, sources: S, weights: Vec<i64>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    D: Into<RedisKey> + Send,
+    S: Into<MultipleKeys> + Send,
+  {
+    into!(destination, sources);
+    commands::redis_bloom::cms_merge(self, destination, sources, weights)
+      .await?
+      .convert()
+  }
+
+  /// Return the count for one or more items in a count-min sketch.
+  ///
+  /// <https://redis.io/commands/cms.query/>
+  async fn cms_query<R, K, V>(&self, key: K, items: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<MultipleValues> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(items);
+    commands::redis_bloom::cms_query(self, key, items).await?.convert()
+  }
+
+  /// Add one or more items to a top-k sketch.
+  ///
+  /// Returns the items that were dropped from the top-k list, if any.
+  ///
+  /// <https://redis.io/commands/topk.add/>
+  async fn topk_add<R, K, V>(&self, key: K, items: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<MultipleValues> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(items);
+    commands::redis_bloom::topk_add(self, key, items).await?.convert()
+  }
+
+  /// Increase the score of one or more items in a top-k sketch by the associated increment.
+  ///
+  /// <https://redis.io/commands/topk.incrby/>
+  async fn topk_incrby<R, K, V>(&self, key: K, increments: Vec<(V, i64)>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisValue> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    let increments = increments
+      .into_iter()
+      .map(|(item, increment)| to!(item).map(|item| (item, increment)))
+      .collect::<Result<Vec<_>, RedisError>>()?;
+    commands::redis_bloom::topk_incrby(self, key, increments)
+      .await?
+      .convert()
+  }
+
+  /// Return the number of required items, width, depth, and decay values of a top-k sketch.
+  ///
+  /// <https://redis.io/commands/topk.info/>
+  async fn topk_info<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::topk_info(self, key).await?.convert()
+  }
+
+  /// Return the full list of items in a top-k sketch, optionally with their counts.
+  ///
+  /// <https://redis.io/commands/topk.list/>
+  async fn topk_list<R, K>(&self, key: K, withcount: bool) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::topk_list(self, key, withcount).await?.convert()
+  }
+
+  /// Check whether one or more items are in a top-k sketch.
+  ///
+  /// <https://redis.io/commands/topk.query/>
+  async fn topk_query<R, K, V>(&self, key: K, items: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<MultipleValues> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(items);
+    commands::redis_bloom::topk_query(self, key, items).await?.convert()
+  }
+
+  /// Initialize a top-k sketch with the provided number of items to keep and optional dimensions.
+  ///
+  /// <https://redis.io/commands/topk.reserve/>
+  async fn topk_reserve<R, K>(&self, key: K, topk: u64, dimensions: Option<TopKDimensions>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::topk_reserve(self, key, topk, dimensions)
+      .await?
+      .convert()
+  }
+
+  /// Add one or more observations to a t-digest sketch.
+  ///
+  /// <https://redis.io/commands/tdigest.add/>
+  async fn tdigest_add<R, K>(&self, key: K, values: Vec<f64>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_add(self, key, values).await?.convert()
+  }
+
+  /// Return, for each input rank, an estimation of the value with that rank.
+  ///
+  /// <https://redis.io/commands/tdigest.byrank/>
+  async fn tdigest_byrank<R, K>(&self, key: K, ranks: Vec<i64>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_byrank(self, key, ranks, false)
+      .await?
+      .convert()
+  }
+
+  /// Return, for each input reverse rank, an estimation of the value with that reverse rank.
+  ///
+  /// <https://redis.io/commands/tdigest.byrevrank/>
+  async fn tdigest_byrevrank<R, K>(&self, key: K, ranks: Vec<i64>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_byrank(self, key, ranks, true)
+      .await?
+      .convert()
+  }
+
+  /// Return, for each input value, an estimation of the fraction of observations less than or equal to the value.
+  ///
+  /// <https://redis.io/commands/tdigest.cdf/>
+  async fn tdigest_cdf<R, K>(&self, key: K, values: Vec<f64>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_cdf(self, key, values).await?.convert()
+  }
+
+  /// Allocate memory and initialize a new t-digest sketch.
+  ///
+  /// <https://redis.io/commands/tdigest.create/>
+  async fn tdigest_create<R, K>(&self, key: K, compression: Option<u64>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_create(self, key, compression)
+      .await?
+      .convert()
+  }
+
+  /// Return information and statistics about a t-digest sketch.
+  ///
+  /// <https://redis.io/commands/tdigest.info/>
+  async fn tdigest_info<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_info(self, key).await?.convert()
+  }
+
+  /// Return the maximum observation value from a t-digest sketch.
+  ///
+  /// <https://redis.io/commands/tdigest.max/>
+  async fn tdigest_max<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_max(self, key).await?.convert()
+  }
+
+  /// Merge multiple t-digest sketches into a single sketch.
+  ///
+  /// <https://redis.io/commands/tdigest.merge/>
+  async fn tdigest_merge<R, D, S>(
+    &self,
+    destination: D,
+    sources: S,
+    compression: Option<u64>,
+    r#override: bool,
+  ) -> RedisResult<R>
+  where
+    R: FromRedis,
+    D: Into<RedisKey> + Send,
+    S: Into<MultipleKeys> + Send,
+  {
+    into!(destination, sources);
+    commands::redis_bloom::tdigest_merge(self, destination, sources, compression, r#override)
+      .await?
+      .convert()
+  }
+
+  /// Return the minimum observation value from a t-digest sketch.
+  ///
+  /// <https://redis.io/commands/tdigest.min/>
+  async fn tdigest_min<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_min(self, key).await?.convert()
+  }
+
+  /// Return, for each input fraction, an estimation of the value smaller than the given fraction of observations.
+  ///
+  /// <https://redis.io/commands/tdigest.quantile/>
+  async fn tdigest_quantile<R, K>(&self, key: K, quantiles: Vec<f64>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_quantile(self, key, quantiles)
+      .await?
+      .convert()
+  }
+
+  /// Return, for each input value, an estimation of the number of observations smaller than the value.
+  ///
+  /// <https://redis.io/commands/tdigest.rank/>
+  async fn tdigest_rank<R, K>(&self, key: K, values: Vec<f64>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_rank(self, key, values, false)
+      .await?
+      .convert()
+  }
+
+  /// Reset a t-digest sketch, emptying it and re-initializing it.
+  ///
+  /// <https://redis.io/commands/tdigest.reset/>
+  async fn tdigest_reset<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_reset(self, key).await?.convert()
+  }
+
+  /// Return, for each input value, an estimation of the number of observations larger than the value.
+  ///
+  /// <https://redis.io/commands/tdigest.revrank/>
+  async fn tdigest_revrank<R, K>(&self, key: K, values: Vec<f64>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_rank(self, key, values, true)
+      .await?
+      .convert()
+  }
+
+  /// Return an estimation of the mean value from the sketch, excluding observation values outside the low and high
+  /// cutoff quantiles.
+  ///
+  /// <https://redis.io/commands/tdigest.trimmed_mean/>
+  async fn tdigest_trimmed_mean<R, K>(&self, key: K, low_cut_quantile: f64, high_cut_quantile: f64) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::redis_bloom::tdigest_trimmed_mean(self, key, low_cut_quantile, high_cut_quantile)
+      .await?
+      .convert()
+  }
+}
@@ -0,0 +1,327 @@
+use crate::{
+  commands,
+  interfaces::{ClientLike, RedisResult},
+  types::{
+    encode_f32_vector,
+    FromRedis,
+    FtAggregateOptions,
+    FtCreateOptions,
+    FtSearchOptions,
+    MultipleStrings,
+    SearchSchema,
+    SpellcheckTerms,
+  },
+};
+use bytes_utils::Str;
+
+/// A [RediSearch](https://github.com/RediSearch/RediSearch) interface.
+///
+/// Responses can be parsed into [SearchResults](crate::types::SearchResults) or
+/// [AggregateResults](crate::types::AggregateResults) with either RESP2 or RESP3. See
+/// [ft_aggregate_stream](crate::clients::RedisClient::ft_aggregate_stream) to page through aggregation results with
+/// a cursor.
+#[async_trait]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+pub trait RediSearchInterface: ClientLike + Sized {
+  /// Returns a list of all existing indexes.
+  ///
+  /// <https://redis.io/commands/ft._list/>
+  async fn ft_list<R>(&self) -> RedisResult<R>
+  where
+    R: FromRedis,
+  {
+    commands::redisearch::ft_list(self).await?.convert()
+  }
+
+  /// Run a search query on an index, and perform aggregate transformations on the results.
+  ///
+  /// <https://redis.io/commands/ft.aggregate/>
+  async fn ft_aggregate<R, I, Q>(&self, index: I, query: Q, options: FtAggregateOptions) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+    Q: Into<Str> + Send,
+  {
+    into!(index, query);
+    commands::redisearch::ft_aggregate(self, index, query, options)
+      .await?
+      .convert()
+  }
+
+  /// Search the index with a textual query, returning either documents or just ids.
+  ///
+  /// ```rust no_run
+  /// # use fred::prelude::*;
+  /// # use fred::types::{FtCreateOptions, FtSearchOptions, IndexKind, SearchResults, SearchSchema};
+  /// async fn example(client: &RedisClient) -> Result<(), RedisError> {
+  ///   let _: () = client
+  ///     .ft_create(
+  ///       "idx",
+  ///       FtCreateOptions {
+  ///         on: Some(IndexKind::Hash),
+  ///         prefixes: vec!["doc:".into()],
+  ///         ..Default::default()
+  ///       },
+  ///       vec![SearchSchema::text("title").sortable(), SearchSchema::numeric("year")],
+  ///     )
+  ///     .await?;
+  ///   let _: () = client.hset("doc:1", [("title", "hello world"), ("year", "2024")]).await?;
+  ///
+  ///   let results: SearchResults = client
+  ///     .ft_search("idx", "@title:hello", FtSearchOptions::default())
+  ///     .await?;
+  ///   assert_eq!(results.results[0].id, "doc:1");
+  ///   Ok(())
+  /// }
+  /// ```
+  ///
+  /// <https://redis.io/commands/ft.search/>
+  async fn ft_search<R, I, Q>(&self, index: I, query: Q, options: FtSearchOptions) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+    Q: Into<Str> + Send,
+  {
+    into!(index, query);
+    commands::redisearch::ft_search(self, index, query, options)
+      .await?
+      .convert()
+  }
+
+  /// Run a K-nearest-neighbors vector similarity query against the `VECTOR` attribute `field`.
+  ///
+  /// The `vector` is encoded as a little-endian `FLOAT32` blob and sent as a query parameter. The `prefilter` query
+  /// limits the candidate documents and should be `"*"` to search the whole index. Unless overridden in `options`
+  /// the results are sorted by distance, limited to `k` documents, and use dialect 2. Responses can be parsed into
+  /// [KnnResults](crate::types::KnnResults).
+  ///
+  /// ```rust no_run
+  /// # use fred::prelude::*;
+  /// # use fred::types::{FtSearchOptions, KnnResults};
+  /// async fn example(client: &RedisClient) -> Result<(), RedisError> {
+  ///   let results: KnnResults = client
+  ///     .ft_search_knn("idx", "embedding", &[0.1, 0.2, 0.3], 3, "*", FtSearchOptions::default())
+  ///     .await?;
+  ///
+  ///   for (key, score, fields) in results.into_tuples() {
+  ///     println!("{}: {} {:?}", key, score, fields);
+  ///   }
+  ///   Ok(())
+  /// }
+  /// ```
+  ///
+  /// <https://redis.io/docs/interact/search-and-query/advanced-concepts/vectors/>
+  async fn ft_search_knn<R, I, F, Q>(
+    &self,
+    index: I,
+    field: F,
+    vector: &[f32],
+    k: u64,
+    prefilter: Q,
+    options: FtSearchOptions,
+  ) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+    F: Into<Str> + Send,
+    Q: Into<Str> + Send,
+  {
+    into!(index, field, prefilter);
+    let vector = encode_f32_vector(vector);
+    commands::redisearch::ft_search_knn(self, index, field, vector, k, prefilter, options)
+      .await?
+      .convert()
+  }
+
+  /// Create an index with the given specification.
+  ///
+  /// <https://redis.io/commands/ft.create/>
+  async fn ft_create<R, I>(&self, index: I, options: FtCreateOptions, schema: Vec<SearchSchema>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+  {
+    into!(index);
+    commands::redisearch::ft_create(self, index, options, schema)
+      .await?
+      .convert()
+  }
+
+  /// Add a new attribute to the index.
+  ///
+  /// <https://redis.io/commands/ft.alter/>
+  async fn ft_alter<R, I>(&self, index: I, skipinitialscan: bool, schema: SearchSchema) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+  {
+    into!(index);
+    commands::redisearch::ft_alter(self, index, skipinitialscan, schema)
+      .await?
+      .convert()
+  }
+
+  /// Add an alias to an index.
+  ///
+  /// <https://redis.io/commands/ft.aliasadd/>
+  async fn ft_aliasadd<R, A, I>(&self, alias: A, index: I) -> RedisResult<R>
+  where
+    R: FromRedis,
+    A: Into<Str> + Send,
+    I: Into<Str> + Send,
+  {
+    into!(alias, index);
+    commands::redisearch::ft_aliasadd(self, alias, index).await?.convert()
+  }
+
+  /// Remove an alias from an index.
+  ///
+  /// <https://redis.io/commands/ft.aliasdel/>
+  async fn ft_aliasdel<R, A>(&self, alias: A) -> RedisResult<R>
+  where
+    R: FromRedis,
+    A: Into<Str> + Send,
+  {
+    into!(alias);
+    commands::redisearch::ft_aliasdel(self, alias).await?.convert()
+  }
+
+  /// Add an alias to an index. If the alias is already associated with another index, this command removes the
+  /// alias association with the previous index.
+  ///
+  /// <https://redis.io/commands/ft.aliasupdate/>
+  async fn ft_aliasupdate<R, A, I>(&self, alias: A, index: I) -> RedisResult<R>
+  where
+    R: FromRedis,
+    A: Into<Str> + Send,
+    I: Into<Str> + Send,
+  {
+    into!(alias, index);
+    commands::redisearch::ft_aliasupdate(self, alias, index)
+      .await?
+      .convert()
+  }
+
+  /// Delete a cursor.
+  ///
+  /// <https://redis.io/commands/ft.cursor-del/>
+  async fn ft_cursor_del<R, I>(&self, index: I, cursor: u64) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+  {
+    into!(index);
+    commands::redisearch::ft_cursor_del(self, index, cursor)
+      .await?
+      .convert()
+  }
+
+  /// Read the next results from an existing cursor.
+  ///
+  /// <https://redis.io/commands/ft.cursor-read/>
+  async fn ft_cursor_read<R, I>(&self, index: I, cursor: u64, count: Option<u64>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+  {
+    into!(index);
+    commands::redisearch::ft_cursor_read(self, index, cursor, count)
+      .await?
+      .convert()
+  }
+
+  /// Delete an index.
+  ///
+  /// <https://redis.io/commands/ft.dropindex/>
+  async fn ft_dropindex<R, I>(&self, index: I, dd: bool) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+  {
+    into!(index);
+    commands::redisearch::ft_dropindex(self, index, dd).await?.convert()
+  }
+
+  /// Return the execution plan for a complex query.
+  ///
+  /// <https://redis.io/commands/ft.explain/>
+  async fn ft_explain<R, I, Q>(&self, index: I, query: Q, dialect: Option<i64>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+    Q: Into<Str> + Send,
+  {
+    into!(index, query);
+    commands::redisearch::ft_explain(self, index, query, dialect)
+      .await?
+      .convert()
+  }
+
+  /// Return information and statistics on the index.
+  ///
+  /// <https://redis.io/commands/ft.info/>
+  async fn ft_info<R, I>(&self, index: I) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+  {
+    into!(index);
+    commands::redisearch::ft_info(self, index).await?.convert()
+  }
+
+  /// Perform spelling correction on a query, returning suggestions for misspelled terms.
+  ///
+  /// <https://redis.io/commands/ft.spellcheck/>
+  async fn ft_spellcheck<R, I, Q>(
+    &self,
+    index: I,
+    query: Q,
+    distance: Option<u8>,
+    terms: Option<SpellcheckTerms>,
+    dialect: Option<i64>,
+  ) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+    Q: Into<Str> + Send,
+  {
+    into!(index, query);
+    commands::redisearch::ft_spellcheck(self, index, query, distance, terms, dialect)
+      .await?
+      .convert()
+  }
+
+  /// Dump the contents of a synonym group.
+  ///
+  /// <https://redis.io/commands/ft.syndump/>
+  async fn ft_syndump<R, I>(&self, index: I) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+  {
+    into!(index);
+    commands::redisearch::ft_syndump(self, index).await?.convert()
+  }
+
+  /// Update a synonym group.
+  ///
+  /// <https://redis.io/commands/ft.synupdate/>
+  async fn ft_synupdate<R, I, S, T>(
+    &self,
+    index: I,
+    synonym_group_id: S,
+    skipinitialscan: bool,
+    terms: T,
+  ) -> RedisResult<R>
+  where
+    R: FromRedis,
+    I: Into<Str> + Send,
+    S: Into<Str> + Send,
+    T: Into<MultipleStrings> + Send,
+  {
+    into!(index, synonym_group_id, terms);
+    commands::redisearch::ft_synupdate(self, index, synonym_group_id, skipinitialscan, terms)
+      .await?
+      .convert()
+  }
+}
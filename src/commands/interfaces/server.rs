@@ -2,10 +2,10 @@ use crate::{
   commands,
   error::RedisError,
   interfaces::{ClientLike, RedisResult},
-  types::{FromRedis, RespVersion, Server},
+  types::{FromRedis, MultipleStrings, MultipleValues, RedisKey, RedisValue, RespVersion, Server},
 };
 use bytes_utils::Str;
-use std::time::Duration;
+use std::{convert::TryInto, time::Duration};
 use tokio::time::interval as tokio_interval;
 
 /// Functions for authenticating clients.
@@ -152,6 +152,212 @@ pub trait ServerInterface: ClientLike {
     commands::server::wait(self, numreplicas, timeout).await?.convert()
   }
 
+  /// Return details about every Redis command.
+  ///
+  /// Callers can use `Vec<CommandInfo>` as the response type. See [CommandInfo](crate::types::CommandInfo).
+  ///
+  /// <https://redis.io/commands/command>
+  async fn command<R>(&self) -> RedisResult<R>
+  where
+    R: FromRedis,
+  {
+    commands::server::command(self).await?.convert()
+  }
+
+  /// Return the total number of commands in the Redis server.
+  ///
+  /// <https://redis.io/commands/command-count>
+  async fn command_count<R>(&self) -> RedisResult<R>
+  where
+    R: FromRedis,
+  {
+    commands::server::command_count(self).await?.convert()
+  }
+
+  /// Return documentary information about the provided commands, or all commands if none are provided.
+  ///
+  /// <https://redis.io/commands/command-docs>
+  async fn command_docs<R, C>(&self, names: C) -> RedisResult<R>
+  where
+    R: FromRedis,
+    C: Into<MultipleStrings> + Send,
+  {
+    into!(names);
+    commands::server::command_docs(self, names).await?.convert()
+  }
+
+  /// Return the keys from a full Redis command, where the first argument is the command name.
+  ///
+  /// <https://redis.io/commands/command-getkeys>
+  async fn command_getkeys<R, A>(&self, args: A) -> RedisResult<R>
+  where
+    R: FromRedis,
+    A: TryInto<MultipleValues> + Send,
+    A::Error: Into<RedisError> + Send,
+  {
+    try_into!(args);
+    commands::server::command_getkeys(self, args).await?.convert()
+  }
+
+  /// Return details about the provided commands, or all commands if none are provided.
+  ///
+  /// Callers can use `Vec<Option<CommandInfo>>` as the response type. See [CommandInfo](crate::types::CommandInfo).
+  ///
+  /// <https://redis.io/commands/command-info>
+  async fn command_info<R, C>(&self, names: C) -> RedisResult<R>
+  where
+    R: FromRedis,
+    C: Into<MultipleStrings> + Send,
+  {
+    into!(names);
+    commands::server::command_info(self, names).await?.convert()
+  }
+
+  /// Return a human-readable latency analysis report.
+  ///
+  /// Clustered clients send the `LATENCY` commands to a random node. Use
+  /// [with_cluster_node](crate::clients::RedisClient::with_cluster_node) to target a specific node.
+  ///
+  /// <https://redis.io/commands/latency-doctor>
+  async fn latency_doctor<R>(&self) -> RedisResult<R>
+  where
+    R: FromRedis,
+  {
+    commands::server::latency_doctor(self).await?.convert()
+  }
+
+  /// Return a latency histogram for the provided commands, or all commands if none are provided.
+  ///
+  /// <https://redis.io/commands/latency-histogram>
+  async fn latency_histogram<R, C>(&self, names: C) -> RedisResult<R>
+  where
+    R: FromRedis,
+    C: Into<MultipleStrings> + Send,
+  {
+    into!(names);
+    commands::server::latency_histogram(self, names).await?.convert()
+  }
+
+  /// Return the latency samples for the provided event.
+  ///
+  /// Callers can use `Vec<LatencySample>` as the response type. See [LatencySample](crate::types::LatencySample).
+  ///
+  /// <https://redis.io/commands/latency-history>
+  async fn latency_history<R, S>(&self, event: S) -> RedisResult<R>
+  where
+    R: FromRedis,
+    S: Into<Str> + Send,
+  {
+    into!(event);
+    commands::server::latency_history(self, event).await?.convert()
+  }
+
+  /// Return the latest latency samples for all events.
+  ///
+  /// Callers can use `Vec<LatencyEvent>` as the response type. See [LatencyEvent](crate::types::LatencyEvent).
+  ///
+  /// <https://redis.io/commands/latency-latest>
+  async fn latency_latest<R>(&self) -> RedisResult<R>
+  where
+    R: FromRedis,
+  {
+    commands::server::latency_latest(self).await?.convert()
+  }
+
+  /// Reset the latency spikes time series for the provided events, or all events if none are provided.
+  ///
+  /// Returns the number of event time series that were reset. Cluster clients must choose a node with
+  /// [with_cluster_node](crate::clients::RedisClient::with_cluster_node).
+  ///
+  /// <https://redis.io/commands/latency-reset>
+  async fn latency_reset<R, S>(&self, events: S) -> RedisResult<R>
+  where
+    R: FromRedis,
+    S: Into<MultipleStrings> + Send,
+  {
+    into!(events);
+    commands::server::latency_reset(self, events).await?.convert()
+  }
+
+  /// Return the modules loaded by the server.
+  ///
+  /// Callers can use `Vec<ModuleInfo>` as the response type. See [ModuleInfo](crate::types::ModuleInfo).
+  ///
+  /// <https://redis.io/commands/module-list>
+  async fn module_list<R>(&self) -> RedisResult<R>
+  where
+    R: FromRedis,
+  {
+    commands::server::module_list(self).await?.convert()
+  }
+
+  /// Load a module from a dynamic library at runtime.
+  ///
+  /// Cluster clients must load the module on each node with
+  /// [with_cluster_node](crate::clients::RedisClient::with_cluster_node).
+  ///
+  /// <https://redis.io/commands/module-load>
+  async fn module_load<S, A>(&self, path: S, args: A) -> RedisResult<()>
+  where
+    S: Into<Str> + Send,
+    A: TryInto<MultipleValues> + Send,
+    A::Error: Into<RedisError> + Send,
+  {
+    into!(path);
+    try_into!(args);
+    commands::server::module_load(self, path, args).await
+  }
+
+  /// Load a module from a dynamic library at runtime with the provided configuration directives and arguments.
+  ///
+  /// Cluster clients must load the module on each node with
+  /// [with_cluster_node](crate::clients::RedisClient::with_cluster_node).
+  ///
+  /// <https://redis.io/commands/module-loadex>
+  async fn module_loadex<S, A>(&self, path: S, configs: Vec<(Str, RedisValue)>, args: A) -> RedisResult<()>
+  where
+    S: Into<Str> + Send,
+    A: TryInto<MultipleValues> + Send,
+    A::Error: Into<RedisError> + Send,
+  {
+    into!(path);
+    try_into!(args);
+    commands::server::module_loadex(self, path, configs, args).await
+  }
+
+  /// Unload a module.
+  ///
+  /// Cluster clients must unload the module on each node with
+  /// [with_cluster_node](crate::clients::RedisClient::with_cluster_node).
+  ///
+  /// <https://redis.io/commands/module-unload>
+  async fn module_unload<S>(&self, name: S) -> RedisResult<()>
+  where
+    S: Into<Str> + Send,
+  {
+    into!(name);
+    commands::server::module_unload(self, name).await
+  }
+
+  /// Return debugging information about the value stored at `key`.
+  ///
+  /// <https://redis.io/commands/debug-object>
+  async fn debug_object<R, K>(&self, key: K) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::server::debug_object(self, key).await?.convert()
+  }
+
+  /// Block the server for the provided number of seconds.
+  ///
+  /// <https://redis.io/commands/debug-sleep>
+  async fn debug_sleep(&self, seconds: f64) -> RedisResult<()> {
+    commands::server::debug_sleep(self, seconds).await
+  }
+
   /// Read the primary Redis server identifier returned from the sentinel nodes.
   fn sentinel_primary(&self) -> Option<Server> {
     self.inner().server_state.read().kind.sentinel_primary()
@@ -21,6 +21,13 @@ use crate::{
 use bytes_utils::Str;
 use std::{convert::TryInto, hash::Hash};
 
+#[cfg(feature = "serde-json")]
+use crate::types::StreamFields;
+#[cfg(feature = "serde-json")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "serde-json")]
+use std::collections::HashMap;
+
 /// Functions that implement the [streams](https://redis.io/commands#stream) interface.
 ///
 /// **Note:** Several of the stream commands can return types with verbose type declarations. Additionally, certain
@@ -148,6 +155,44 @@ pub trait StreamsInterface: ClientLike + Sized {
       .into_xread_value()
   }
 
+  /// Return the stream entries matching the provided range of IDs, deserializing the fields in each entry into `T`.
+  ///
+  /// See [StreamFields](crate::types::StreamFields) for more information.
+  ///
+  /// <https://redis.io/commands/xrange>
+  #[cfg(feature = "serde-json")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+  async fn xrange_typed<Ri, T, K, S, E>(
+    &self,
+    key: K,
+    start: S,
+    end: E,
+    count: Option<u64>,
+  ) -> RedisResult<Vec<(Ri, T)>>
+  where
+    Ri: FromRedis,
+    T: DeserializeOwned,
+    K: Into<RedisKey> + Send,
+    S: TryInto<RedisValue> + Send,
+    S::Error: Into<RedisError> + Send,
+    E: TryInto<RedisValue> + Send,
+    E::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(start, end);
+    let entries: Vec<(Ri, StreamFields<T>)> = commands::streams::xrange(self, key, start, end, count)
+      .await?
+      .flatten_array_values(1)
+      .convert()?;
+
+    Ok(
+      entries
+        .into_iter()
+        .map(|(id, fields)| (id, fields.into_inner()))
+        .collect(),
+    )
+  }
+
   /// The command returns the stream entries matching a given range of IDs. The range is specified by a minimum
   /// and maximum ID. All the entries having an ID between the two specified or exactly one of the two IDs specified
   /// (closed interval) are returned.
@@ -197,6 +242,45 @@ pub trait StreamsInterface: ClientLike + Sized {
       .into_xread_value()
   }
 
+  /// Similar to `XRANGE`, but with the results returned in reverse order, deserializing the fields in each entry into
+  /// `T`.
+  ///
+  /// See [StreamFields](crate::types::StreamFields) for more information.
+  ///
+  /// <https://redis.io/commands/xrevrange>
+  #[cfg(feature = "serde-json")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+  async fn xrevrange_typed<Ri, T, K, E, S>(
+    &self,
+    key: K,
+    end: E,
+    start: S,
+    count: Option<u64>,
+  ) -> RedisResult<Vec<(Ri, T)>>
+  where
+    Ri: FromRedis,
+    T: DeserializeOwned,
+    K: Into<RedisKey> + Send,
+    S: TryInto<RedisValue> + Send,
+    S::Error: Into<RedisError> + Send,
+    E: TryInto<RedisValue> + Send,
+    E::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(start, end);
+    let entries: Vec<(Ri, StreamFields<T>)> = commands::streams::xrevrange(self, key, end, start, count)
+      .await?
+      .flatten_array_values(1)
+      .convert()?;
+
+    Ok(
+      entries
+        .into_iter()
+        .map(|(id, fields)| (id, fields.into_inner()))
+        .collect(),
+    )
+  }
+
   /// Similar to `XRANGE`, but with the results returned in reverse order.
   ///
   /// <https://redis.io/commands/xrevrange>
@@ -348,6 +432,44 @@ pub trait StreamsInterface: ClientLike + Sized {
       .into_xread_response()
   }
 
+  /// Read data from one or multiple streams, deserializing the fields in each entry into `T`.
+  ///
+  /// See [StreamFields](crate::types::StreamFields) for more information.
+  ///
+  /// <https://redis.io/commands/xread>
+  #[cfg(feature = "serde-json")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+  async fn xread_typed<Rk, Ri, T, K, I>(
+    &self,
+    count: Option<u64>,
+    block: Option<u64>,
+    keys: K,
+    ids: I,
+  ) -> RedisResult<HashMap<Rk, Vec<(Ri, T)>>>
+  where
+    Rk: FromRedisKey + Hash + Eq,
+    Ri: FromRedis,
+    T: DeserializeOwned,
+    K: Into<MultipleKeys> + Send,
+    I: Into<MultipleIDs> + Send,
+  {
+    into!(keys, ids);
+    let entries: HashMap<Rk, Vec<(Ri, StreamFields<T>)>> = commands::streams::xread(self, count, block, keys, ids)
+      .await?
+      .flatten_array_values(2)
+      .convert()?;
+
+    Ok(
+      entries
+        .into_iter()
+        .map(|(key, entries)| {
+          let entries = entries.into_iter().map(|(id, fields)| (id, fields.into_inner()));
+          (key, entries.collect())
+        })
+        .collect(),
+    )
+  }
+
   /// Read data from one or multiple streams, only returning entries with an ID greater than the last received ID
   /// reported by the caller.
   ///
@@ -484,6 +606,48 @@ pub trait StreamsInterface: ClientLike + Sized {
       .into_xread_response()
   }
 
+  /// A variation of [xread_typed](Self::xread_typed) that reads from a consumer group.
+  ///
+  /// <https://redis.io/commands/xreadgroup>
+  #[cfg(feature = "serde-json")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+  async fn xreadgroup_typed<Rk, Ri, T, G, C, K, I>(
+    &self,
+    group: G,
+    consumer: C,
+    count: Option<u64>,
+    block: Option<u64>,
+    noack: bool,
+    keys: K,
+    ids: I,
+  ) -> RedisResult<HashMap<Rk, Vec<(Ri, T)>>>
+  where
+    Rk: FromRedisKey + Hash + Eq,
+    Ri: FromRedis,
+    T: DeserializeOwned,
+    G: Into<Str> + Send,
+    C: Into<Str> + Send,
+    K: Into<MultipleKeys> + Send,
+    I: Into<MultipleIDs> + Send,
+  {
+    into!(group, consumer, keys, ids);
+    let entries: HashMap<Rk, Vec<(Ri, StreamFields<T>)>> =
+      commands::streams::xreadgroup(self, group, consumer, count, block, noack, keys, ids)
+        .await?
+        .flatten_array_values(2)
+        .convert()?;
+
+    Ok(
+      entries
+        .into_iter()
+        .map(|(key, entries)| {
+          let entries = entries.into_iter().map(|(id, fields)| (id, fields.into_inner()));
+          (key, entries.collect())
+        })
+        .collect(),
+    )
+  }
+
   /// A special version of the `XREAD` command with support for consumer groups.
   ///
   /// Declaring proper type declarations for this command can be complicated due to the complex nature of the response
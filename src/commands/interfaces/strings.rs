@@ -1 +1,73 @@
+use crate::{
+  commands,
+  error::RedisError,
+  interfaces::{ClientLike, RedisResult},
+  types::{FromRedis, GetExpiration, RedisKey, RedisValue},
+};
+use std::convert::TryInto;
 
+/// Functions that implement the [string](https://redis.io/commands#string) interface.
+///
+/// Older string commands such as `GET` and `SET` are implemented by the
+/// [KeysInterface](crate::interfaces::KeysInterface).
+#[async_trait]
+pub trait StringsInterface: ClientLike + Sized {
+  /// Returns the substring of the string value stored at `key` with offsets `start` and `end` (both inclusive).
+  ///
+  /// Unlike `GETRANGE` this accepts negative offsets relative to the end of the string. Deprecated by Redis in favor
+  /// of `GETRANGE`.
+  ///
+  /// <https://redis.io/commands/substr>
+  async fn substr<R, K>(&self, key: K, start: i64, end: i64) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::strings::substr(self, key, start, end).await?.convert()
+  }
+
+  /// Get the value of `key` and optionally set or remove its expiration.
+  ///
+  /// <https://redis.io/commands/getex>
+  async fn getex<R, K>(&self, key: K, expiration: Option<GetExpiration>) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+  {
+    into!(key);
+    commands::strings::getex(self, key, expiration).await?.convert()
+  }
+
+  /// Set `key` to `value` with a timeout in seconds.
+  ///
+  /// <https://redis.io/commands/setex>
+  async fn setex<R, K, V>(&self, key: K, seconds: i64, value: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisValue> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(value);
+    commands::strings::setex(self, key, seconds, value).await?.convert()
+  }
+
+  /// Set `key` to `value` with a timeout in milliseconds.
+  ///
+  /// <https://redis.io/commands/psetex>
+  async fn psetex<R, K, V>(&self, key: K, milliseconds: i64, value: V) -> RedisResult<R>
+  where
+    R: FromRedis,
+    K: Into<RedisKey> + Send,
+    V: TryInto<RedisValue> + Send,
+    V::Error: Into<RedisError> + Send,
+  {
+    into!(key);
+    try_into!(value);
+    commands::strings::psetex(self, key, milliseconds, value)
+      .await?
+      .convert()
+  }
+}
@@ -2,7 +2,7 @@ use crate::{
   commands,
   interfaces::ClientLike,
   prelude::RedisResult,
-  types::{Invalidation, MultipleStrings},
+  types::{ClientCacheConfig, ClientCacheStats, Invalidation, MultipleStrings},
 };
 use tokio::{sync::broadcast::Receiver as BroadcastReceiver, task::JoinHandle};
 
@@ -12,8 +12,11 @@ use tokio::{sync::broadcast::Receiver as BroadcastReceiver, task::JoinHandle};
 pub trait TrackingInterface: ClientLike + Sized {
   /// Send the [CLIENT TRACKING](https://redis.io/commands/client-tracking/) command to all connected servers, subscribing to [invalidation messages](Self::on_invalidation) on the same connection.
   ///
-  /// This interface requires the RESP3 protocol mode and supports all server deployment types (centralized,
-  /// clustered, and sentinel).
+  /// This interface supports all server deployment types (centralized, clustered, and sentinel). With RESP2 the
+  /// client opens a companion connection to each primary node that subscribes to the `__redis__:invalidate` channel,
+  /// and tracking is enabled with `REDIRECT` to the companion's connection ID. The companion is managed
+  /// automatically across reconnections and cluster changes, and invalidations are delivered via
+  /// [invalidation_rx](Self::invalidation_rx) in either protocol mode.
   ///
   /// See the basic [client tracking](crate::interfaces::ClientInterface::client_tracking) function for more
   /// information on the underlying commands.
@@ -32,7 +35,7 @@ pub trait TrackingInterface: ClientLike + Sized {
     commands::tracking::start_tracking(self, prefixes, bcast, optin, optout, noloop).await
   }
 
-  /// Disable client tracking on all connections.
+  /// Disable client tracking on all connections, closing the RESP2 companion connection if necessary.
   async fn stop_tracking(&self) -> RedisResult<()> {
     commands::tracking::stop_tracking(self).await
   }
@@ -63,4 +66,31 @@ pub trait TrackingInterface: ClientLike + Sized {
   fn invalidation_rx(&self) -> BroadcastReceiver<Invalidation> {
     self.inner().notifications.invalidations.load().subscribe()
   }
+
+  /// Enable a local cache that serves `GET`, `HGET`, `HGETALL`, `MGET`, `SMEMBERS`, and similar read-only commands
+  /// until the server sends an invalidation message for the associated keys.
+  ///
+  /// This sends `CLIENT TRACKING ON` to all connected servers and again after each reconnection, clearing the cache
+  /// each time a connection is established. Commands sent via pipelines, transactions, replicas, or to a specific
+  /// cluster node are not cached.
+  ///
+  /// See [start_tracking](Self::start_tracking) for more information on RESP2 support.
+  async fn enable_client_cache(&self, config: ClientCacheConfig) -> RedisResult<()> {
+    commands::tracking::enable_client_cache(self, config).await
+  }
+
+  /// Disable the local cache and client tracking on all connections.
+  async fn disable_client_cache(&self) -> RedisResult<()> {
+    commands::tracking::disable_client_cache(self).await
+  }
+
+  /// Remove all entries from the local cache.
+  fn clear_client_cache(&self) {
+    self.inner().cache.clear();
+  }
+
+  /// Read the hit, miss, and eviction counters for the local cache.
+  fn client_cache_stats(&self) -> ClientCacheStats {
+    self.inner().cache.stats()
+  }
 }
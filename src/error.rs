@@ -1,3 +1,4 @@
+use crate::types::RedisKey;
 use bytes_utils::string::Utf8Error as BytesUtf8Error;
 use futures::channel::oneshot::Canceled;
 use redis_protocol::{resp2::types::Frame as Resp2Frame, types::RedisProtocolError};
@@ -91,17 +92,34 @@ impl RedisErrorKind {
   }
 }
 
+/// An error from an individual hash slot when a multi-key command is split across cluster hash slots.
+///
+/// See [split_cross_slot](crate::types::Options::split_cross_slot) for more information.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlotError {
+  pub slot:  u16,
+  /// The keys sent to the hash slot, in their original order.
+  pub keys:  Vec<RedisKey>,
+  pub error: RedisError,
+}
+
 /// An error from Redis.
 pub struct RedisError {
   /// Details about the specific error condition.
-  details: Cow<'static, str>,
+  details:     Cow<'static, str>,
   /// The kind of error.
-  kind:    RedisErrorKind,
+  kind:        RedisErrorKind,
+  /// Errors from individual hash slots when a split multi-key command partially fails.
+  slot_errors: Option<Box<[SlotError]>>,
 }
 
 impl Clone for RedisError {
   fn clone(&self) -> Self {
-    RedisError::new(self.kind.clone(), self.details.clone())
+    RedisError {
+      details:     self.details.clone(),
+      kind:        self.kind.clone(),
+      slot_errors: self.slot_errors.clone(),
+    }
   }
 }
 
@@ -327,6 +345,16 @@ impl RedisError {
     RedisError {
       kind,
       details: details.into(),
+      slot_errors: None,
+    }
+  }
+
+  /// Create a new `Cluster` error from the failed hash slots of a split multi-key command.
+  pub(crate) fn new_slot_errors(errors: Vec<SlotError>, total: usize) -> Self {
+    RedisError {
+      kind:        RedisErrorKind::Cluster,
+      details:     format!("Failed to run command on {} of {} hash slots.", errors.len(), total).into(),
+      slot_errors: Some(errors.into_boxed_slice()),
     }
   }
 
@@ -345,6 +373,14 @@ impl RedisError {
     self.details.borrow()
   }
 
+  /// Read the errors from each failed hash slot, if the error came from a multi-key command that was split across
+  /// cluster hash slots.
+  ///
+  /// Keys in other hash slots were processed successfully.
+  pub fn slot_errors(&self) -> Option<&[SlotError]> {
+    self.slot_errors.as_deref()
+  }
+
   /// Create a new empty Canceled error.
   pub fn new_canceled() -> Self {
     RedisError::new(RedisErrorKind::Canceled, "Canceled.")
@@ -34,6 +34,8 @@ use tokio::{sync::broadcast::Receiver as BroadcastReceiver, task::JoinHandle};
 /// Type alias for `Result<T, RedisError>`.
 pub type RedisResult<T> = Result<T, RedisError>;
 
+#[cfg(feature = "client-tracking")]
+use crate::modules::cache;
 #[cfg(feature = "dns")]
 use crate::protocol::types::Resolve;
 
@@ -50,6 +52,11 @@ where
     command.debug_id()
   );
   command.inherit_options(inner);
+  #[cfg(feature = "client-tracking")]
+  let command = match cache::intercept(inner, command) {
+    Some(command) => command,
+    None => return Ok(()),
+  };
 
   send_to_router(inner, command.into())
 }
@@ -134,6 +141,13 @@ pub trait ClientLike: Clone + Send + Sized {
     default_send_command(self.inner(), command)
   }
 
+  /// Whether multi-key commands should be split by cluster hash slot, with an optional override of the client
+  /// config.
+  #[doc(hidden)]
+  fn split_cross_slot(&self, enabled: Option<bool>) -> bool {
+    self.is_clustered() && enabled.unwrap_or(self.inner().connection.split_cross_slot)
+  }
+
   /// The unique ID identifying this client and underlying connections.
   fn id(&self) -> &str {
     &self.inner().id
@@ -236,6 +250,8 @@ pub trait ClientLike: Clone + Send + Sized {
   fn connect(&self) -> ConnectHandle {
     let inner = self.inner().clone();
     utils::reset_router_task(&inner);
+    #[cfg(feature = "credential-provider")]
+    utils::spawn_credential_refresh(&inner);
 
     tokio::spawn(async move {
       utils::clear_backchannel_state(&inner).await;
@@ -350,7 +366,8 @@ pub trait ClientLike: Clone + Send + Sized {
   /// interacting with third party modules or extensions.
   ///
   /// Callers should use the re-exported [redis_keyslot](crate::util::redis_keyslot) function to hash the command's
-  /// key, if necessary.
+  /// key, if necessary. Alternatively, [KeySpecs](crate::types::ClusterHash::KeySpecs) can be used to find the keys
+  /// via the server's key specifications.
   ///
   /// This interface should be used with caution as it may break the automatic pipeline features in the client if
   /// command flags are not properly configured.
@@ -570,6 +587,7 @@ pub trait EventInterface: ClientLike {
 
 pub use crate::commands::interfaces::{
   acl::AclInterface,
+  bitmaps::BitmapInterface,
   client::ClientInterface,
   cluster::ClusterInterface,
   config::ConfigInterface,
@@ -577,6 +595,7 @@ pub use crate::commands::interfaces::{
   hashes::HashesInterface,
   hyperloglog::HyperloglogInterface,
   keys::KeysInterface,
+  keyspace::KeyspaceNotificationsInterface,
   lists::ListInterface,
   lua::{FunctionInterface, LuaInterface},
   memory::MemoryInterface,
@@ -587,10 +606,15 @@ pub use crate::commands::interfaces::{
   slowlog::SlowlogInterface,
   sorted_sets::SortedSetsInterface,
   streams::StreamsInterface,
+  strings::StringsInterface,
 };
 
+#[cfg(feature = "redis-bloom")]
+pub use crate::commands::interfaces::redis_bloom::RedisBloomInterface;
 #[cfg(feature = "redis-json")]
 pub use crate::commands::interfaces::redis_json::RedisJsonInterface;
+#[cfg(feature = "redis-search")]
+pub use crate::commands::interfaces::redisearch::RediSearchInterface;
 #[cfg(feature = "sentinel-client")]
 pub use crate::commands::interfaces::sentinel::SentinelInterface;
 #[cfg(feature = "time-series")]
@@ -0,0 +1,455 @@
+use crate::{
+  modules::inner::RedisClientInner,
+  protocol::{
+    command::{RedisCommand, RedisCommandKind},
+    responders::ResponseKind,
+    utils as protocol_utils,
+  },
+  types::{ClientCacheConfig, ClientCacheStats, RedisKey, RedisValue},
+  utils,
+};
+use bytes::Bytes;
+use parking_lot::{Mutex, RwLock};
+use redis_protocol::resp3::types::Frame as Resp3Frame;
+use std::{
+  collections::{BTreeMap, HashMap, HashSet},
+  sync::{
+    atomic::{AtomicBool, AtomicUsize},
+    Arc,
+  },
+  time::Instant,
+};
+use tokio::sync::oneshot::channel as oneshot_channel;
+
+/// The command name and arguments, used to look up cached responses.
+type CacheKey = Vec<u8>;
+
+struct CacheEntry {
+  frame:   Resp3Frame,
+  keys:    Vec<Bytes>,
+  size:    usize,
+  created: Instant,
+  tick:    u64,
+}
+
+#[derive(Default)]
+struct CacheState {
+  entries: HashMap<CacheKey, CacheEntry>,
+  /// The cache keys that depend on each Redis key.
+  keys:    HashMap<Bytes, HashSet<CacheKey>>,
+  /// The cache keys ordered by last access.
+  lru:     BTreeMap<u64, CacheKey>,
+  tick:    u64,
+  stats:   ClientCacheStats,
+}
+
+impl CacheState {
+  fn next_tick(&mut self) -> u64 {
+    self.tick = self.tick.wrapping_add(1);
+    self.tick
+  }
+
+  fn remove(&mut self, key: &CacheKey) -> bool {
+    let entry = match self.entries.remove(key) {
+      Some(entry) => entry,
+      None => return false,
+    };
+
+    self.lru.remove(&entry.tick);
+    for redis_key in entry.keys.iter() {
+      if let Some(dependents) = self.keys.get_mut(redis_key) {
+        dependents.remove(key);
+        if dependents.is_empty() {
+          self.keys.remove(redis_key);
+        }
+      }
+    }
+    self.stats.entries = self.entries.len();
+    self.stats.memory = self.stats.memory.saturating_sub(entry.size);
+    true
+  }
+
+  fn insert(&mut self, key: CacheKey, mut entry: CacheEntry) {
+    entry.tick = self.next_tick();
+    self.lru.insert(entry.tick, key.clone());
+    for redis_key in entry.keys.iter() {
+      self.keys.entry(redis_key.clone()).or_default().insert(key.clone());
+    }
+    self.stats.memory += entry.size;
+    self.entries.insert(key, entry);
+    self.stats.entries = self.entries.len();
+  }
+
+  fn evict_lru(&mut self) -> bool {
+    let key = match self.lru.iter().next() {
+      Some((_, key)) => key.clone(),
+      None => return false,
+    };
+
+    self.stats.evictions += 1;
+    self.remove(&key)
+  }
+
+  fn clear(&mut self) {
+    self.stats.invalidations += self.entries.len() as u64;
+    self.entries.clear();
+    self.keys.clear();
+    self.lru.clear();
+    self.stats.entries = 0;
+    self.stats.memory = 0;
+  }
+}
+
+/// A size and TTL bounded LRU cache of command responses, invalidated by client tracking messages.
+#[derive(Default)]
+pub struct ClientCache {
+  config:      RwLock<Option<ClientCacheConfig>>,
+  /// Incremented whenever entries are invalidated, so that responses to commands sent before the invalidation are
+  /// not cached.
+  epoch:       Arc<AtomicUsize>,
+  /// Incremented whenever the cache is suspended while client tracking is reestablished.
+  suspensions: Arc<AtomicUsize>,
+  suspended:   Arc<AtomicBool>,
+  state:       Mutex<CacheState>,
+}
+
+impl ClientCache {
+  pub fn is_enabled(&self) -> bool {
+    self.config.read().is_some()
+  }
+
+  /// Whether the cache is enabled and client tracking is active on all connections.
+  pub fn is_active(&self) -> bool {
+    self.is_enabled() && !utils::read_bool_atomic(&self.suspended)
+  }
+
+  /// Clear the cache and stop serving commands until [resume](Self::resume) is called with the returned token.
+  pub fn suspend(&self) -> usize {
+    let mut state = self.state.lock();
+    utils::set_bool_atomic(&self.suspended, true);
+    utils::incr_atomic(&self.epoch);
+    state.clear();
+    utils::incr_atomic(&self.suspensions)
+  }
+
+  /// Resume serving commands if the cache was not suspended again after `token` was issued.
+  pub fn resume(&self, token: usize) {
+    let _state = self.state.lock();
+    if utils::read_atomic(&self.suspensions) == token {
+      utils::incr_atomic(&self.epoch);
+      utils::set_bool_atomic(&self.suspended, false);
+    }
+  }
+
+  pub fn enable(&self, config: ClientCacheConfig) {
+    let mut state = self.state.lock();
+    utils::incr_atomic(&self.epoch);
+    state.clear();
+    *self.config.write() = Some(config);
+  }
+
+  pub fn disable(&self) {
+    let mut state = self.state.lock();
+    utils::incr_atomic(&self.epoch);
+    self.config.write().take();
+    state.clear();
+  }
+
+  /// Remove all entries.
+  pub fn clear(&self) {
+    let mut state = self.state.lock();
+    utils::incr_atomic(&self.epoch);
+    state.clear();
+  }
+
+  /// Remove the entries that depend on the provided keys, or all entries if `keys` is empty.
+  pub fn invalidate(&self, keys: &[RedisKey]) {
+    if !self.is_enabled() {
+      return;
+    }
+    let mut state = self.state.lock();
+    utils::incr_atomic(&self.epoch);
+
+    if keys.is_empty() {
+      state.clear();
+      return;
+    }
+    for key in keys.iter() {
+      let dependents = match state.keys.get(key.as_bytes()) {
+        Some(dependents) => dependents.iter().cloned().collect::<Vec<_>>(),
+        None => continue,
+      };
+
+      for dependent in dependents.iter() {
+        if state.remove(dependent) {
+          state.stats.invalidations += 1;
+        }
+      }
+    }
+  }
+
+  pub fn stats(&self) -> ClientCacheStats {
+    self.state.lock().stats.clone()
+  }
+
+  fn get(&self, key: &CacheKey) -> Option<Resp3Frame> {
+    let ttl = match *self.config.read() {
+      Some(ref config) => config.ttl,
+      None => return None,
+    };
+    let mut state = self.state.lock();
+
+    let (expired, tick) = match state.entries.get(key) {
+      Some(entry) => (!ttl.is_zero() && entry.created.elapsed() >= ttl, entry.tick),
+      None => {
+        state.stats.misses += 1;
+        return None;
+      },
+    };
+    if expired {
+      state.remove(key);
+      state.stats.evictions += 1;
+      state.stats.misses += 1;
+      return None;
+    }
+
+    let next_tick = state.next_tick();
+    state.lru.remove(&tick);
+    state.lru.insert(next_tick, key.clone());
+    state.stats.hits += 1;
+    state.entries.get_mut(key).map(|entry| {
+      entry.tick = next_tick;
+      entry.frame.clone()
+    })
+  }
+
+  fn set(&self, key: CacheKey, keys: Vec<Bytes>, frame: Resp3Frame, epoch: usize) {
+    let (max_entries, max_memory) = match *self.config.read() {
+      Some(ref config) => (config.max_entries, config.max_memory),
+      None => return,
+    };
+    let size = protocol_utils::resp3_frame_size(&frame) + key.len() + keys.iter().map(|k| k.len()).sum::<usize>();
+    if max_entries == 0 || size > max_memory {
+      return;
+    }
+
+    let mut state = self.state.lock();
+    if utils::read_atomic(&self.epoch) != epoch || utils::read_bool_atomic(&self.suspended) {
+      return;
+    }
+    state.remove(&key);
+    while state.entries.len() >= max_entries || state.stats.memory + size > max_memory {
+      if !state.evict_lru() {
+        break;
+      }
+    }
+
+    let entry = CacheEntry {
+      created: Instant::now(),
+      tick: 0,
+      frame,
+      keys,
+      size,
+    };
+    state.insert(key, entry);
+  }
+}
+
+fn arg_bytes(value: &RedisValue) -> Option<Bytes> {
+  match value {
+    RedisValue::Bytes(ref b) => Some(b.clone()),
+    RedisValue::String(ref s) => Some(s.inner().clone()),
+    RedisValue::Integer(ref i) => Some(i.to_string().into()),
+    RedisValue::Double(ref f) => Some(f.to_string().into()),
+    _ => None,
+  }
+}
+
+/// Read the cache key and the Redis keys read by the command, if the command can be cached.
+fn cache_key(command: &RedisCommand) -> Option<(CacheKey, Vec<Bytes>)> {
+  if command.use_replica || command.cluster_node.is_some() {
+    return None;
+  }
+  let all_keys = match command.kind {
+    RedisCommandKind::Mget | RedisCommandKind::Exists => true,
+    RedisCommandKind::Get
+    | RedisCommandKind::GetRange
+    | RedisCommandKind::Strlen
+    | RedisCommandKind::Type
+    | RedisCommandKind::HExists
+    | RedisCommandKind::HGet
+    | RedisCommandKind::HGetAll
+    | RedisCommandKind::HKeys
+    | RedisCommandKind::HLen
+    | RedisCommandKind::HMGet
+    | RedisCommandKind::HStrLen
+    | RedisCommandKind::HVals
+    | RedisCommandKind::LIndex
+    | RedisCommandKind::LLen
+    | RedisCommandKind::LRange
+    | RedisCommandKind::Scard
+    | RedisCommandKind::Sismember
+    | RedisCommandKind::Smembers
+    | RedisCommandKind::Smismember
+    | RedisCommandKind::Zcard
+    | RedisCommandKind::Zrange
+    | RedisCommandKind::Zscore => false,
+    _ => return None,
+  };
+
+  let mut key = command.kind.to_str_debug().as_bytes().to_vec();
+  let mut keys = Vec::new();
+  for (idx, arg) in command.args().iter().enumerate() {
+    let arg = arg_bytes(arg)?;
+    key.extend_from_slice(&(arg.len() as u64).to_be_bytes());
+    key.extend_from_slice(&arg);
+
+    if all_keys || idx == 0 {
+      keys.push(arg);
+    }
+  }
+
+  if keys.is_empty() {
+    None
+  } else {
+    Some((key, keys))
+  }
+}
+
+/// Respond to the command from the cache, or intercept the response so it can be cached.
+///
+/// Returns `None` if the command was served from the cache.
+pub fn intercept(inner: &Arc<RedisClientInner>, mut command: RedisCommand) -> Option<RedisCommand> {
+  if !inner.cache.is_active() {
+    return Some(command);
+  }
+  let (key, keys) = match cache_key(&command) {
+    Some(key) => key,
+    None => return Some(command),
+  };
+  let tx = match command.response {
+    ResponseKind::Respond(ref mut tx) if tx.is_some() => tx.take(),
+    _ => return Some(command),
+  };
+
+  if let Some(frame) = inner.cache.get(&key) {
+    _trace!(inner, "Serving {} from client cache.", command.kind.to_str_debug());
+    if let Some(tx) = tx {
+      let _ = tx.send(Ok(frame));
+    }
+    return None;
+  }
+
+  let (cache_tx, cache_rx) = oneshot_channel();
+  command.response = ResponseKind::Respond(Some(cache_tx));
+  let epoch = utils::read_atomic(&inner.cache.epoch);
+  let inner = inner.clone();
+  tokio::spawn(async move {
+    if let Ok(result) = cache_rx.await {
+      if let Ok(ref frame) = result {
+        if !frame.is_error() {
+          inner.cache.set(key, keys, frame.clone(), epoch);
+        }
+      }
+      if let Some(tx) = tx {
+        let _ = tx.send(result);
+      }
+    }
+  });
+
+  Some(command)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  fn cache(max_entries: usize, ttl: Duration) -> ClientCache {
+    let cache = ClientCache::default();
+    cache.enable(ClientCacheConfig {
+      max_memory: 1024 * 1024,
+      max_entries,
+      ttl,
+    });
+    cache
+  }
+
+  fn frame(value: &'static str) -> Resp3Frame {
+    Resp3Frame::BlobString {
+      data:       Bytes::from_static(value.as_bytes()),
+      attributes: None,
+    }
+  }
+
+  fn set(cache: &ClientCache, key: &'static str) {
+    let epoch = utils::read_atomic(&cache.epoch);
+    cache.set(
+      key.as_bytes().to_vec(),
+      vec![Bytes::from_static(key.as_bytes())],
+      frame(key),
+      epoch,
+    );
+  }
+
+  #[test]
+  fn should_evict_least_recently_used() {
+    let cache = cache(2, Duration::from_secs(0));
+    set(&cache, "a");
+    set(&cache, "b");
+    assert!(cache.get(&b"a".to_vec()).is_some());
+    set(&cache, "c");
+
+    assert!(cache.get(&b"b".to_vec()).is_none());
+    assert_eq!(cache.get(&b"a".to_vec()), Some(frame("a")));
+    assert_eq!(cache.get(&b"c".to_vec()), Some(frame("c")));
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses, stats.evictions, stats.entries), (3, 1, 1, 2));
+  }
+
+  #[test]
+  fn should_invalidate_keys() {
+    let cache = cache(10, Duration::from_secs(0));
+    set(&cache, "a");
+    set(&cache, "b");
+    let epoch = utils::read_atomic(&cache.epoch);
+
+    cache.invalidate(&["a".into()]);
+    assert!(cache.get(&b"a".to_vec()).is_none());
+    assert!(cache.get(&b"b".to_vec()).is_some());
+    // responses to commands sent before the invalidation are not cached
+    cache.set(b"a".to_vec(), vec![Bytes::from_static(b"a")], frame("a"), epoch);
+    assert!(cache.get(&b"a".to_vec()).is_none());
+
+    cache.invalidate(&[]);
+    assert_eq!(cache.stats().entries, 0);
+    assert_eq!(cache.stats().invalidations, 2);
+  }
+
+  #[test]
+  fn should_suspend_and_resume() {
+    let cache = cache(10, Duration::from_secs(0));
+    set(&cache, "a");
+    let first = cache.suspend();
+    let second = cache.suspend();
+    assert!(!cache.is_active());
+    assert_eq!(cache.stats().entries, 0);
+
+    set(&cache, "b");
+    assert_eq!(cache.stats().entries, 0);
+    cache.resume(first);
+    assert!(!cache.is_active());
+    cache.resume(second);
+    assert!(cache.is_active());
+  }
+
+  #[test]
+  fn should_expire_entries() {
+    let cache = cache(10, Duration::from_millis(10));
+    set(&cache, "a");
+    std::thread::sleep(Duration::from_millis(20));
+
+    assert!(cache.get(&b"a".to_vec()).is_none());
+    assert_eq!(cache.stats().evictions, 1);
+  }
+}
@@ -1,4 +1,5 @@
 use crate::{
+  clients::DedicatedClients,
   error::*,
   interfaces,
   modules::backchannel::Backchannel,
@@ -32,16 +33,19 @@ use tokio::{
 };
 
 #[cfg(feature = "metrics")]
-use crate::modules::metrics::MovingStats;
+use crate::modules::metrics::{DecayingAverage, MovingStats};
+#[cfg(feature = "subscriber-client")]
+use crate::modules::subscriptions::Subscriptions;
 use bytes_utils::Str;
-#[cfg(feature = "replicas")]
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+#[cfg(feature = "credential-provider")]
+use tokio::task::JoinHandle;
 
 pub type CommandSender = UnboundedSender<RouterCommand>;
 pub type CommandReceiver = UnboundedReceiver<RouterCommand>;
 
 #[cfg(feature = "client-tracking")]
-use crate::types::Invalidation;
+use crate::{commands::tracking::RedirectTracking, modules::cache::ClientCache, types::Invalidation};
 
 pub struct Notifications {
   /// The client ID.
@@ -67,22 +71,27 @@ pub struct Notifications {
   pub invalidations:  ArcSwap<BroadcastSender<Invalidation>>,
   /// A broadcast channel for notifying callers when servers go unresponsive.
   pub unresponsive:   ArcSwap<BroadcastSender<Server>>,
+  /// Per-channel message buffers for the `subscribe_stream` interface.
+  #[cfg(feature = "subscriber-client")]
+  pub subscriptions:  Arc<Subscriptions>,
 }
 
 impl Notifications {
   pub fn new(id: &Str, capacity: usize) -> Self {
     Notifications {
-      id:                                                id.clone(),
-      close:                                             broadcast::channel(capacity).0,
-      errors:                                            ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
-      pubsub:                                            ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
-      keyspace:                                          ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
-      reconnect:                                         ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
-      cluster_change:                                    ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
-      connect:                                           ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
+      id:                                                  id.clone(),
+      close:                                               broadcast::channel(capacity).0,
+      errors:                                              ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
+      pubsub:                                              ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
+      keyspace:                                            ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
+      reconnect:                                           ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
+      cluster_change:                                      ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
+      connect:                                             ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
       #[cfg(feature = "client-tracking")]
-      invalidations:                                     ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
-      unresponsive:                                      ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
+      invalidations:                                       ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
+      unresponsive:                                        ArcSwap::new(Arc::new(broadcast::channel(capacity).0)),
+      #[cfg(feature = "subscriber-client")]
+      subscriptions:                                       Arc::new(Subscriptions::default()),
     }
   }
 
@@ -97,6 +106,8 @@ impl Notifications {
     #[cfg(feature = "client-tracking")]
     utils::swap_new_broadcast_channel(&self.invalidations, capacity);
     utils::swap_new_broadcast_channel(&self.unresponsive, capacity);
+    #[cfg(feature = "subscriber-client")]
+    self.subscriptions.close_all();
   }
 
   pub fn broadcast_error(&self, error: RedisError) {
@@ -209,9 +220,13 @@ impl ClientCounters {
 
 /// Cached state related to the server(s).
 pub struct ServerState {
-  pub kind:     ServerKind,
+  pub kind:             ServerKind,
   #[cfg(feature = "replicas")]
-  pub replicas: HashMap<Server, Server>,
+  pub replicas:         HashMap<Server, Server>,
+  /// Cached `COMMAND INFO` output used to find keys in custom commands, keyed by the lowercase command name.
+  pub command_info:     HashMap<Str, CommandInfo>,
+  /// The server version associated with the `command_info` cache.
+  pub command_info_ver: Option<Version>,
 }
 
 impl ServerState {
@@ -220,9 +235,26 @@ impl ServerState {
       kind:                                  ServerKind::new(config),
       #[cfg(feature = "replicas")]
       replicas:                              HashMap::new(),
+      command_info:                          HashMap::new(),
+      command_info_ver:                      None,
     }
   }
 
+  /// Read the cached command info, clearing the cache if the server version changed.
+  pub fn cached_command_info(&mut self, name: &str) -> Option<CommandInfo> {
+    let version = self.kind.server_version();
+    if self.command_info_ver != version {
+      self.command_info.clear();
+      self.command_info_ver = version;
+    }
+
+    self.command_info.get(name).cloned()
+  }
+
+  pub fn cache_command_info(&mut self, name: Str, info: CommandInfo) {
+    self.command_info.insert(name, info);
+  }
+
   #[cfg(feature = "replicas")]
   pub fn update_replicas(&mut self, map: HashMap<Server, Server>) {
     self.replicas = map;
@@ -376,39 +408,58 @@ fn create_resolver(id: &Str) -> Arc<dyn Resolve> {
 
 pub struct RedisClientInner {
   /// An internal lock used to sync certain select operations that should not run concurrently across tasks.
-  pub _lock:         Mutex<()>,
+  pub _lock:              Mutex<()>,
   /// The client ID used for logging and the default `CLIENT SETNAME` value.
-  pub id:            Str,
+  pub id:                 Str,
   /// Whether the client uses RESP3.
-  pub resp3:         Arc<AtomicBool>,
+  pub resp3:              Arc<AtomicBool>,
   /// The state of the underlying connection.
-  pub state:         RwLock<ClientState>,
+  pub state:              RwLock<ClientState>,
   /// Client configuration options.
-  pub config:        Arc<RedisConfig>,
+  pub config:             Arc<RedisConfig>,
   /// Connection configuration options.
-  pub connection:    Arc<ConnectionConfig>,
+  pub connection:         Arc<ConnectionConfig>,
   /// Performance config options for the client.
-  pub performance:   ArcSwap<PerformanceConfig>,
+  pub performance:        ArcSwap<PerformanceConfig>,
   /// An optional reconnect policy.
-  pub policy:        RwLock<Option<ReconnectPolicy>>,
+  pub policy:             RwLock<Option<ReconnectPolicy>>,
   /// Notification channels for the event interfaces.
-  pub notifications: Arc<Notifications>,
+  pub notifications:      Arc<Notifications>,
   /// An mpsc sender for commands to the router.
-  pub command_tx:    ArcSwap<CommandSender>,
+  pub command_tx:         ArcSwap<CommandSender>,
   /// Temporary storage for the receiver half of the router command channel.
-  pub command_rx:    RwLock<Option<CommandReceiver>>,
+  pub command_rx:         RwLock<Option<CommandReceiver>>,
   /// Shared counters.
-  pub counters:      ClientCounters,
+  pub counters:           ClientCounters,
   /// The DNS resolver to use when establishing new connections.
-  pub resolver:      AsyncRwLock<Arc<dyn Resolve>>,
+  pub resolver:           AsyncRwLock<Arc<dyn Resolve>>,
   /// A backchannel that can be used to control the router connections even while the connections are blocked.
-  pub backchannel:   Arc<AsyncRwLock<Backchannel>>,
+  pub backchannel:        Arc<AsyncRwLock<Backchannel>>,
   /// Server state cache for various deployment types.
-  pub server_state:  RwLock<ServerState>,
+  pub server_state:       RwLock<ServerState>,
+  /// The dedicated connections checked out from, or idle in, the client.
+  pub(crate) dedicated:   Arc<DedicatedClients>,
+  /// Channel patterns used by the keyspace notifications interface.
+  pub keyspace_channels:  RwLock<BTreeSet<Str>>,
+  /// The local cache used with client side caching.
+  #[cfg(feature = "client-tracking")]
+  pub cache:              ClientCache,
+  /// The companion connection used to receive invalidation messages when client tracking is used with RESP2.
+  #[cfg(feature = "client-tracking")]
+  pub tracking_redirect:  Mutex<Option<RedirectTracking>>,
+  /// The task that re-authenticates connections on the credential provider's refresh interval.
+  #[cfg(feature = "credential-provider")]
+  pub credential_refresh: Mutex<Option<JoinHandle<()>>>,
 
   /// Command latency metrics.
   #[cfg(feature = "metrics")]
   pub latency_stats:         RwLock<MovingStats>,
+  /// A decaying average of command latency used to route commands in a pool.
+  #[cfg(feature = "metrics")]
+  pub latency_average:       RwLock<DecayingAverage>,
+  /// Whether to sample `latency_average`, set when a pool routes commands by latency.
+  #[cfg(feature = "metrics")]
+  pub sample_latency:        Arc<AtomicBool>,
   /// Network latency metrics.
   #[cfg(feature = "metrics")]
   pub network_latency_stats: RwLock<MovingStats>,
@@ -442,6 +493,7 @@ impl RedisClientInner {
     } else {
       Arc::new(AtomicBool::new(false))
     };
+    let dedicated = Arc::new(DedicatedClients::new(&connection));
     let connection = Arc::new(connection);
     let command_tx = ArcSwap::new(Arc::new(command_tx));
 
@@ -450,6 +502,10 @@ impl RedisClientInner {
       #[cfg(feature = "metrics")]
       latency_stats: RwLock::new(MovingStats::default()),
       #[cfg(feature = "metrics")]
+      latency_average: RwLock::new(DecayingAverage::default()),
+      #[cfg(feature = "metrics")]
+      sample_latency: Arc::new(AtomicBool::new(false)),
+      #[cfg(feature = "metrics")]
       network_latency_stats: RwLock::new(MovingStats::default()),
       #[cfg(feature = "metrics")]
       req_size_stats: Arc::new(RwLock::new(MovingStats::default())),
@@ -459,6 +515,14 @@ impl RedisClientInner {
       backchannel,
       command_rx,
       server_state,
+      dedicated,
+      keyspace_channels: RwLock::new(BTreeSet::new()),
+      #[cfg(feature = "client-tracking")]
+      cache: ClientCache::default(),
+      #[cfg(feature = "client-tracking")]
+      tracking_redirect: Mutex::new(None),
+      #[cfg(feature = "credential-provider")]
+      credential_refresh: Mutex::new(None),
       command_tx,
       state,
       counters,
@@ -499,8 +563,10 @@ impl RedisClientInner {
   }
 
   pub fn reset_server_state(&self) {
+    let mut guard = self.server_state.write();
     #[cfg(feature = "replicas")]
-    self.server_state.write().replicas.clear()
+    guard.replicas.clear();
+    guard.command_info.clear();
   }
 
   pub fn shared_resp3(&self) -> Arc<AtomicBool> {
@@ -710,6 +776,35 @@ impl RedisClientInner {
     self.backchannel.write().await.transport = Some(transport);
   }
 
+  /// Read the username and password to use when authenticating, preferring the credential provider if configured.
+  #[cfg(feature = "credential-provider")]
+  pub async fn read_credentials(
+    &self,
+    server: Option<&Server>,
+  ) -> Result<(Option<String>, Option<String>), RedisError> {
+    if let Some(ref provider) = self.config.credential_provider {
+      provider.fetch(server).await
+    } else {
+      Ok((self.config.username.clone(), self.config.password.clone()))
+    }
+  }
+
+  /// Read the username and password to use when authenticating.
+  #[cfg(not(feature = "credential-provider"))]
+  pub async fn read_credentials(&self, _: Option<&Server>) -> Result<(Option<String>, Option<String>), RedisError> {
+    Ok((self.config.username.clone(), self.config.password.clone()))
+  }
+
+  /// Whether the client should authenticate when creating new connections.
+  pub fn has_credentials(&self) -> bool {
+    #[cfg(feature = "credential-provider")]
+    if self.config.credential_provider.is_some() {
+      return true;
+    }
+
+    self.config.password.is_some()
+  }
+
   pub async fn wait_with_interrupt(&self, duration: Duration) -> Result<(), RedisError> {
     let mut rx = self.notifications.close.subscribe();
     debug!("{}: Sleeping for {} ms", self.id, duration.as_millis());
@@ -1,7 +1,12 @@
 #![allow(unused_variables)]
 #![allow(dead_code)]
 
-use std::cmp;
+use std::{cmp, time::Instant};
+
+/// The time, in milliseconds, over which a [DecayingAverage] decays by a factor of `e` without new samples.
+const DECAY_TIME_MS: f64 = 1_000.0;
+/// The weight of each new sample in a [DecayingAverage].
+const SAMPLE_WEIGHT: f64 = 0.2;
 
 /// Stats describing a distribution of samples.
 ///
@@ -15,6 +20,45 @@ pub struct Stats {
   pub sum:     i64,
 }
 
+impl Stats {
+  /// Combine stats from several distributions into one.
+  pub(crate) fn merge(stats: Vec<Stats>) -> Stats {
+    let stats: Vec<Stats> = stats.into_iter().filter(|s| s.samples > 0).collect();
+    let samples: u64 = stats.iter().map(|s| s.samples).sum();
+    if samples == 0 {
+      return Stats {
+        min:     0,
+        max:     0,
+        avg:     0.0,
+        stddev:  0.0,
+        samples: 0,
+        sum:     0,
+      };
+    }
+
+    let sum: i64 = stats.iter().map(|s| s.sum).sum();
+    let avg = stats.iter().map(|s| s.avg * s.samples as f64).sum::<f64>() / samples as f64;
+    let variance = if samples > 1 {
+      let s: f64 = stats
+        .iter()
+        .map(|s| (s.samples - 1) as f64 * s.stddev.powi(2) + s.samples as f64 * (s.avg - avg).powi(2))
+        .sum();
+      s / (samples - 1) as f64
+    } else {
+      0.0
+    };
+
+    Stats {
+      min: stats.iter().map(|s| s.min).min().unwrap_or(0),
+      max: stats.iter().map(|s| s.max).max().unwrap_or(0),
+      stddev: variance.sqrt(),
+      avg,
+      samples,
+      sum,
+    }
+  }
+}
+
 /// Struct for tracking moving stats about network latency or request/response sizes.
 ///
 /// Time units are in milliseconds, data size units are in bytes.
@@ -108,3 +152,90 @@ impl<'a> From<&'a MovingStats> for Stats {
     }
   }
 }
+
+/// An exponentially weighted moving average that decays toward zero while no samples are recorded.
+///
+/// Pools use this to route commands by latency, so that a client that looks slow is eventually selected and sampled
+/// again.
+pub struct DecayingAverage {
+  value:   Option<f64>,
+  updated: Instant,
+}
+
+impl Default for DecayingAverage {
+  fn default() -> Self {
+    DecayingAverage {
+      value:   None,
+      updated: Instant::now(),
+    }
+  }
+}
+
+impl DecayingAverage {
+  pub fn sample(&mut self, value: f64, now: Instant) {
+    self.value = Some(match self.read(now) {
+      Some(avg) => avg + SAMPLE_WEIGHT * (value - avg),
+      None => value,
+    });
+    self.updated = now;
+  }
+
+  /// Read the average, or `None` if no samples have been recorded.
+  pub fn read(&self, now: Instant) -> Option<f64> {
+    let elapsed = now.saturating_duration_since(self.updated).as_secs_f64() * 1000.0;
+    self.value.map(|value| value * (-elapsed / DECAY_TIME_MS).exp())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_merge_stats() {
+    let (mut lhs, mut rhs, mut all) = (MovingStats::default(), MovingStats::default(), MovingStats::default());
+    for value in [1, 5, 9] {
+      lhs.sample(value);
+      all.sample(value);
+    }
+    for value in [2, 20] {
+      rhs.sample(value);
+      all.sample(value);
+    }
+
+    let merged = Stats::merge(vec![
+      lhs.read_metrics(),
+      MovingStats::default().read_metrics(),
+      rhs.read_metrics(),
+    ]);
+    let expected = all.read_metrics();
+    assert_eq!(merged.min, expected.min);
+    assert_eq!(merged.max, expected.max);
+    assert_eq!(merged.sum, expected.sum);
+    assert_eq!(merged.samples, expected.samples);
+    assert!((merged.avg - expected.avg).abs() < 1e-9);
+    assert!((merged.stddev - expected.stddev).abs() < 1e-9);
+  }
+
+  #[test]
+  fn should_weight_recent_samples() {
+    let now = Instant::now();
+    let mut avg = DecayingAverage::default();
+    assert!(avg.read(now).is_none());
+
+    avg.sample(10.0, now);
+    assert!((avg.read(now).unwrap() - 10.0).abs() < 1e-9);
+    avg.sample(20.0, now);
+    assert!((avg.read(now).unwrap() - 12.0).abs() < 1e-9);
+  }
+
+  #[test]
+  fn should_decay_without_samples() {
+    let now = Instant::now();
+    let mut avg = DecayingAverage::default();
+    avg.sample(100.0, now);
+
+    let later = now + std::time::Duration::from_secs(5);
+    assert!(avg.read(later).unwrap() < 1.0);
+  }
+}
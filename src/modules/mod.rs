@@ -1,8 +1,12 @@
 pub mod backchannel;
+#[cfg(feature = "client-tracking")]
+pub mod cache;
 /// Utility functions for reading or changing global config values.
 pub mod inner;
 pub mod metrics;
 pub mod response;
+#[cfg(feature = "subscriber-client")]
+pub mod subscriptions;
 
 #[cfg(feature = "mocks")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mocks")))]
@@ -1,6 +1,26 @@
 use crate::{
   error::{RedisError, RedisErrorKind},
-  types::{ClusterInfo, DatabaseMemoryStats, GeoPosition, MemoryStats, RedisKey, RedisValue, SlowlogEntry, QUEUED},
+  types::{
+    ClusterInfo,
+    CommandInfo,
+    DatabaseMemoryStats,
+    FieldExpireResult,
+    FieldPersistResult,
+    GeoPosition,
+    KeySpec,
+    KeyType,
+    LatencyEvent,
+    LatencySample,
+    LcsMatch,
+    LcsResult,
+    MemoryStats,
+    ModuleInfo,
+    ObjectEncoding,
+    RedisKey,
+    RedisValue,
+    SlowlogEntry,
+    QUEUED,
+  },
 };
 use bytes::Bytes;
 use bytes_utils::Str;
@@ -12,6 +32,8 @@ use std::{
 #[allow(unused_imports)]
 use std::any::type_name;
 
+#[cfg(feature = "redis-search")]
+use crate::types::{AggregateResults, KnnResults, SearchResults};
 #[cfg(feature = "serde-json")]
 use serde_json::{Map, Value};
 
@@ -601,12 +623,82 @@ impl FromRedis for GeoPosition {
   }
 }
 
+impl FromRedis for FieldExpireResult {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    check_single_bulk_reply!(value);
+    FieldExpireResult::try_from(value)
+  }
+}
+
+impl FromRedis for FieldPersistResult {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    check_single_bulk_reply!(value);
+    FieldPersistResult::try_from(value)
+  }
+}
+
+impl FromRedis for KeyType {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    check_single_bulk_reply!(value);
+    KeyType::try_from(value)
+  }
+}
+
+impl FromRedis for ObjectEncoding {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    check_single_bulk_reply!(value);
+    ObjectEncoding::try_from(value)
+  }
+}
+
+impl FromRedis for LcsMatch {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    LcsMatch::try_from(value)
+  }
+}
+
+impl FromRedis for LcsResult {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    LcsResult::try_from(value)
+  }
+}
+
 impl FromRedis for SlowlogEntry {
   fn from_value(value: RedisValue) -> Result<Self, RedisError> {
     SlowlogEntry::try_from(value)
   }
 }
 
+impl FromRedis for CommandInfo {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    CommandInfo::try_from(value)
+  }
+}
+
+impl FromRedis for KeySpec {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    KeySpec::try_from(value)
+  }
+}
+
+impl FromRedis for LatencyEvent {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    LatencyEvent::try_from(value)
+  }
+}
+
+impl FromRedis for LatencySample {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    LatencySample::try_from(value)
+  }
+}
+
+impl FromRedis for ModuleInfo {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    ModuleInfo::try_from(value)
+  }
+}
+
 impl FromRedis for ClusterInfo {
   fn from_value(value: RedisValue) -> Result<Self, RedisError> {
     ClusterInfo::try_from(value)
@@ -625,6 +717,30 @@ impl FromRedis for DatabaseMemoryStats {
   }
 }
 
+#[cfg(feature = "redis-search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+impl FromRedis for SearchResults {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    SearchResults::try_from(value)
+  }
+}
+
+#[cfg(feature = "redis-search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+impl FromRedis for AggregateResults {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    AggregateResults::try_from(value)
+  }
+}
+
+#[cfg(feature = "redis-search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+impl FromRedis for KnnResults {
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    KnnResults::try_from(value)
+  }
+}
+
 impl FromRedis for RedisKey {
   fn from_value(value: RedisValue) -> Result<Self, RedisError> {
     let key = match value {
@@ -0,0 +1,175 @@
+use crate::{
+  error::{RedisError, RedisErrorKind},
+  protocol::types::{Message, MessageKind},
+  types::LagPolicy,
+};
+use bytes_utils::Str;
+use parking_lot::Mutex;
+use std::{
+  collections::{HashMap, VecDeque},
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+};
+use tokio::sync::Notify;
+
+/// The kind of subscription and the channel, pattern, or shard channel name.
+pub type StreamKey = (MessageKind, Str);
+
+/// A bounded message buffer for one subscription stream.
+pub struct StreamBuffer {
+  queue:    Mutex<VecDeque<Message>>,
+  capacity: usize,
+  policy:   LagPolicy,
+  lagged:   AtomicBool,
+  closed:   AtomicBool,
+  readable: Notify,
+  writable: Notify,
+}
+
+impl StreamBuffer {
+  pub fn new(capacity: usize, policy: LagPolicy) -> Self {
+    StreamBuffer {
+      queue: Mutex::new(VecDeque::with_capacity(capacity)),
+      capacity: capacity.max(1),
+      lagged: AtomicBool::new(false),
+      closed: AtomicBool::new(false),
+      readable: Notify::new(),
+      writable: Notify::new(),
+      policy,
+    }
+  }
+
+  /// Add a message to the buffer according to the lag policy.
+  pub async fn push(&self, message: Message) {
+    loop {
+      if self.closed.load(Ordering::Acquire) {
+        return;
+      }
+
+      {
+        let mut queue = self.queue.lock();
+        if queue.len() < self.capacity {
+          queue.push_back(message);
+          self.readable.notify_one();
+          return;
+        }
+
+        match self.policy {
+          LagPolicy::DropOldest => {
+            queue.pop_front();
+            queue.push_back(message);
+            self.readable.notify_one();
+            return;
+          },
+          LagPolicy::Error => {
+            self.lagged.store(true, Ordering::Release);
+            self.readable.notify_one();
+            return;
+          },
+          LagPolicy::Block => {},
+        }
+      }
+
+      self.writable.notified().await;
+    }
+  }
+
+  /// Read the next message, or `None` if the buffer is closed and empty.
+  pub async fn recv(&self) -> Option<Result<Message, RedisError>> {
+    loop {
+      if self.lagged.swap(false, Ordering::AcqRel) {
+        return Some(Err(RedisError::new(
+          RedisErrorKind::Backpressure,
+          "Subscription stream buffer is full.",
+        )));
+      }
+
+      if let Some(message) = self.queue.lock().pop_front() {
+        self.writable.notify_one();
+        return Some(Ok(message));
+      }
+      if self.closed.load(Ordering::Acquire) {
+        return None;
+      }
+
+      self.readable.notified().await;
+    }
+  }
+
+  /// Close the buffer, ending the stream after any buffered messages are read.
+  pub fn close(&self) {
+    self.closed.store(true, Ordering::Release);
+    self.readable.notify_one();
+    self.writable.notify_waiters();
+  }
+}
+
+/// The set of subscription streams on a client.
+#[derive(Default)]
+pub struct Subscriptions {
+  streams: Mutex<HashMap<StreamKey, Vec<Arc<StreamBuffer>>>>,
+}
+
+impl Subscriptions {
+  pub fn add(&self, key: StreamKey, buffer: Arc<StreamBuffer>) {
+    self.streams.lock().entry(key).or_default().push(buffer);
+  }
+
+  /// Remove the buffer, returning whether it was the last buffer for the key.
+  pub fn remove(&self, key: &StreamKey, buffer: &Arc<StreamBuffer>) -> bool {
+    let mut guard = self.streams.lock();
+    let is_empty = match guard.get_mut(key) {
+      Some(buffers) => {
+        buffers.retain(|b| !Arc::ptr_eq(b, buffer));
+        buffers.is_empty()
+      },
+      None => return false,
+    };
+
+    if is_empty {
+      guard.remove(key);
+    }
+    is_empty
+  }
+
+  /// Whether any streams are subscribed to the key.
+  pub fn contains(&self, key: &StreamKey) -> bool {
+    self
+      .streams
+      .lock()
+      .get(key)
+      .map(|buffers| !buffers.is_empty())
+      .unwrap_or(false)
+  }
+
+  /// Copy the message to each stream subscribed to its channel, or to `pattern` if the message came from a
+  /// `psubscribe` command.
+  pub async fn dispatch(&self, message: &Message, pattern: Option<Str>) {
+    let name = match message.kind {
+      MessageKind::PMessage => match pattern {
+        Some(pattern) => pattern,
+        None => return,
+      },
+      _ => message.channel.clone(),
+    };
+
+    let buffers = match self.streams.lock().get(&(message.kind.clone(), name)) {
+      Some(buffers) => buffers.clone(),
+      None => return,
+    };
+    for buffer in buffers.into_iter() {
+      buffer.push(message.clone()).await;
+    }
+  }
+
+  /// Close and remove all streams.
+  pub fn close_all(&self) {
+    for (_, buffers) in self.streams.lock().drain() {
+      for buffer in buffers.into_iter() {
+        buffer.close();
+      }
+    }
+  }
+}
@@ -138,6 +138,7 @@ pub enum RedisCommandKind {
   BgSave,
   BitCount,
   BitField,
+  BitFieldRO,
   BitOp,
   BitPos,
   BlPop,
@@ -183,12 +184,19 @@ pub enum RedisCommandKind {
   ClusterSetSlot,
   ClusterReplicas,
   ClusterSlots,
+  Command,
+  CommandCount,
+  CommandDocs,
+  CommandGetKeys,
+  CommandInfo,
   ConfigGet,
   ConfigRewrite,
   ConfigSet,
   ConfigResetStat,
   Copy,
   DBSize,
+  DebugObject,
+  DebugSleep,
   Decr,
   DecrBy,
   Del,
@@ -201,6 +209,7 @@ pub enum RedisCommandKind {
   Exists,
   Expire,
   ExpireAt,
+  ExpireTime,
   Failover,
   FlushAll,
   FlushDB,
@@ -215,6 +224,7 @@ pub enum RedisCommandKind {
   Get,
   GetBit,
   GetDel,
+  GetEx,
   GetRange,
   GetSet,
   HDel,
@@ -231,6 +241,18 @@ pub enum RedisCommandKind {
   HSetNx,
   HStrLen,
   HVals,
+  HExpire,
+  HPExpire,
+  HExpireAt,
+  HPExpireAt,
+  HTtl,
+  HPTtl,
+  HExpireTime,
+  HPExpireTime,
+  HPersist,
+  HGetDel,
+  HGetEx,
+  HSetEx,
   HRandField,
   Incr,
   IncrBy,
@@ -252,6 +274,11 @@ pub enum RedisCommandKind {
   LSet,
   LTrim,
   Lcs,
+  LatencyDoctor,
+  LatencyHistogram,
+  LatencyHistory,
+  LatencyLatest,
+  LatencyReset,
   MemoryDoctor,
   MemoryHelp,
   MemoryMallocStats,
@@ -260,6 +287,10 @@ pub enum RedisCommandKind {
   MemoryUsage,
   Mget,
   Migrate,
+  ModuleList,
+  ModuleLoad,
+  ModuleLoadEx,
+  ModuleUnload,
   Monitor,
   Move,
   Mset,
@@ -269,6 +300,7 @@ pub enum RedisCommandKind {
   Persist,
   Pexpire,
   Pexpireat,
+  PexpireTime,
   Pfadd,
   Pfcount,
   Pfmerge,
@@ -309,10 +341,12 @@ pub enum RedisCommandKind {
   Smismember,
   Smove,
   Sort,
+  SortRo,
   Spop,
   Srandmember,
   Srem,
   Strlen,
+  Substr,
   Sunion,
   Sunionstore,
   Swapdb,
@@ -324,6 +358,7 @@ pub enum RedisCommandKind {
   Unlink,
   Unwatch,
   Wait,
+  WaitAof,
   Watch,
   // Streams
   XinfoConsumers,
@@ -454,6 +489,72 @@ pub enum RedisCommandKind {
   TsQueryIndex,
   TsRange,
   TsRevRange,
+  // RediSearch
+  FtList,
+  FtAggregate,
+  FtSearch,
+  FtCreate,
+  FtAlter,
+  FtAliasAdd,
+  FtAliasDel,
+  FtAliasUpdate,
+  FtCursorDel,
+  FtCursorRead,
+  FtDropIndex,
+  FtExplain,
+  FtInfo,
+  FtSpellCheck,
+  FtSynDump,
+  FtSynUpdate,
+  // RedisBloom
+  BfAdd,
+  BfCard,
+  BfExists,
+  BfInfo,
+  BfInsert,
+  BfLoadChunk,
+  BfMAdd,
+  BfMExists,
+  BfReserve,
+  BfScanDump,
+  CfAdd,
+  CfAddNx,
+  CfCount,
+  CfDel,
+  CfExists,
+  CfInfo,
+  CfInsert,
+  CfInsertNx,
+  CfLoadChunk,
+  CfMExists,
+  CfReserve,
+  CfScanDump,
+  CmsIncrBy,
+  CmsInfo,
+  CmsInitByDim,
+  CmsInitByProb,
+  CmsMerge,
+  CmsQuery,
+  TopKAdd,
+  TopKIncrBy,
+  TopKInfo,
+  TopKList,
+  TopKQuery,
+  TopKReserve,
+  TDigestAdd,
+  TDigestByRank,
+  TDigestByRevRank,
+  TDigestCdf,
+  TDigestCreate,
+  TDigestInfo,
+  TDigestMax,
+  TDigestMerge,
+  TDigestMin,
+  TDigestQuantile,
+  TDigestRank,
+  TDigestReset,
+  TDigestRevRank,
+  TDigestTrimmedMean,
   // Commands with custom state or commands that don't map directly to the server's command interface.
   _Hello(RespVersion),
   _AuthAllCluster,
@@ -574,6 +675,7 @@ impl RedisCommandKind {
       RedisCommandKind::BgSave => "BGSAVE",
       RedisCommandKind::BitCount => "BITCOUNT",
       RedisCommandKind::BitField => "BITFIELD",
+      RedisCommandKind::BitFieldRO => "BITFIELD_RO",
       RedisCommandKind::BitOp => "BITOP",
       RedisCommandKind::BitPos => "BITPOS",
       RedisCommandKind::BlPop => "BLPOP",
@@ -619,12 +721,19 @@ impl RedisCommandKind {
       RedisCommandKind::ClusterBumpEpoch => "CLUSTER BUMPEPOCH",
       RedisCommandKind::ClusterFlushSlots => "CLUSTER FLUSHSLOTS",
       RedisCommandKind::ClusterMyID => "CLUSTER MYID",
+      RedisCommandKind::Command => "COMMAND",
+      RedisCommandKind::CommandCount => "COMMAND COUNT",
+      RedisCommandKind::CommandDocs => "COMMAND DOCS",
+      RedisCommandKind::CommandGetKeys => "COMMAND GETKEYS",
+      RedisCommandKind::CommandInfo => "COMMAND INFO",
       RedisCommandKind::ConfigGet => "CONFIG GET",
       RedisCommandKind::ConfigRewrite => "CONFIG REWRITE",
       RedisCommandKind::ConfigSet => "CONFIG SET",
       RedisCommandKind::ConfigResetStat => "CONFIG RESETSTAT",
       RedisCommandKind::Copy => "COPY",
       RedisCommandKind::DBSize => "DBSIZE",
+      RedisCommandKind::DebugObject => "DEBUG OBJECT",
+      RedisCommandKind::DebugSleep => "DEBUG SLEEP",
       RedisCommandKind::Decr => "DECR",
       RedisCommandKind::DecrBy => "DECRBY",
       RedisCommandKind::Del => "DEL",
@@ -637,6 +746,7 @@ impl RedisCommandKind {
       RedisCommandKind::Exists => "EXISTS",
       RedisCommandKind::Expire => "EXPIRE",
       RedisCommandKind::ExpireAt => "EXPIREAT",
+      RedisCommandKind::ExpireTime => "EXPIRETIME",
       RedisCommandKind::Failover => "FAILOVER",
       RedisCommandKind::FlushAll => "FLUSHALL",
       RedisCommandKind::FlushDB => "FLUSHDB",
@@ -650,6 +760,7 @@ impl RedisCommandKind {
       RedisCommandKind::GeoSearchStore => "GEOSEARCHSTORE",
       RedisCommandKind::Get => "GET",
       RedisCommandKind::GetDel => "GETDEL",
+      RedisCommandKind::GetEx => "GETEX",
       RedisCommandKind::GetBit => "GETBIT",
       RedisCommandKind::GetRange => "GETRANGE",
       RedisCommandKind::GetSet => "GETSET",
@@ -669,6 +780,18 @@ impl RedisCommandKind {
       RedisCommandKind::HStrLen => "HSTRLEN",
       RedisCommandKind::HRandField => "HRANDFIELD",
       RedisCommandKind::HVals => "HVALS",
+      RedisCommandKind::HExpire => "HEXPIRE",
+      RedisCommandKind::HPExpire => "HPEXPIRE",
+      RedisCommandKind::HExpireAt => "HEXPIREAT",
+      RedisCommandKind::HPExpireAt => "HPEXPIREAT",
+      RedisCommandKind::HTtl => "HTTL",
+      RedisCommandKind::HPTtl => "HPTTL",
+      RedisCommandKind::HExpireTime => "HEXPIRETIME",
+      RedisCommandKind::HPExpireTime => "HPEXPIRETIME",
+      RedisCommandKind::HPersist => "HPERSIST",
+      RedisCommandKind::HGetDel => "HGETDEL",
+      RedisCommandKind::HGetEx => "HGETEX",
+      RedisCommandKind::HSetEx => "HSETEX",
       RedisCommandKind::Incr => "INCR",
       RedisCommandKind::IncrBy => "INCRBY",
       RedisCommandKind::IncrByFloat => "INCRBYFLOAT",
@@ -689,6 +812,11 @@ impl RedisCommandKind {
       RedisCommandKind::LSet => "LSET",
       RedisCommandKind::LTrim => "LTRIM",
       RedisCommandKind::Lcs => "LCS",
+      RedisCommandKind::LatencyDoctor => "LATENCY DOCTOR",
+      RedisCommandKind::LatencyHistogram => "LATENCY HISTOGRAM",
+      RedisCommandKind::LatencyHistory => "LATENCY HISTORY",
+      RedisCommandKind::LatencyLatest => "LATENCY LATEST",
+      RedisCommandKind::LatencyReset => "LATENCY RESET",
       RedisCommandKind::MemoryDoctor => "MEMORY DOCTOR",
       RedisCommandKind::MemoryHelp => "MEMORY HELP",
       RedisCommandKind::MemoryMallocStats => "MEMORY MALLOC-STATS",
@@ -697,6 +825,10 @@ impl RedisCommandKind {
       RedisCommandKind::MemoryUsage => "MEMORY USAGE",
       RedisCommandKind::Mget => "MGET",
       RedisCommandKind::Migrate => "MIGRATE",
+      RedisCommandKind::ModuleList => "MODULE LIST",
+      RedisCommandKind::ModuleLoad => "MODULE LOAD",
+      RedisCommandKind::ModuleLoadEx => "MODULE LOADEX",
+      RedisCommandKind::ModuleUnload => "MODULE UNLOAD",
       RedisCommandKind::Monitor => "MONITOR",
       RedisCommandKind::Move => "MOVE",
       RedisCommandKind::Mset => "MSET",
@@ -706,6 +838,7 @@ impl RedisCommandKind {
       RedisCommandKind::Persist => "PERSIST",
       RedisCommandKind::Pexpire => "PEXPIRE",
       RedisCommandKind::Pexpireat => "PEXPIREAT",
+      RedisCommandKind::PexpireTime => "PEXPIRETIME",
       RedisCommandKind::Pfadd => "PFADD",
       RedisCommandKind::Pfcount => "PFCOUNT",
       RedisCommandKind::Pfmerge => "PFMERGE",
@@ -749,10 +882,12 @@ impl RedisCommandKind {
       RedisCommandKind::Smismember => "SMISMEMBER",
       RedisCommandKind::Smove => "SMOVE",
       RedisCommandKind::Sort => "SORT",
+      RedisCommandKind::SortRo => "SORT_RO",
       RedisCommandKind::Spop => "SPOP",
       RedisCommandKind::Srandmember => "SRANDMEMBER",
       RedisCommandKind::Srem => "SREM",
       RedisCommandKind::Strlen => "STRLEN",
+      RedisCommandKind::Substr => "SUBSTR",
       RedisCommandKind::Subscribe => "SUBSCRIBE",
       RedisCommandKind::Sunion => "SUNION",
       RedisCommandKind::Sunionstore => "SUNIONSTORE",
@@ -766,6 +901,7 @@ impl RedisCommandKind {
       RedisCommandKind::Unlink => "UNLINK",
       RedisCommandKind::Unwatch => "UNWATCH",
       RedisCommandKind::Wait => "WAIT",
+      RedisCommandKind::WaitAof => "WAITAOF",
       RedisCommandKind::Watch => "WATCH",
       RedisCommandKind::XinfoConsumers => "XINFO CONSUMERS",
       RedisCommandKind::XinfoGroups => "XINFO GROUPS",
@@ -894,6 +1030,70 @@ impl RedisCommandKind {
       RedisCommandKind::TsQueryIndex => "TS.QUERYINDEX",
       RedisCommandKind::TsRange => "TS.RANGE",
       RedisCommandKind::TsRevRange => "TS.REVRANGE",
+      RedisCommandKind::FtList => "FT._LIST",
+      RedisCommandKind::FtAggregate => "FT.AGGREGATE",
+      RedisCommandKind::FtSearch => "FT.SEARCH",
+      RedisCommandKind::FtCreate => "FT.CREATE",
+      RedisCommandKind::FtAlter => "FT.ALTER",
+      RedisCommandKind::FtAliasAdd => "FT.ALIASADD",
+      RedisCommandKind::FtAliasDel => "FT.ALIASDEL",
+      RedisCommandKind::FtAliasUpdate => "FT.ALIASUPDATE",
+      RedisCommandKind::FtCursorDel => "FT.CURSOR DEL",
+      RedisCommandKind::FtCursorRead => "FT.CURSOR READ",
+      RedisCommandKind::FtDropIndex => "FT.DROPINDEX",
+      RedisCommandKind::FtExplain => "FT.EXPLAIN",
+      RedisCommandKind::FtInfo => "FT.INFO",
+      RedisCommandKind::FtSpellCheck => "FT.SPELLCHECK",
+      RedisCommandKind::FtSynDump => "FT.SYNDUMP",
+      RedisCommandKind::FtSynUpdate => "FT.SYNUPDATE",
+      RedisCommandKind::BfAdd => "BF.ADD",
+      RedisCommandKind::BfCard => "BF.CARD",
+      RedisCommandKind::BfExists => "BF.EXISTS",
+      RedisCommandKind::BfInfo => "BF.INFO",
+      RedisCommandKind::BfInsert => "BF.INSERT",
+      RedisCommandKind::BfLoadChunk => "BF.LOADCHUNK",
+      RedisCommandKind::BfMAdd => "BF.MADD",
+      RedisCommandKind::BfMExists => "BF.MEXISTS",
+      RedisCommandKind::BfReserve => "BF.RESERVE",
+      RedisCommandKind::BfScanDump => "BF.SCANDUMP",
+      RedisCommandKind::CfAdd => "CF.ADD",
+      RedisCommandKind::CfAddNx => "CF.ADDNX",
+      RedisCommandKind::CfCount => "CF.COUNT",
+      RedisCommandKind::CfDel => "CF.DEL",
+      RedisCommandKind::CfExists => "CF.EXISTS",
+      RedisCommandKind::CfInfo => "CF.INFO",
+      RedisCommandKind::CfInsert => "CF.INSERT",
+      RedisCommandKind::CfInsertNx => "CF.INSERTNX",
+      RedisCommandKind::CfLoadChunk => "CF.LOADCHUNK",
+      RedisCommandKind::CfMExists => "CF.MEXISTS",
+      RedisCommandKind::CfReserve => "CF.RESERVE",
+      RedisCommandKind::CfScanDump => "CF.SCANDUMP",
+      RedisCommandKind::CmsIncrBy => "CMS.INCRBY",
+      RedisCommandKind::CmsInfo => "CMS.INFO",
+      RedisCommandKind::CmsInitByDim => "CMS.INITBYDIM",
+      RedisCommandKind::CmsInitByProb => "CMS.INITBYPROB",
+      RedisCommandKind::CmsMerge => "CMS.MERGE",
+      RedisCommandKind::CmsQuery => "CMS.QUERY",
+      RedisCommandKind::TopKAdd => "TOPK.ADD",
+      RedisCommandKind::TopKIncrBy => "TOPK.INCRBY",
+      RedisCommandKind::TopKInfo => "TOPK.INFO",
+      RedisCommandKind::TopKList => "TOPK.LIST",
+      RedisCommandKind::TopKQuery => "TOPK.QUERY",
+      RedisCommandKind::TopKReserve => "TOPK.RESERVE",
+      RedisCommandKind::TDigestAdd => "TDIGEST.ADD",
+      RedisCommandKind::TDigestByRank => "TDIGEST.BYRANK",
+      RedisCommandKind::TDigestByRevRank => "TDIGEST.BYREVRANK",
+      RedisCommandKind::TDigestCdf => "TDIGEST.CDF",
+      RedisCommandKind::TDigestCreate => "TDIGEST.CREATE",
+      RedisCommandKind::TDigestInfo => "TDIGEST.INFO",
+      RedisCommandKind::TDigestMax => "TDIGEST.MAX",
+      RedisCommandKind::TDigestMerge => "TDIGEST.MERGE",
+      RedisCommandKind::TDigestMin => "TDIGEST.MIN",
+      RedisCommandKind::TDigestQuantile => "TDIGEST.QUANTILE",
+      RedisCommandKind::TDigestRank => "TDIGEST.RANK",
+      RedisCommandKind::TDigestReset => "TDIGEST.RESET",
+      RedisCommandKind::TDigestRevRank => "TDIGEST.REVRANK",
+      RedisCommandKind::TDigestTrimmedMean => "TDIGEST.TRIMMED_MEAN",
       RedisCommandKind::_Custom(ref kind) => &kind.cmd,
     }
   }
@@ -921,6 +1121,7 @@ impl RedisCommandKind {
       RedisCommandKind::BgSave => "BGSAVE",
       RedisCommandKind::BitCount => "BITCOUNT",
       RedisCommandKind::BitField => "BITFIELD",
+      RedisCommandKind::BitFieldRO => "BITFIELD_RO",
       RedisCommandKind::BitOp => "BITOP",
       RedisCommandKind::BitPos => "BITPOS",
       RedisCommandKind::BlPop => "BLPOP",
@@ -966,12 +1167,18 @@ impl RedisCommandKind {
       | RedisCommandKind::ClusterBumpEpoch
       | RedisCommandKind::ClusterFlushSlots
       | RedisCommandKind::ClusterMyID => "CLUSTER",
+      RedisCommandKind::Command
+      | RedisCommandKind::CommandCount
+      | RedisCommandKind::CommandDocs
+      | RedisCommandKind::CommandGetKeys
+      | RedisCommandKind::CommandInfo => "COMMAND",
       RedisCommandKind::ConfigGet
       | RedisCommandKind::ConfigRewrite
       | RedisCommandKind::ConfigSet
       | RedisCommandKind::ConfigResetStat => "CONFIG",
       RedisCommandKind::Copy => "COPY",
       RedisCommandKind::DBSize => "DBSIZE",
+      RedisCommandKind::DebugObject | RedisCommandKind::DebugSleep => "DEBUG",
       RedisCommandKind::Decr => "DECR",
       RedisCommandKind::DecrBy => "DECRBY",
       RedisCommandKind::Del => "DEL",
@@ -984,6 +1191,7 @@ impl RedisCommandKind {
       RedisCommandKind::Exists => "EXISTS",
       RedisCommandKind::Expire => "EXPIRE",
       RedisCommandKind::ExpireAt => "EXPIREAT",
+      RedisCommandKind::ExpireTime => "EXPIRETIME",
       RedisCommandKind::Failover => "FAILOVER",
       RedisCommandKind::FlushAll => "FLUSHALL",
       RedisCommandKind::_FlushAllCluster => "FLUSHALL",
@@ -998,6 +1206,7 @@ impl RedisCommandKind {
       RedisCommandKind::GeoSearchStore => "GEOSEARCHSTORE",
       RedisCommandKind::Get => "GET",
       RedisCommandKind::GetDel => "GETDEL",
+      RedisCommandKind::GetEx => "GETEX",
       RedisCommandKind::GetBit => "GETBIT",
       RedisCommandKind::GetRange => "GETRANGE",
       RedisCommandKind::GetSet => "GETSET",
@@ -1017,6 +1226,18 @@ impl RedisCommandKind {
       RedisCommandKind::HStrLen => "HSTRLEN",
       RedisCommandKind::HRandField => "HRANDFIELD",
       RedisCommandKind::HVals => "HVALS",
+      RedisCommandKind::HExpire => "HEXPIRE",
+      RedisCommandKind::HPExpire => "HPEXPIRE",
+      RedisCommandKind::HExpireAt => "HEXPIREAT",
+      RedisCommandKind::HPExpireAt => "HPEXPIREAT",
+      RedisCommandKind::HTtl => "HTTL",
+      RedisCommandKind::HPTtl => "HPTTL",
+      RedisCommandKind::HExpireTime => "HEXPIRETIME",
+      RedisCommandKind::HPExpireTime => "HPEXPIRETIME",
+      RedisCommandKind::HPersist => "HPERSIST",
+      RedisCommandKind::HGetDel => "HGETDEL",
+      RedisCommandKind::HGetEx => "HGETEX",
+      RedisCommandKind::HSetEx => "HSETEX",
       RedisCommandKind::Incr => "INCR",
       RedisCommandKind::IncrBy => "INCRBY",
       RedisCommandKind::IncrByFloat => "INCRBYFLOAT",
@@ -1037,6 +1258,11 @@ impl RedisCommandKind {
       RedisCommandKind::LSet => "LSET",
       RedisCommandKind::LTrim => "LTRIM",
       RedisCommandKind::Lcs => "LCS",
+      RedisCommandKind::LatencyDoctor
+      | RedisCommandKind::LatencyHistogram
+      | RedisCommandKind::LatencyHistory
+      | RedisCommandKind::LatencyLatest
+      | RedisCommandKind::LatencyReset => "LATENCY",
       RedisCommandKind::MemoryDoctor => "MEMORY",
       RedisCommandKind::MemoryHelp => "MEMORY",
       RedisCommandKind::MemoryMallocStats => "MEMORY",
@@ -1045,6 +1271,10 @@ impl RedisCommandKind {
       RedisCommandKind::MemoryUsage => "MEMORY",
       RedisCommandKind::Mget => "MGET",
       RedisCommandKind::Migrate => "MIGRATE",
+      RedisCommandKind::ModuleList
+      | RedisCommandKind::ModuleLoad
+      | RedisCommandKind::ModuleLoadEx
+      | RedisCommandKind::ModuleUnload => "MODULE",
       RedisCommandKind::Monitor => "MONITOR",
       RedisCommandKind::Move => "MOVE",
       RedisCommandKind::Mset => "MSET",
@@ -1054,6 +1284,7 @@ impl RedisCommandKind {
       RedisCommandKind::Persist => "PERSIST",
       RedisCommandKind::Pexpire => "PEXPIRE",
       RedisCommandKind::Pexpireat => "PEXPIREAT",
+      RedisCommandKind::PexpireTime => "PEXPIRETIME",
       RedisCommandKind::Pfadd => "PFADD",
       RedisCommandKind::Pfcount => "PFCOUNT",
       RedisCommandKind::Pfmerge => "PFMERGE",
@@ -1097,10 +1328,12 @@ impl RedisCommandKind {
       RedisCommandKind::Smismember => "SMISMEMBER",
       RedisCommandKind::Smove => "SMOVE",
       RedisCommandKind::Sort => "SORT",
+      RedisCommandKind::SortRo => "SORT_RO",
       RedisCommandKind::Spop => "SPOP",
       RedisCommandKind::Srandmember => "SRANDMEMBER",
       RedisCommandKind::Srem => "SREM",
       RedisCommandKind::Strlen => "STRLEN",
+      RedisCommandKind::Substr => "SUBSTR",
       RedisCommandKind::Subscribe => "SUBSCRIBE",
       RedisCommandKind::Sunion => "SUNION",
       RedisCommandKind::Sunionstore => "SUNIONSTORE",
@@ -1114,6 +1347,7 @@ impl RedisCommandKind {
       RedisCommandKind::Unlink => "UNLINK",
       RedisCommandKind::Unwatch => "UNWATCH",
       RedisCommandKind::Wait => "WAIT",
+      RedisCommandKind::WaitAof => "WAITAOF",
       RedisCommandKind::Watch => "WATCH",
       RedisCommandKind::XinfoConsumers | RedisCommandKind::XinfoGroups | RedisCommandKind::XinfoStream => "XINFO",
       RedisCommandKind::Xadd => "XADD",
@@ -1239,6 +1473,70 @@ impl RedisCommandKind {
       RedisCommandKind::TsQueryIndex => "TS.QUERYINDEX",
       RedisCommandKind::TsRange => "TS.RANGE",
       RedisCommandKind::TsRevRange => "TS.REVRANGE",
+      RedisCommandKind::FtList => "FT._LIST",
+      RedisCommandKind::FtAggregate => "FT.AGGREGATE",
+      RedisCommandKind::FtSearch => "FT.SEARCH",
+      RedisCommandKind::FtCreate => "FT.CREATE",
+      RedisCommandKind::FtAlter => "FT.ALTER",
+      RedisCommandKind::FtAliasAdd => "FT.ALIASADD",
+      RedisCommandKind::FtAliasDel => "FT.ALIASDEL",
+      RedisCommandKind::FtAliasUpdate => "FT.ALIASUPDATE",
+      RedisCommandKind::FtCursorDel => "FT.CURSOR",
+      RedisCommandKind::FtCursorRead => "FT.CURSOR",
+      RedisCommandKind::FtDropIndex => "FT.DROPINDEX",
+      RedisCommandKind::FtExplain => "FT.EXPLAIN",
+      RedisCommandKind::FtInfo => "FT.INFO",
+      RedisCommandKind::FtSpellCheck => "FT.SPELLCHECK",
+      RedisCommandKind::FtSynDump => "FT.SYNDUMP",
+      RedisCommandKind::FtSynUpdate => "FT.SYNUPDATE",
+      RedisCommandKind::BfAdd => "BF.ADD",
+      RedisCommandKind::BfCard => "BF.CARD",
+      RedisCommandKind::BfExists => "BF.EXISTS",
+      RedisCommandKind::BfInfo => "BF.INFO",
+      RedisCommandKind::BfInsert => "BF.INSERT",
+      RedisCommandKind::BfLoadChunk => "BF.LOADCHUNK",
+      RedisCommandKind::BfMAdd => "BF.MADD",
+      RedisCommandKind::BfMExists => "BF.MEXISTS",
+      RedisCommandKind::BfReserve => "BF.RESERVE",
+      RedisCommandKind::BfScanDump => "BF.SCANDUMP",
+      RedisCommandKind::CfAdd => "CF.ADD",
+      RedisCommandKind::CfAddNx => "CF.ADDNX",
+      RedisCommandKind::CfCount => "CF.COUNT",
+      RedisCommandKind::CfDel => "CF.DEL",
+      RedisCommandKind::CfExists => "CF.EXISTS",
+      RedisCommandKind::CfInfo => "CF.INFO",
+      RedisCommandKind::CfInsert => "CF.INSERT",
+      RedisCommandKind::CfInsertNx => "CF.INSERTNX",
+      RedisCommandKind::CfLoadChunk => "CF.LOADCHUNK",
+      RedisCommandKind::CfMExists => "CF.MEXISTS",
+      RedisCommandKind::CfReserve => "CF.RESERVE",
+      RedisCommandKind::CfScanDump => "CF.SCANDUMP",
+      RedisCommandKind::CmsIncrBy => "CMS.INCRBY",
+      RedisCommandKind::CmsInfo => "CMS.INFO",
+      RedisCommandKind::CmsInitByDim => "CMS.INITBYDIM",
+      RedisCommandKind::CmsInitByProb => "CMS.INITBYPROB",
+      RedisCommandKind::CmsMerge => "CMS.MERGE",
+      RedisCommandKind::CmsQuery => "CMS.QUERY",
+      RedisCommandKind::TopKAdd => "TOPK.ADD",
+      RedisCommandKind::TopKIncrBy => "TOPK.INCRBY",
+      RedisCommandKind::TopKInfo => "TOPK.INFO",
+      RedisCommandKind::TopKList => "TOPK.LIST",
+      RedisCommandKind::TopKQuery => "TOPK.QUERY",
+      RedisCommandKind::TopKReserve => "TOPK.RESERVE",
+      RedisCommandKind::TDigestAdd => "TDIGEST.ADD",
+      RedisCommandKind::TDigestByRank => "TDIGEST.BYRANK",
+      RedisCommandKind::TDigestByRevRank => "TDIGEST.BYREVRANK",
+      RedisCommandKind::TDigestCdf => "TDIGEST.CDF",
+      RedisCommandKind::TDigestCreate => "TDIGEST.CREATE",
+      RedisCommandKind::TDigestInfo => "TDIGEST.INFO",
+      RedisCommandKind::TDigestMax => "TDIGEST.MAX",
+      RedisCommandKind::TDigestMerge => "TDIGEST.MERGE",
+      RedisCommandKind::TDigestMin => "TDIGEST.MIN",
+      RedisCommandKind::TDigestQuantile => "TDIGEST.QUANTILE",
+      RedisCommandKind::TDigestRank => "TDIGEST.RANK",
+      RedisCommandKind::TDigestReset => "TDIGEST.RESET",
+      RedisCommandKind::TDigestRevRank => "TDIGEST.REVRANK",
+      RedisCommandKind::TDigestTrimmedMean => "TDIGEST.TRIMMED_MEAN",
       RedisCommandKind::_Custom(ref kind) => return kind.cmd.clone(),
     };
 
@@ -1313,6 +1611,21 @@ impl RedisCommandKind {
       RedisCommandKind::MemoryMallocStats => "MALLOC-STATS",
       RedisCommandKind::MemoryStats => "STATS",
       RedisCommandKind::MemoryPurge => "PURGE",
+      RedisCommandKind::CommandCount => "COUNT",
+      RedisCommandKind::CommandDocs => "DOCS",
+      RedisCommandKind::CommandGetKeys => "GETKEYS",
+      RedisCommandKind::CommandInfo => "INFO",
+      RedisCommandKind::DebugObject => "OBJECT",
+      RedisCommandKind::DebugSleep => "SLEEP",
+      RedisCommandKind::LatencyDoctor => "DOCTOR",
+      RedisCommandKind::LatencyHistogram => "HISTOGRAM",
+      RedisCommandKind::LatencyHistory => "HISTORY",
+      RedisCommandKind::LatencyLatest => "LATEST",
+      RedisCommandKind::LatencyReset => "RESET",
+      RedisCommandKind::ModuleList => "LIST",
+      RedisCommandKind::ModuleLoad => "LOAD",
+      RedisCommandKind::ModuleLoadEx => "LOADEX",
+      RedisCommandKind::ModuleUnload => "UNLOAD",
       RedisCommandKind::XinfoConsumers => "CONSUMERS",
       RedisCommandKind::XinfoGroups => "GROUPS",
       RedisCommandKind::XinfoStream => "STREAM",
@@ -1340,6 +1653,8 @@ impl RedisCommandKind {
       RedisCommandKind::_FunctionRestoreCluster => "RESTORE",
       RedisCommandKind::_ClientTrackingCluster => "TRACKING",
       RedisCommandKind::JsonDebugMemory => "MEMORY",
+      RedisCommandKind::FtCursorDel => "DEL",
+      RedisCommandKind::FtCursorRead => "READ",
       _ => return None,
     };
 
@@ -1353,11 +1668,35 @@ impl RedisCommandKind {
         | RedisCommandKind::Ping
         | RedisCommandKind::Info
         | RedisCommandKind::Scan
+        | RedisCommandKind::Keys
+        | RedisCommandKind::Command
+        | RedisCommandKind::CommandCount
+        | RedisCommandKind::CommandDocs
+        | RedisCommandKind::CommandGetKeys
+        | RedisCommandKind::CommandInfo
+        | RedisCommandKind::DebugSleep
+        | RedisCommandKind::LatencyDoctor
+        | RedisCommandKind::LatencyHistogram
+        | RedisCommandKind::LatencyHistory
+        | RedisCommandKind::LatencyLatest
+        | RedisCommandKind::ModuleList
         | RedisCommandKind::FlushAll
         | RedisCommandKind::FlushDB
     )
   }
 
+  /// Whether the command changes state that is local to one node, and must be sent to a node chosen by the caller
+  /// on a cluster.
+  pub fn requires_cluster_node(&self) -> bool {
+    matches!(
+      *self,
+      RedisCommandKind::LatencyReset
+        | RedisCommandKind::ModuleLoad
+        | RedisCommandKind::ModuleLoadEx
+        | RedisCommandKind::ModuleUnload
+    )
+  }
+
   pub fn is_blocking(&self) -> bool {
     match *self {
       RedisCommandKind::BlPop
@@ -1370,7 +1709,8 @@ impl RedisCommandKind {
       | RedisCommandKind::BzmPop
       | RedisCommandKind::Fcall
       | RedisCommandKind::FcallRO
-      | RedisCommandKind::Wait => true,
+      | RedisCommandKind::Wait
+      | RedisCommandKind::WaitAof => true,
       // default is false, but can be changed by the BLOCKING args. the RedisCommand::can_pipeline function checks the
       // args too.
       RedisCommandKind::Xread | RedisCommandKind::Xreadgroup => false,
@@ -1486,6 +1826,10 @@ pub struct RedisCommand {
   pub use_replica:            bool,
   /// Only send the command to the provided server.
   pub cluster_node:           Option<Server>,
+  /// Send the command to the provided replica node, if it is still a replica of the primary node, when routing to a
+  /// replica.
+  #[cfg(feature = "replicas")]
+  pub replica_node:           Option<Server>,
   /// A timestamp of when the command was first created from the public interface.
   #[cfg(feature = "metrics")]
   pub created:                Instant,
@@ -1549,6 +1893,8 @@ impl Default for RedisCommand {
       transaction_id:                              None,
       use_replica:                                 false,
       cluster_node:                                None,
+      #[cfg(feature = "replicas")]
+      replica_node:                                None,
       network_start:                               None,
       write_attempts:                              0,
       fail_fast:                                   false,
@@ -1767,6 +2113,8 @@ impl RedisCommand {
       skip_backpressure: self.skip_backpressure,
       router_tx: self.router_tx.clone(),
       cluster_node: self.cluster_node.clone(),
+      #[cfg(feature = "replicas")]
+      replica_node: self.replica_node.clone(),
       fail_fast: self.fail_fast,
       response,
       use_replica: self.use_replica,
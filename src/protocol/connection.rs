@@ -38,6 +38,8 @@ use tokio_util::codec::Framed;
 use crate::prelude::ServerConfig;
 #[cfg(any(feature = "enable-native-tls", feature = "enable-rustls"))]
 use crate::protocol::tls::TlsConnector;
+#[cfg(all(feature = "client-tracking", not(feature = "replicas")))]
+use crate::types::RedisValue;
 #[cfg(feature = "replicas")]
 use crate::{
   protocol::{connection, responders::ResponseKind},
@@ -611,6 +613,25 @@ impl RedisTransport {
     }
   }
 
+  /// Clear the local cache and send `CLIENT TRACKING ON` if client side caching is enabled.
+  #[cfg(feature = "client-tracking")]
+  pub async fn setup_client_cache(&mut self, inner: &Arc<RedisClientInner>) -> Result<(), RedisError> {
+    if !inner.cache.is_enabled() {
+      return Ok(());
+    }
+    if !inner.is_resp3() {
+      // RESP2 tracking is redirected to a companion connection once the reconnection event is processed
+      inner.cache.suspend();
+      return Ok(());
+    }
+
+    _debug!(inner, "Enabling client tracking on {}", self.server);
+    inner.cache.clear();
+    let command = RedisCommand::new(RedisCommandKind::ClientTracking, vec![static_val!("ON")]);
+    let response = self.request_response(command, inner.is_resp3()).await?;
+    protocol_utils::frame_to_results(response).map(|_| ())
+  }
+
   /// Read and cache the server version.
   pub async fn cache_server_version(&mut self, inner: &Arc<RedisClientInner>) -> Result<(), RedisError> {
     let command = RedisCommand::new(RedisCommandKind::Info, vec![InfoKind::Server.to_str().into()]);
@@ -694,8 +715,7 @@ impl RedisTransport {
   pub async fn switch_protocols_and_authenticate(&mut self, inner: &Arc<RedisClientInner>) -> Result<(), RedisError> {
     // reset the protocol version to the one specified by the config when we create new connections
     inner.reset_protocol_version();
-    let username = inner.config.username.clone();
-    let password = inner.config.password.clone();
+    let (username, password) = inner.read_credentials(Some(&self.server)).await?;
 
     if inner.is_resp3() {
       _debug!(inner, "Switching to RESP3 protocol with HELLO...");
@@ -814,7 +834,7 @@ impl RedisTransport {
 
     utils::apply_timeout(
       async {
-        if inner.config.password.is_some() || inner.config.version == RespVersion::RESP3 {
+        if inner.has_credentials() || inner.config.version == RespVersion::RESP3 {
           self.switch_protocols_and_authenticate(inner).await?;
         } else {
           self.ping(inner).await?;
@@ -825,6 +845,8 @@ impl RedisTransport {
         }
         self.cache_connection_id(inner).await?;
         self.cache_server_version(inner).await?;
+        #[cfg(feature = "client-tracking")]
+        self.setup_client_cache(inner).await?;
         if !inner.connection.disable_cluster_health_check {
           self.check_cluster_state(inner).await?;
         }
@@ -1,4 +1,7 @@
-use crate::types::RedisValue;
+use crate::{
+  error::{RedisError, RedisErrorKind},
+  types::{KeySpec, KeySpecBeginSearch, KeySpecFindKeys, RedisValue},
+};
 use redis_protocol::redis_keyslot;
 
 pub fn hash_value(value: &RedisValue) -> Option<u16> {
@@ -38,6 +41,12 @@ pub enum ClusterHash {
   Offset(usize),
   /// Provide a custom hash slot value.
   Custom(u16),
+  /// Hash the keys found via the server's [key specifications](https://redis.io/docs/reference/key-specs/).
+  ///
+  /// Only supported with [custom](crate::interfaces::ClientLike::custom) commands. The client will read and cache
+  /// the `COMMAND INFO` output for the command, hash every key in the arguments, and return an error without
+  /// sending the command if the keys do not map to the same hash slot. Other commands fall back to `FirstKey`.
+  KeySpecs,
 }
 
 impl Default for ClusterHash {
@@ -78,7 +87,7 @@ impl ClusterHash {
   pub fn hash(&self, args: &[RedisValue]) -> Option<u16> {
     match self {
       ClusterHash::FirstValue => args.first().and_then(hash_value),
-      ClusterHash::FirstKey => args.iter().find_map(hash_key),
+      ClusterHash::FirstKey | ClusterHash::KeySpecs => args.iter().find_map(hash_key),
       ClusterHash::Random => None,
       ClusterHash::Offset(idx) => args.get(*idx).and_then(hash_value),
       ClusterHash::Custom(val) => Some(*val),
@@ -89,9 +98,234 @@ impl ClusterHash {
   pub fn find_key<'a>(&self, args: &'a [RedisValue]) -> Option<&'a [u8]> {
     match self {
       ClusterHash::FirstValue => args.first().and_then(read_redis_key),
-      ClusterHash::FirstKey => args.iter().find_map(read_redis_key),
+      ClusterHash::FirstKey | ClusterHash::KeySpecs => args.iter().find_map(read_redis_key),
       ClusterHash::Offset(idx) => args.get(*idx).and_then(read_redis_key),
       ClusterHash::Random | ClusterHash::Custom(_) => None,
     }
   }
 }
+
+fn arg_eq_ignore_case(value: &RedisValue, keyword: &str) -> bool {
+  match value {
+    RedisValue::String(s) => s.eq_ignore_ascii_case(keyword),
+    RedisValue::Bytes(b) => b.eq_ignore_ascii_case(keyword.as_bytes()),
+    _ => false,
+  }
+}
+
+/// Find the indexes of the keys in `argv` described by a key spec, following the server's `getKeysUsingKeySpecs`.
+///
+/// Returns `None` if the keys cannot be found without parsing the command arguments.
+fn find_spec_key_indexes(spec: &KeySpec, argv: &[&RedisValue]) -> Option<Vec<usize>> {
+  let argc = argv.len() as i64;
+
+  let first = match spec.begin_search {
+    KeySpecBeginSearch::Index(idx) => idx,
+    KeySpecBeginSearch::Keyword {
+      ref keyword,
+      start_from,
+    } => {
+      let start = if start_from > 0 { start_from } else { argc + start_from };
+      let found = if start_from > 0 {
+        (start .. argc - 1).find(|idx| arg_eq_ignore_case(argv[*idx as usize], keyword))
+      } else {
+        (1 ..= start.min(argc - 1))
+          .rev()
+          .find(|idx| arg_eq_ignore_case(argv[*idx as usize], keyword))
+      };
+
+      match found {
+        Some(idx) => idx + 1,
+        None => return Some(Vec::new()),
+      }
+    },
+    KeySpecBeginSearch::Unknown => return None,
+  };
+
+  let (first, last, step) = match spec.find_keys {
+    KeySpecFindKeys::Range {
+      last_key,
+      key_step,
+      limit,
+    } => {
+      let last = if last_key >= 0 {
+        first + last_key
+      } else if limit <= 0 {
+        argc + last_key
+      } else {
+        first + ((argc - first) / limit + last_key)
+      };
+
+      (first, last, key_step)
+    },
+    KeySpecFindKeys::KeyNum {
+      key_num_idx,
+      first_key,
+      key_step,
+    } => {
+      let num_keys = argv
+        .get((first + key_num_idx) as usize)
+        .and_then(|v| v.as_i64())
+        .filter(|n| *n >= 0)?;
+
+      (first + first_key, first + first_key + num_keys - 1, key_step)
+    },
+    KeySpecFindKeys::Unknown => return None,
+  };
+
+  let step = step.max(1) as usize;
+  let mut indexes = Vec::new();
+  for idx in (first.max(1) ..= last).step_by(step) {
+    if idx >= argc {
+      return None;
+    }
+    indexes.push(idx as usize);
+  }
+
+  Some(indexes)
+}
+
+/// Find the keys in the arguments (excluding the command name) with the provided key specs.
+///
+/// Returns `None` if any key spec requires parsing the command arguments.
+pub fn find_spec_keys<'a>(specs: &[KeySpec], args: &'a [RedisValue]) -> Option<Vec<&'a RedisValue>> {
+  // key spec indexes include the command name
+  let nil = RedisValue::Null;
+  let argv: Vec<&RedisValue> = Some(&nil).into_iter().chain(args.iter()).collect();
+
+  let mut keys = Vec::new();
+  for spec in specs.iter() {
+    for idx in find_spec_key_indexes(spec, &argv)? {
+      keys.push(&args[idx - 1]);
+    }
+  }
+
+  Some(keys)
+}
+
+/// Hash the provided keys, returning an error if they do not map to the same hash slot.
+pub fn hash_same_slot(keys: &[&RedisValue]) -> Result<Option<u16>, RedisError> {
+  let mut slot = None;
+  for key in keys.iter() {
+    let key_slot = match hash_value(key) {
+      Some(slot) => slot,
+      None => continue,
+    };
+
+    if slot.is_some() && slot != Some(key_slot) {
+      return Err(RedisError::new(
+        RedisErrorKind::Cluster,
+        "CROSSSLOT Keys in request don't hash to the same slot.",
+      ));
+    }
+    slot = Some(key_slot);
+  }
+
+  Ok(slot)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn spec(begin_search: KeySpecBeginSearch, find_keys: KeySpecFindKeys) -> KeySpec {
+    KeySpec {
+      begin_search,
+      find_keys,
+      flags: Vec::new(),
+      notes: None,
+    }
+  }
+
+  fn args(values: &[&str]) -> Vec<RedisValue> {
+    values.iter().map(|v| RedisValue::from(*v)).collect()
+  }
+
+  fn keys(values: Option<Vec<&RedisValue>>) -> Vec<String> {
+    values.unwrap().into_iter().map(|v| v.as_string().unwrap()).collect()
+  }
+
+  #[test]
+  fn should_find_range_keys_with_step() {
+    // MSET
+    let specs = vec![spec(KeySpecBeginSearch::Index(1), KeySpecFindKeys::Range {
+      last_key: -1,
+      key_step: 2,
+      limit:    0,
+    })];
+    let args = args(&["a", "1", "b", "2"]);
+
+    assert_eq!(keys(find_spec_keys(&specs, &args)), vec!["a", "b"]);
+  }
+
+  #[test]
+  fn should_find_keynum_keys() {
+    // ZUNIONSTORE
+    let specs = vec![
+      spec(KeySpecBeginSearch::Index(1), KeySpecFindKeys::Range {
+        last_key: 0,
+        key_step: 1,
+        limit:    0,
+      }),
+      spec(KeySpecBeginSearch::Index(2), KeySpecFindKeys::KeyNum {
+        key_num_idx: 0,
+        first_key:   1,
+        key_step:    1,
+      }),
+    ];
+    let args = args(&["dest", "2", "a", "b", "WEIGHTS", "1", "2"]);
+
+    assert_eq!(keys(find_spec_keys(&specs, &args)), vec!["dest", "a", "b"]);
+  }
+
+  #[test]
+  fn should_find_keyword_keys() {
+    // XREAD
+    let specs = vec![spec(
+      KeySpecBeginSearch::Keyword {
+        keyword:    "STREAMS".into(),
+        start_from: 1,
+      },
+      KeySpecFindKeys::Range {
+        last_key: -1,
+        key_step: 1,
+        limit:    2,
+      },
+    )];
+    let args = args(&["COUNT", "10", "streams", "a", "b", "0", "0"]);
+
+    assert_eq!(keys(find_spec_keys(&specs, &args)), vec!["a", "b"]);
+  }
+
+  #[test]
+  fn should_skip_missing_keyword() {
+    let specs = vec![spec(
+      KeySpecBeginSearch::Keyword {
+        keyword:    "STORE".into(),
+        start_from: -2,
+      },
+      KeySpecFindKeys::Range {
+        last_key: 0,
+        key_step: 1,
+        limit:    0,
+      },
+    )];
+    let args = args(&["a", "b"]);
+
+    assert!(keys(find_spec_keys(&specs, &args)).is_empty());
+  }
+
+  #[test]
+  fn should_not_find_unknown_keys() {
+    let specs = vec![spec(KeySpecBeginSearch::Unknown, KeySpecFindKeys::Unknown)];
+    assert!(find_spec_keys(&specs, &args(&["a"])).is_none());
+  }
+
+  #[test]
+  fn should_error_on_crossslot_keys() {
+    let (a, b, c) = ("a{1}".into(), "b{1}".into(), "c{2}".into());
+
+    assert_eq!(hash_same_slot(&[&a, &b]).unwrap(), Some(redis_keyslot(b"1")));
+    assert_eq!(*hash_same_slot(&[&a, &c]).unwrap_err().kind(), RedisErrorKind::Cluster);
+  }
+}
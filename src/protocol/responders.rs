@@ -1,4 +1,5 @@
 use crate::{
+  commands,
   error::{RedisError, RedisErrorKind},
   interfaces::Resp3Frame,
   modules::inner::RedisClientInner,
@@ -190,6 +191,12 @@ fn sample_command_latencies(inner: &Arc<RedisClientInner>, command: &mut RedisCo
     sample_latency(&inner.network_latency_stats, sent);
   }
   sample_latency(&inner.latency_stats, command.created.clone());
+
+  if client_utils::read_bool_atomic(&inner.sample_latency) {
+    let now = Instant::now();
+    let latency = now.duration_since(command.created).as_secs_f64() * 1000.0;
+    inner.latency_average.write().sample(latency, now);
+  }
 }
 
 #[cfg(not(feature = "metrics"))]
@@ -558,8 +565,17 @@ pub fn respond_key_scan(
   let scan_stream = scanner.tx.clone();
   let can_continue = next_cursor != LAST_CURSOR;
   scanner.update_cursor(next_cursor);
+  #[cfg(feature = "replicas")]
+  if command.use_replica {
+    scanner.replica = Some(server.clone());
+  }
   command.respond_to_router(inner, RouterResponse::Continue);
 
+  if !can_continue {
+    if let Some(ref state) = scanner.cluster {
+      commands::scan::start_pending_cluster_node(inner, state);
+    }
+  }
   let scan_result = ScanResult {
     scan_state: scanner,
     inner: inner.clone(),
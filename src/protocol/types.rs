@@ -1,14 +1,16 @@
 use std::{
   cmp::Ordering,
-  collections::{BTreeMap, BTreeSet, HashMap},
+  collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
   convert::TryInto,
   fmt::{Display, Formatter},
   hash::{Hash, Hasher},
   net::{SocketAddr, ToSocketAddrs},
   sync::Arc,
+  time::Instant,
 };
 
 use bytes_utils::Str;
+use parking_lot::Mutex;
 use rand::Rng;
 #[allow(unused_imports)]
 pub use redis_protocol::{redis_keyslot, resp2::types::NULL, types::CRLF};
@@ -249,7 +251,7 @@ impl Ord for Server {
 }
 
 /// The kind of pubsub message.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum MessageKind {
   /// A message from a `subscribe` command.
   Message,
@@ -283,6 +285,22 @@ pub struct Message {
   pub server:  Server,
 }
 
+/// Shared state for a `scan_cluster` operation.
+pub struct ClusterScanState {
+  /// The next cursor on each node, or `None` if the scan on that node finished.
+  pub cursors: Mutex<BTreeMap<Server, Option<Str>>>,
+  /// The node that owns each hash slot used to route the scan commands.
+  pub nodes:   HashMap<u16, Server>,
+  /// The hash slots and cursors waiting to be scanned due to the concurrency limit.
+  pub pending: Mutex<VecDeque<(u16, Str)>>,
+  /// The arguments sent in each scan command, with the cursor at index 0.
+  pub args:    Vec<RedisValue>,
+  /// The sender half of the results channel.
+  pub tx:      UnboundedSender<Result<ScanResult, RedisError>>,
+  /// Options that limit the load on the cluster.
+  pub options: ScanOptions,
+}
+
 pub struct KeyScanInner {
   /// The hash slot for the command.
   pub hash_slot:  Option<u16>,
@@ -294,6 +312,13 @@ pub struct KeyScanInner {
   pub args:       Vec<RedisValue>,
   /// The sender half of the results channel.
   pub tx:         UnboundedSender<Result<ScanResult, RedisError>>,
+  /// The shared state, if used in a `scan_cluster` operation.
+  pub cluster:    Option<Arc<ClusterScanState>>,
+  /// When the last page was requested.
+  pub requested:  Option<Instant>,
+  /// The replica node that returned the last page, if any.
+  #[cfg(feature = "replicas")]
+  pub replica:    Option<Server>,
 }
 
 impl KeyScanInner {
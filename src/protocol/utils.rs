@@ -229,6 +229,17 @@ pub fn parse_message_fields(frame: &Resp3Frame) -> Result<(Str, RedisValue), Red
   Ok((channel, value))
 }
 
+/// Read the pattern from a `pmessage` frame in either the RESP2 or RESP3 format.
+#[cfg(feature = "subscriber-client")]
+pub fn parse_message_pattern(frame: &Resp3Frame) -> Option<Str> {
+  match frame {
+    Resp3Frame::Array { ref data, .. } | Resp3Frame::Push { ref data, .. } if data.len() >= 4 => {
+      frame_to_str(&data[data.len() - 3])
+    },
+    _ => None,
+  }
+}
+
 /// Parse the frame as a pubsub message.
 pub fn frame_to_pubsub(server: &Server, frame: Resp3Frame) -> Result<Message, RedisError> {
   if let Some(message) = parse_shard_pubsub_frame(server, &frame) {
@@ -789,7 +800,12 @@ pub fn arg_size(value: &RedisValue) -> usize {
   }
 }
 
-#[cfg(any(feature = "blocking-encoding", feature = "partial-tracing", feature = "full-tracing"))]
+#[cfg(any(
+  feature = "blocking-encoding",
+  feature = "partial-tracing",
+  feature = "full-tracing",
+  feature = "client-tracking"
+))]
 pub fn resp3_frame_size(frame: &Resp3Frame) -> usize {
   frame.encode_len().unwrap_or(0)
 }
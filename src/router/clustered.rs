@@ -73,6 +73,23 @@ pub async fn write(
   force_flush: bool,
 ) -> Written {
   let has_custom_server = command.cluster_node.is_some();
+  if !has_custom_server && command.kind.requires_cluster_node() {
+    _debug!(
+      inner,
+      "Respond to caller with error from missing cluster node for {}",
+      command.kind.to_str_debug()
+    );
+    let error = RedisError::new(
+      RedisErrorKind::Cluster,
+      format!(
+        "{} must be sent to a specific cluster node with `with_cluster_node`.",
+        command.kind.to_str_debug()
+      ),
+    );
+    command.finish(inner, Err(error));
+    return Written::Ignore;
+  }
+
   let server = match route_command(inner, state, &command) {
     Some(server) => server,
     None => {
@@ -265,7 +282,7 @@ pub fn spawn_reader_task(
         last_error = Some(error);
         break;
       }
-      if let Some(frame) = responses::check_pubsub_message(&inner, &server, frame) {
+      if let Some(frame) = responses::check_pubsub_message(&inner, &server, frame).await {
         if let Err(e) = process_response_frame(&inner, &server, &buffer, &counters, frame).await {
           _debug!(
             inner,
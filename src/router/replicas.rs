@@ -424,8 +424,13 @@ impl Replicas {
     mut command: RedisCommand,
     force_flush: bool,
   ) -> Written {
-    let replica = match self.routing.next_replica(primary) {
-      Some(replica) => replica.clone(),
+    let pinned = command
+      .replica_node
+      .as_ref()
+      .filter(|server| self.routing.replicas(primary).any(|replica| replica == *server))
+      .cloned();
+    let replica = match pinned.or_else(|| self.routing.next_replica(primary).cloned()) {
+      Some(replica) => replica,
       None => {
         // we do not know of any replica node associated with the primary node
         return if inner.connection.replica.primary_fallback {
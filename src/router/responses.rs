@@ -15,7 +15,7 @@ use crate::types::Invalidation;
 const KEYSPACE_PREFIX: &str = "__keyspace@";
 const KEYEVENT_PREFIX: &str = "__keyevent@";
 #[cfg(feature = "client-tracking")]
-const INVALIDATION_CHANNEL: &'static str = "__redis__:invalidate";
+pub const INVALIDATION_CHANNEL: &'static str = "__redis__:invalidate";
 
 fn parse_keyspace_notification(channel: &str, message: &RedisValue) -> Option<KeyspaceEvent> {
   if channel.starts_with(KEYEVENT_PREFIX) {
@@ -111,6 +111,7 @@ fn parse_pubsub_message(
 #[cfg(feature = "client-tracking")]
 fn broadcast_pubsub_invalidation(inner: &Arc<RedisClientInner>, message: Message, server: &Server) {
   if let Some(invalidation) = Invalidation::from_message(message, server) {
+    inner.cache.invalidate(&invalidation.keys);
     inner.notifications.broadcast_invalidation(invalidation);
   } else {
     _debug!(
@@ -142,15 +143,21 @@ fn broadcast_resp3_invalidation(inner: &Arc<RedisClientInner>, server: &Server,
 
     // RESP3 example: Push { data: [BlobString { data: b"invalidate", attributes: None }, Array { data:
     // [BlobString { data: b"foo", attributes: None }], attributes: None }], attributes: None }
-    if let Resp3Frame::Array { data, .. } = data[1].take() {
-      inner.notifications.broadcast_invalidation(Invalidation {
-        keys:   data
-          .into_iter()
-          .filter_map(|f| f.as_bytes().map(|b| b.into()))
-          .collect(),
-        server: server.clone(),
-      })
-    }
+    // the server sends a null key array when the cache should be flushed
+    let keys: Vec<RedisKey> = match data[1].take() {
+      Resp3Frame::Array { data, .. } => data
+        .into_iter()
+        .filter_map(|f| f.as_bytes().map(|b| b.into()))
+        .collect(),
+      Resp3Frame::Null => Vec::new(),
+      _ => return,
+    };
+
+    inner.cache.invalidate(&keys);
+    inner.notifications.broadcast_invalidation(Invalidation {
+      keys,
+      server: server.clone(),
+    })
   }
 }
 
@@ -213,7 +220,11 @@ fn is_resp3_invalidation(_: &Resp3Frame) -> bool {
 /// Check if the frame is part of a pubsub message, and if so route it to any listeners.
 ///
 /// If not then return it to the caller for further processing.
-pub fn check_pubsub_message(inner: &Arc<RedisClientInner>, server: &Server, frame: Resp3Frame) -> Option<Resp3Frame> {
+pub async fn check_pubsub_message(
+  inner: &Arc<RedisClientInner>,
+  server: &Server,
+  frame: Resp3Frame,
+) -> Option<Resp3Frame> {
   if is_subscription_response(&frame) {
     _debug!(inner, "Dropping unused subscription response.");
     return None;
@@ -228,6 +239,8 @@ pub fn check_pubsub_message(inner: &Arc<RedisClientInner>, server: &Server, fram
     return Some(frame);
   }
 
+  #[cfg(feature = "subscriber-client")]
+  let pattern = protocol_utils::parse_message_pattern(&frame);
   let span = trace::create_pubsub_span(inner, &frame);
   _trace!(inner, "Processing pubsub message from {}.", server);
   let parsed_frame = if let Some(ref span) = span {
@@ -254,6 +267,8 @@ pub fn check_pubsub_message(inner: &Arc<RedisClientInner>, server: &Server, fram
   } else if let Some(event) = parse_keyspace_notification(&message.channel, &message.value) {
     inner.notifications.broadcast_keyspace(event);
   } else {
+    #[cfg(feature = "subscriber-client")]
+    inner.notifications.subscriptions.dispatch(&message, pattern).await;
     inner.notifications.broadcast_pubsub(message);
   }
 
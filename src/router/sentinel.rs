@@ -96,7 +96,10 @@ fn has_different_sentinel_nodes(old: &[(String, u16)], new: &[(String, u16)]) ->
 }
 
 #[cfg(feature = "sentinel-auth")]
-fn read_sentinel_auth(inner: &Arc<RedisClientInner>) -> Result<(Option<String>, Option<String>), RedisError> {
+async fn read_sentinel_auth(
+  inner: &Arc<RedisClientInner>,
+  _: &Server,
+) -> Result<(Option<String>, Option<String>), RedisError> {
   match inner.config.server {
     ServerConfig::Sentinel {
       ref username,
@@ -111,26 +114,22 @@ fn read_sentinel_auth(inner: &Arc<RedisClientInner>) -> Result<(Option<String>,
 }
 
 #[cfg(not(feature = "sentinel-auth"))]
-fn read_sentinel_auth(inner: &Arc<RedisClientInner>) -> Result<(Option<String>, Option<String>), RedisError> {
-  Ok((inner.config.username.clone(), inner.config.password.clone()))
-}
-
-/// Read the `(host, port)` tuples for the known sentinel nodes, and the credentials to use when connecting.
-fn read_sentinel_nodes_and_auth(
+async fn read_sentinel_auth(
   inner: &Arc<RedisClientInner>,
-) -> Result<(Vec<Server>, (Option<String>, Option<String>)), RedisError> {
-  let (username, password) = read_sentinel_auth(inner)?;
-  let hosts = match inner.server_state.read().kind.read_sentinel_nodes(&inner.config.server) {
-    Some(hosts) => hosts,
-    None => {
-      return Err(RedisError::new(
-        RedisErrorKind::Sentinel,
-        "Failed to read cached sentinel nodes.",
-      ))
-    },
-  };
+  server: &Server,
+) -> Result<(Option<String>, Option<String>), RedisError> {
+  inner.read_credentials(Some(server)).await
+}
 
-  Ok((hosts, (username, password)))
+/// Read the `(host, port)` tuples for the known sentinel nodes.
+fn read_sentinel_nodes(inner: &Arc<RedisClientInner>) -> Result<Vec<Server>, RedisError> {
+  match inner.server_state.read().kind.read_sentinel_nodes(&inner.config.server) {
+    Some(hosts) => Ok(hosts),
+    None => Err(RedisError::new(
+      RedisErrorKind::Sentinel,
+      "Failed to read cached sentinel nodes.",
+    )),
+  }
 }
 
 /// Read the set of sentinel nodes via `SENTINEL sentinels`.
@@ -158,14 +157,15 @@ async fn read_sentinels(
 
 /// Connect to any of the sentinel nodes provided on the associated `RedisConfig`.
 async fn connect_to_sentinel(inner: &Arc<RedisClientInner>) -> Result<RedisTransport, RedisError> {
-  let (hosts, (username, password)) = read_sentinel_nodes_and_auth(inner)?;
+  let hosts = read_sentinel_nodes(inner)?;
 
   for server in hosts.into_iter() {
     _debug!(inner, "Connecting to sentinel {}", server);
+    let (username, password) = try_or_continue!(read_sentinel_auth(inner, &server).await);
     let mut transport = try_or_continue!(connection::create(inner, &server, None).await);
     try_or_continue!(
       utils::apply_timeout(
-        transport.authenticate(&inner.id, username.clone(), password.clone(), false),
+        transport.authenticate(&inner.id, username, password, false),
         inner.internal_command_timeout()
       )
       .await
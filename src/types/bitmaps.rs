@@ -0,0 +1,340 @@
+use crate::{
+  error::{RedisError, RedisErrorKind},
+  types::RedisValue,
+  utils,
+};
+use bytes_utils::Str;
+use std::convert::TryInto;
+
+/// The operation argument for the [BITOP](https://redis.io/commands/bitop) command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BitOperation {
+  And,
+  Or,
+  Xor,
+  Not,
+}
+
+impl BitOperation {
+  pub(crate) fn to_str(&self) -> Str {
+    utils::static_str(match *self {
+      BitOperation::And => "AND",
+      BitOperation::Or => "OR",
+      BitOperation::Xor => "XOR",
+      BitOperation::Not => "NOT",
+    })
+  }
+}
+
+/// The unit used to interpret the range arguments in the `BITCOUNT` and `BITPOS` commands.
+///
+/// The server uses `BYTE` if not provided.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BitUnit {
+  Byte,
+  Bit,
+}
+
+impl BitUnit {
+  pub(crate) fn to_str(&self) -> Str {
+    utils::static_str(match *self {
+      BitUnit::Byte => "BYTE",
+      BitUnit::Bit => "BIT",
+    })
+  }
+}
+
+/// The integer encoding used by a `BITFIELD` sub-operation.
+///
+/// Signed integers support up to 64 bits and unsigned integers support up to 63 bits.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BitfieldEncoding {
+  /// A signed integer with the provided number of bits, such as `i8`.
+  Signed(u8),
+  /// An unsigned integer with the provided number of bits, such as `u4`.
+  Unsigned(u8),
+}
+
+impl BitfieldEncoding {
+  pub(crate) fn to_value(&self) -> Result<RedisValue, RedisError> {
+    let (prefix, bits, max) = match *self {
+      BitfieldEncoding::Signed(bits) => ("i", bits, 64),
+      BitfieldEncoding::Unsigned(bits) => ("u", bits, 63),
+    };
+
+    if bits == 0 || bits > max {
+      Err(RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        format!("Invalid bitfield encoding size: {}{}", prefix, bits),
+      ))
+    } else {
+      Ok(format!("{}{}", prefix, bits).into())
+    }
+  }
+}
+
+/// The offset argument used by a `BITFIELD` sub-operation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BitfieldOffset {
+  /// An absolute offset, in bits.
+  Bits(u64),
+  /// An offset multiplied by the size of the encoding, equivalent to `#<offset>`.
+  Multiplied(u64),
+}
+
+impl BitfieldOffset {
+  pub(crate) fn to_value(&self) -> Result<RedisValue, RedisError> {
+    match *self {
+      BitfieldOffset::Bits(offset) => offset.try_into(),
+      BitfieldOffset::Multiplied(offset) => Ok(format!("#{}", offset).into()),
+    }
+  }
+}
+
+impl From<u64> for BitfieldOffset {
+  fn from(offset: u64) -> Self {
+    BitfieldOffset::Bits(offset)
+  }
+}
+
+impl From<u32> for BitfieldOffset {
+  fn from(offset: u32) -> Self {
+    BitfieldOffset::Bits(offset as u64)
+  }
+}
+
+/// The overflow behavior applied to subsequent `SET` and `INCRBY` sub-operations in a `BITFIELD` command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BitfieldOverflow {
+  Wrap,
+  Sat,
+  Fail,
+}
+
+impl BitfieldOverflow {
+  pub(crate) fn to_str(&self) -> Str {
+    utils::static_str(match *self {
+      BitfieldOverflow::Wrap => "WRAP",
+      BitfieldOverflow::Sat => "SAT",
+      BitfieldOverflow::Fail => "FAIL",
+    })
+  }
+}
+
+/// A sub-operation in a `BITFIELD` command.
+///
+/// <https://redis.io/commands/bitfield>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BitfieldOperation {
+  /// Read the integer at the provided offset.
+  Get {
+    encoding: BitfieldEncoding,
+    offset:   BitfieldOffset,
+  },
+  /// Set the integer at the provided offset, returning the old value.
+  Set {
+    encoding: BitfieldEncoding,
+    offset:   BitfieldOffset,
+    value:    i64,
+  },
+  /// Increment or decrement the integer at the provided offset, returning the new value.
+  IncrBy {
+    encoding:  BitfieldEncoding,
+    offset:    BitfieldOffset,
+    increment: i64,
+  },
+  /// Change the overflow behavior of the following `SET` or `INCRBY` operations.
+  Overflow(BitfieldOverflow),
+}
+
+impl BitfieldOperation {
+  pub(crate) fn is_read_only(&self) -> bool {
+    matches!(*self, BitfieldOperation::Get { .. })
+  }
+
+  pub(crate) fn args_len(&self) -> usize {
+    match *self {
+      BitfieldOperation::Get { .. } => 3,
+      BitfieldOperation::Set { .. } | BitfieldOperation::IncrBy { .. } => 4,
+      BitfieldOperation::Overflow(_) => 2,
+    }
+  }
+
+  pub(crate) fn append_args(&self, args: &mut Vec<RedisValue>) -> Result<(), RedisError> {
+    match self {
+      BitfieldOperation::Get { encoding, offset } => {
+        args.push(static_val!("GET"));
+        args.push(encoding.to_value()?);
+        args.push(offset.to_value()?);
+      },
+      BitfieldOperation::Set {
+        encoding,
+        offset,
+        value,
+      } => {
+        args.push(static_val!("SET"));
+        args.push(encoding.to_value()?);
+        args.push(offset.to_value()?);
+        args.push((*value).into());
+      },
+      BitfieldOperation::IncrBy {
+        encoding,
+        offset,
+        increment,
+      } => {
+        args.push(static_val!("INCRBY"));
+        args.push(encoding.to_value()?);
+        args.push(offset.to_value()?);
+        args.push((*increment).into());
+      },
+      BitfieldOperation::Overflow(overflow) => {
+        args.push(static_val!("OVERFLOW"));
+        args.push(overflow.to_str().into());
+      },
+    };
+
+    Ok(())
+  }
+}
+
+/// An ordered set of sub-operations for the `BITFIELD` and `BITFIELD_RO` commands.
+///
+/// ```rust
+/// # use fred::types::{BitfieldEncoding, BitfieldOffset, BitfieldOperations, BitfieldOverflow};
+/// let mut operations = BitfieldOperations::new();
+/// operations
+///   .overflow(BitfieldOverflow::Sat)
+///   .incrby(BitfieldEncoding::Unsigned(8), 0_u64, 10)
+///   .get(BitfieldEncoding::Signed(4), BitfieldOffset::Multiplied(1));
+///
+/// assert_eq!(operations.len(), 3);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct BitfieldOperations {
+  operations: Vec<BitfieldOperation>,
+}
+
+impl BitfieldOperations {
+  /// Create a new empty set of operations.
+  pub fn new() -> Self {
+    BitfieldOperations::default()
+  }
+
+  /// Add a `GET <encoding> <offset>` operation.
+  pub fn get<O>(&mut self, encoding: BitfieldEncoding, offset: O) -> &mut Self
+  where
+    O: Into<BitfieldOffset>,
+  {
+    self.operations.push(BitfieldOperation::Get {
+      encoding,
+      offset: offset.into(),
+    });
+    self
+  }
+
+  /// Add a `SET <encoding> <offset> <value>` operation.
+  pub fn set<O>(&mut self, encoding: BitfieldEncoding, offset: O, value: i64) -> &mut Self
+  where
+    O: Into<BitfieldOffset>,
+  {
+    self.operations.push(BitfieldOperation::Set {
+      encoding,
+      offset: offset.into(),
+      value,
+    });
+    self
+  }
+
+  /// Add an `INCRBY <encoding> <offset> <increment>` operation.
+  pub fn incrby<O>(&mut self, encoding: BitfieldEncoding, offset: O, increment: i64) -> &mut Self
+  where
+    O: Into<BitfieldOffset>,
+  {
+    self.operations.push(BitfieldOperation::IncrBy {
+      encoding,
+      offset: offset.into(),
+      increment,
+    });
+    self
+  }
+
+  /// Add an `OVERFLOW <WRAP|SAT|FAIL>` operation.
+  pub fn overflow(&mut self, overflow: BitfieldOverflow) -> &mut Self {
+    self.operations.push(BitfieldOperation::Overflow(overflow));
+    self
+  }
+
+  /// Read the number of operations.
+  pub fn len(&self) -> usize {
+    self.operations.len()
+  }
+
+  /// Whether the set of operations is empty.
+  pub fn is_empty(&self) -> bool {
+    self.operations.is_empty()
+  }
+
+  /// Read the inner operations.
+  pub fn inner(&self) -> &[BitfieldOperation] {
+    &self.operations
+  }
+
+  /// Whether all the operations can be used with `BITFIELD_RO`.
+  pub(crate) fn is_read_only(&self) -> bool {
+    self.operations.iter().all(|op| op.is_read_only())
+  }
+
+  pub(crate) fn args_len(&self) -> usize {
+    self.operations.iter().fold(0, |count, op| count + op.args_len())
+  }
+
+  pub(crate) fn append_args(&self, args: &mut Vec<RedisValue>) -> Result<(), RedisError> {
+    for operation in self.operations.iter() {
+      operation.append_args(args)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl From<BitfieldOperation> for BitfieldOperations {
+  fn from(operation: BitfieldOperation) -> Self {
+    BitfieldOperations {
+      operations: vec![operation],
+    }
+  }
+}
+
+impl From<Vec<BitfieldOperation>> for BitfieldOperations {
+  fn from(operations: Vec<BitfieldOperation>) -> Self {
+    BitfieldOperations { operations }
+  }
+}
+
+impl<const N: usize> From<[BitfieldOperation; N]> for BitfieldOperations {
+  fn from(operations: [BitfieldOperation; N]) -> Self {
+    BitfieldOperations {
+      operations: operations.into_iter().collect(),
+    }
+  }
+}
+
+impl FromIterator<BitfieldOperation> for BitfieldOperations {
+  fn from_iter<I: IntoIterator<Item = BitfieldOperation>>(iter: I) -> Self {
+    BitfieldOperations {
+      operations: iter.into_iter().collect(),
+    }
+  }
+}
+
+impl From<&mut BitfieldOperations> for BitfieldOperations {
+  fn from(operations: &mut BitfieldOperations) -> Self {
+    operations.clone()
+  }
+}
+
+impl From<&BitfieldOperations> for BitfieldOperations {
+  fn from(operations: &BitfieldOperations) -> Self {
+    operations.clone()
+  }
+}
@@ -2,7 +2,7 @@ use crate::{
   clients::{RedisClient, RedisPool},
   error::{RedisError, RedisErrorKind},
   prelude::ReconnectPolicy,
-  types::{ConnectionConfig, PerformanceConfig, RedisConfig, ServerConfig},
+  types::{ConnectionConfig, ElasticPoolConfig, PerformanceConfig, RedisConfig, ServerConfig},
 };
 
 #[cfg(feature = "subscriber-client")]
@@ -247,6 +247,23 @@ impl Builder {
     }
   }
 
+  /// Create a new client pool that resizes itself based on load.
+  ///
+  /// See [new_elastic](crate::clients::RedisPool::new_elastic) for more information.
+  pub fn build_elastic_pool(&self, elastic: ElasticPoolConfig) -> Result<RedisPool, RedisError> {
+    if let Some(config) = self.config.as_ref() {
+      RedisPool::new_elastic(
+        config.clone(),
+        Some(self.performance.clone()),
+        Some(self.connection.clone()),
+        self.policy.clone(),
+        elastic,
+      )
+    } else {
+      Err(RedisError::new(RedisErrorKind::Config, "Missing client configuration."))
+    }
+  }
+
   /// Create a new subscriber client.
   #[cfg(feature = "subscriber-client")]
   #[cfg_attr(docsrs, doc(cfg(feature = "subscriber-client")))]
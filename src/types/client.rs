@@ -179,6 +179,25 @@ impl From<bool> for Toggle {
   }
 }
 
+/// Counters describing the state of the local cache used with [client side caching](https://redis.io/docs/manual/client-side-caching/).
+#[cfg(feature = "client-tracking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "client-tracking")))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ClientCacheStats {
+  /// The number of commands served from the cache.
+  pub hits:          u64,
+  /// The number of cacheable commands sent to the server.
+  pub misses:        u64,
+  /// The number of entries removed due to size limits or TTL expiration.
+  pub evictions:     u64,
+  /// The number of entries removed due to invalidation messages or reconnections.
+  pub invalidations: u64,
+  /// The number of entries in the cache.
+  pub entries:       usize,
+  /// The estimated size, in bytes, of all entries in the cache.
+  pub memory:        usize,
+}
+
 /// A [client tracking](https://redis.io/docs/manual/client-side-caching/) invalidation message from the provided server.
 #[cfg(feature = "client-tracking")]
 #[cfg_attr(docsrs, doc(cfg(feature = "client-tracking")))]
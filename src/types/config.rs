@@ -1,14 +1,23 @@
 pub use crate::protocol::types::Server;
-use crate::{error::RedisError, protocol::command::RedisCommand, types::RespVersion, utils};
+use crate::{
+  error::RedisError,
+  protocol::command::RedisCommand,
+  types::{RedisKey, RespVersion, XID},
+  utils,
+};
 use socket2::TcpKeepalive;
 use std::{cmp, time::Duration};
 use url::Url;
 
 #[cfg(feature = "mocks")]
 use crate::mocks::Mocks;
+#[cfg(feature = "credential-provider")]
+use async_trait::async_trait;
+#[cfg(feature = "credential-provider")]
+use std::fmt::Debug;
 #[cfg(feature = "unix-sockets")]
 use std::path::PathBuf;
-#[cfg(feature = "mocks")]
+#[cfg(any(feature = "mocks", feature = "credential-provider"))]
 use std::sync::Arc;
 
 #[cfg(any(feature = "enable-rustls", feature = "enable-native-tls"))]
@@ -453,6 +462,24 @@ pub struct ConnectionConfig {
   ///
   /// Default: `false`
   pub disable_cluster_health_check: bool,
+  /// Whether to split `MGET`, `MSET`, `DEL`, `EXISTS`, `UNLINK`, and `TOUCH` by hash slot when the keys do not
+  /// share a hash slot in a cluster.
+  ///
+  /// See [split_cross_slot](crate::types::Options::split_cross_slot) for more information.
+  ///
+  /// Default: `false`
+  pub split_cross_slot:             bool,
+  /// The maximum number of dedicated connections that can be checked out at once via
+  /// [dedicated](crate::clients::RedisClient::dedicated) or [acquire](crate::clients::RedisPool::acquire).
+  ///
+  /// Callers that exceed this limit will wait until another dedicated connection is dropped.
+  ///
+  /// Default: `0` (unlimited)
+  pub max_dedicated_connections:    usize,
+  /// The maximum amount of time to wait for a dedicated connection when `max_dedicated_connections` is reached.
+  ///
+  /// Default: `0` (wait forever)
+  pub dedicated_wait_timeout:       Duration,
   /// Configuration options for replica nodes.
   ///
   /// Default: `None`
@@ -479,6 +506,9 @@ impl Default for ConnectionConfig {
       cluster_cache_update_delay: Duration::from_millis(0),
       reconnect_on_auth_error: false,
       disable_cluster_health_check: false,
+      split_cross_slot: false,
+      max_dedicated_connections: 0,
+      dedicated_wait_timeout: Duration::from_millis(0),
       tcp: TcpConfig::default(),
       unresponsive: UnresponsiveConfig::default(),
       #[cfg(feature = "replicas")]
@@ -493,6 +523,140 @@ impl Default for ConnectionConfig {
   }
 }
 
+/// Configuration options for a [RedisPool](crate::clients::RedisPool) that resizes itself based on load.
+///
+/// See [new_elastic](crate::clients::RedisPool::new_elastic) for more information.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElasticPoolConfig {
+  /// The minimum number of clients in the pool.
+  ///
+  /// Default: `1`
+  pub min_size:             usize,
+  /// The maximum number of clients in the pool.
+  ///
+  /// Default: `10`
+  pub max_size:             usize,
+  /// Add a client when the average number of in-flight commands per client is at or above this value.
+  ///
+  /// See [command_queue_len](crate::interfaces::MetricsInterface::command_queue_len) for more information.
+  ///
+  /// Default: `100`
+  pub scale_up_threshold:   usize,
+  /// Remove a client when the average number of in-flight commands per client is at or below this value.
+  ///
+  /// Default: `10`
+  pub scale_down_threshold: usize,
+  /// The interval on which the pool checks the load and health of each client.
+  ///
+  /// Default: 1 sec
+  pub check_interval:       Duration,
+  /// Replace any client that stays in a `Disconnected` state for this long with a new client.
+  ///
+  /// Default: 30 sec
+  pub unhealthy_timeout:    Duration,
+}
+
+impl Default for ElasticPoolConfig {
+  fn default() -> Self {
+    ElasticPoolConfig {
+      min_size:             1,
+      max_size:             10,
+      scale_up_threshold:   100,
+      scale_down_threshold: 10,
+      check_interval:       Duration::from_secs(1),
+      unhealthy_timeout:    Duration::from_secs(30),
+    }
+  }
+}
+
+/// Configuration options for a [StreamConsumer](crate::clients::StreamConsumer).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamConsumerConfig {
+  /// The ID used to create the consumer group if it does not exist.
+  ///
+  /// Default: `XID::Max` (`$`)
+  pub start_id:        XID,
+  /// The maximum number of entries to read with each `XREADGROUP` or `XAUTOCLAIM` command.
+  ///
+  /// Default: `10`
+  pub batch_size:      u64,
+  /// The amount of time to block while waiting for new entries. Must be greater than zero.
+  ///
+  /// Default: 2 sec
+  pub block:           Duration,
+  /// The maximum number of entries that can be processed concurrently.
+  ///
+  /// Default: `10`
+  pub max_concurrency: usize,
+  /// The interval on which the consumer claims stale entries from other consumers.
+  ///
+  /// Default: 30 sec
+  pub claim_interval:  Duration,
+  /// The minimum amount of time an entry must be pending before it can be claimed.
+  ///
+  /// Default: 60 sec
+  pub claim_min_idle:  Duration,
+  /// The stream that receives entries that were delivered more than `max_deliveries` times.
+  ///
+  /// If `None` entries are retried indefinitely.
+  ///
+  /// Default: `None`
+  pub dead_letter_key: Option<RedisKey>,
+  /// The maximum number of times an entry can be delivered before it is moved to the dead letter stream.
+  ///
+  /// Default: `5`
+  pub max_deliveries:  u64,
+}
+
+impl Default for StreamConsumerConfig {
+  fn default() -> Self {
+    StreamConsumerConfig {
+      start_id:        XID::Max,
+      batch_size:      10,
+      block:           Duration::from_secs(2),
+      max_concurrency: 10,
+      claim_interval:  Duration::from_secs(30),
+      claim_min_idle:  Duration::from_secs(60),
+      dead_letter_key: None,
+      max_deliveries:  5,
+    }
+  }
+}
+
+/// Configuration options for the local cache used with [client side caching](https://redis.io/docs/manual/client-side-caching/).
+///
+/// See [enable_client_cache](crate::interfaces::TrackingInterface::enable_client_cache) for more information.
+#[cfg(feature = "client-tracking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "client-tracking")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClientCacheConfig {
+  /// The maximum number of cached responses.
+  ///
+  /// Default: `10_000`
+  pub max_entries: usize,
+  /// The maximum estimated size, in bytes, of all cached responses.
+  ///
+  /// Default: `64 MB`
+  pub max_memory:  usize,
+  /// The maximum amount of time a response can be served from the cache.
+  ///
+  /// If `0` entries are only removed by invalidation messages or LRU eviction.
+  ///
+  /// Default: `0`
+  pub ttl:         Duration,
+}
+
+#[cfg(feature = "client-tracking")]
+impl Default for ClientCacheConfig {
+  fn default() -> Self {
+    ClientCacheConfig {
+      max_entries: 10_000,
+      max_memory:  64 * 1024 * 1024,
+      ttl:         Duration::from_secs(0),
+    }
+  }
+}
+
 /// Configuration options that can affect the performance of the client.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PerformanceConfig {
@@ -546,6 +710,57 @@ impl Default for PerformanceConfig {
   }
 }
 
+/// A trait that can be used to override the credentials used in each `AUTH` or `HELLO` command.
+///
+/// The client will call [fetch](Self::fetch) before authenticating each new connection, including reconnections.
+///
+/// ```rust
+/// # use fred::prelude::*;
+/// # use fred::types::{CredentialProvider, Server};
+/// # use std::{sync::Arc, time::Duration};
+/// #[derive(Debug)]
+/// struct RotatingPassword;
+///
+/// #[async_trait::async_trait]
+/// impl CredentialProvider for RotatingPassword {
+///   async fn fetch(
+///     &self,
+///     _: Option<&Server>,
+///   ) -> Result<(Option<String>, Option<String>), RedisError> {
+///     // read the latest password from a secrets manager, etc
+///     Ok((None, Some("bar".into())))
+///   }
+///
+///   fn refresh_interval(&self) -> Option<Duration> {
+///     Some(Duration::from_secs(60 * 5))
+///   }
+/// }
+///
+/// let config = RedisConfig {
+///   credential_provider: Some(Arc::new(RotatingPassword)),
+///   ..Default::default()
+/// };
+/// ```
+#[cfg(feature = "credential-provider")]
+#[cfg_attr(docsrs, doc(cfg(feature = "credential-provider")))]
+#[async_trait]
+pub trait CredentialProvider: Debug + Send + Sync + 'static {
+  /// Read the username and password that should be used in the next `AUTH` or `HELLO` command.
+  ///
+  /// The server is `None` when the client re-authenticates all existing connections.
+  async fn fetch(&self, server: Option<&Server>) -> Result<(Option<String>, Option<String>), RedisError>;
+
+  /// An optional interval on which the client will call [fetch](Self::fetch) and send `AUTH` to the primary
+  /// node(s) on existing connections.
+  ///
+  /// The `AUTH` command is queued like any other command, so in-flight commands are not interrupted.
+  ///
+  /// Default: `None`
+  fn refresh_interval(&self) -> Option<Duration> {
+    None
+  }
+}
+
 /// Configuration options for a `RedisClient`.
 #[derive(Clone, Debug)]
 pub struct RedisConfig {
@@ -560,27 +775,34 @@ pub struct RedisConfig {
   /// difficult.
   ///
   /// Default: `true`
-  pub fail_fast: bool,
+  pub fail_fast:           bool,
   /// The default behavior of the client when a command is sent while the connection is blocked on a blocking
   /// command.
   ///
   /// Setting this to anything other than `Blocking::Block` incurs a small performance penalty.
   ///
   /// Default: `Blocking::Block`
-  pub blocking:  Blocking,
+  pub blocking:            Blocking,
   /// An optional ACL username for the client to use when authenticating. If ACL rules are not configured this should
   /// be `None`.
   ///
   /// Default: `None`
-  pub username:  Option<String>,
+  pub username:            Option<String>,
   /// An optional password for the client to use when authenticating.
   ///
   /// Default: `None`
-  pub password:  Option<String>,
+  pub password:            Option<String>,
+  /// An optional credential provider. If provided this will be used instead of the `username` and `password`
+  /// fields.
+  ///
+  /// Default: `None`
+  #[cfg(feature = "credential-provider")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "credential-provider")))]
+  pub credential_provider: Option<Arc<dyn CredentialProvider>>,
   /// Connection configuration for the server(s).
   ///
   /// Default: `Centralized(localhost, 6379)`
-  pub server:    ServerConfig,
+  pub server:              ServerConfig,
   /// The protocol version to use when communicating with the server(s).
   ///
   /// If RESP3 is specified the client will automatically use `HELLO` when authenticating. **This requires Redis
@@ -591,7 +813,7 @@ pub struct RedisConfig {
   /// has a slightly different type system than RESP2.
   ///
   /// Default: `RESP2`
-  pub version:   RespVersion,
+  pub version:             RespVersion,
   /// An optional database number that the client will automatically `SELECT` after connecting or reconnecting.
   ///
   /// It is recommended that callers use this field instead of putting a `select()` call inside the `on_reconnect`
@@ -599,23 +821,23 @@ pub struct RedisConfig {
   /// the `on_reconnect` block.
   ///
   /// Default: `None`
-  pub database:  Option<u8>,
+  pub database:            Option<u8>,
   /// TLS configuration options.
   ///
   /// Default: `None`
   #[cfg(any(feature = "enable-native-tls", feature = "enable-rustls"))]
   #[cfg_attr(docsrs, doc(cfg(any(feature = "enable-native-tls", feature = "enable-rustls"))))]
-  pub tls:       Option<TlsConfig>,
+  pub tls:                 Option<TlsConfig>,
   /// Tracing configuration options.
   #[cfg(feature = "partial-tracing")]
   #[cfg_attr(docsrs, doc(cfg(feature = "partial-tracing")))]
-  pub tracing:   TracingConfig,
+  pub tracing:             TracingConfig,
   /// An optional [mocking layer](crate::mocks) to intercept and process commands.
   ///
   /// Default: `None`
   #[cfg(feature = "mocks")]
   #[cfg_attr(docsrs, doc(cfg(feature = "mocks")))]
-  pub mocks:     Option<Arc<dyn Mocks>>,
+  pub mocks:               Option<Arc<dyn Mocks>>,
 }
 
 impl PartialEq for RedisConfig {
@@ -639,6 +861,8 @@ impl Default for RedisConfig {
       blocking: Blocking::default(),
       username: None,
       password: None,
+      #[cfg(feature = "credential-provider")]
+      credential_provider: None,
       server: ServerConfig::default(),
       version: RespVersion::RESP2,
       database: None,
@@ -1111,20 +1335,27 @@ pub struct SentinelConfig {
   /// The hostname for the sentinel node.
   ///
   /// Default: `127.0.0.1`
-  pub host:     String,
+  pub host:                String,
   /// The port on which the sentinel node is listening.
   ///
   /// Default: `26379`
-  pub port:     u16,
+  pub port:                u16,
   /// An optional ACL username for the client to use when authenticating. If ACL rules are not configured this should
   /// be `None`.
   ///
   /// Default: `None`
-  pub username: Option<String>,
+  pub username:            Option<String>,
   /// An optional password for the client to use when authenticating.
   ///
   /// Default: `None`
-  pub password: Option<String>,
+  pub password:            Option<String>,
+  /// An optional credential provider. If provided this will be used instead of the `username` and `password`
+  /// fields.
+  ///
+  /// Default: `None`
+  #[cfg(feature = "credential-provider")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "credential-provider")))]
+  pub credential_provider: Option<Arc<dyn CredentialProvider>>,
   /// TLS configuration fields. If `None` the connection will not use TLS.
   ///
   /// See the `tls` examples on Github for more information.
@@ -1132,13 +1363,13 @@ pub struct SentinelConfig {
   /// Default: `None`
   #[cfg(any(feature = "enable-native-tls", feature = "enable-rustls"))]
   #[cfg_attr(docsrs, doc(cfg(any(feature = "enable-native-tls", feature = "enable-rustls"))))]
-  pub tls:      Option<TlsConfig>,
+  pub tls:                 Option<TlsConfig>,
   /// Whether or not to enable tracing for this client.
   ///
   /// Default: `false`
   #[cfg(feature = "partial-tracing")]
   #[cfg_attr(docsrs, doc(cfg(feature = "partial-tracing")))]
-  pub tracing:  TracingConfig,
+  pub tracing:             TracingConfig,
 }
 
 #[cfg(feature = "sentinel-client")]
@@ -1149,6 +1380,8 @@ impl Default for SentinelConfig {
       port: 26379,
       username: None,
       password: None,
+      #[cfg(feature = "credential-provider")]
+      credential_provider: None,
       #[cfg(any(feature = "enable-native-tls", feature = "enable-rustls"))]
       tls: None,
       #[cfg(feature = "partial-tracing")]
@@ -1170,6 +1403,8 @@ impl From<SentinelConfig> for RedisConfig {
       blocking: Blocking::Block,
       username: config.username,
       password: config.password,
+      #[cfg(feature = "credential-provider")]
+      credential_provider: config.credential_provider,
       version: RespVersion::RESP2,
       #[cfg(any(feature = "enable-native-tls", feature = "enable-rustls"))]
       tls: config.tls,
@@ -1236,6 +1471,16 @@ pub struct Options {
   #[cfg(feature = "client-tracking")]
   #[cfg_attr(docsrs, doc(cfg(feature = "client-tracking")))]
   pub caching:          Option<bool>,
+  /// Whether to split `MGET`, `MSET`, `DEL`, `EXISTS`, `UNLINK`, and `TOUCH` into one command per hash slot when
+  /// connected to a cluster.
+  ///
+  /// The per-slot commands are sent concurrently and the responses are combined in the original key order. If any
+  /// of the per-slot commands fail the caller will receive a `Cluster` error with the failed keys available via
+  /// [slot_errors](crate::error::RedisError::slot_errors). Split commands are not atomic, and this has no effect on
+  /// pipelines or transactions.
+  ///
+  /// Default: the [client's setting](crate::types::ConnectionConfig::split_cross_slot)
+  pub split_cross_slot: Option<bool>,
 }
 
 impl Options {
@@ -1260,6 +1505,9 @@ impl Options {
     if let Some(val) = other.caching {
       self.caching = Some(val);
     }
+    if let Some(val) = other.split_cross_slot {
+      self.split_cross_slot = Some(val);
+    }
 
     self
   }
@@ -1276,6 +1524,7 @@ impl Options {
       fail_fast:                                   cmd.fail_fast,
       #[cfg(feature = "client-tracking")]
       caching:                                     cmd.caching.clone(),
+      split_cross_slot:                            None,
     }
   }
 
@@ -0,0 +1,71 @@
+use crate::{error::RedisError, types::RedisValue, utils};
+use bytes_utils::Str;
+
+/// Options for the [hsetex](https://redis.io/commands/hsetex) command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum HSetExOptions {
+  /// Only set the fields if none of them already exist.
+  FNX,
+  /// Only set the fields if all of them already exist.
+  FXX,
+}
+
+impl HSetExOptions {
+  pub(crate) fn to_str(&self) -> Str {
+    utils::static_str(match *self {
+      HSetExOptions::FNX => "FNX",
+      HSetExOptions::FXX => "FXX",
+    })
+  }
+}
+
+/// The per-field result of the `HEXPIRE` family of commands.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FieldExpireResult {
+  /// The field does not exist, or the key does not exist.
+  NoField,
+  /// The expiration was not set because the `NX`, `XX`, `GT`, or `LT` condition was not met.
+  ConditionNotMet,
+  /// The expiration was set or updated.
+  Set,
+  /// The field was deleted because the expiration was in the past.
+  Deleted,
+}
+
+impl TryFrom<RedisValue> for FieldExpireResult {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    match value.as_i64() {
+      Some(-2) => Ok(FieldExpireResult::NoField),
+      Some(0) => Ok(FieldExpireResult::ConditionNotMet),
+      Some(1) => Ok(FieldExpireResult::Set),
+      Some(2) => Ok(FieldExpireResult::Deleted),
+      _ => Err(RedisError::new_parse("Invalid field expiration result.")),
+    }
+  }
+}
+
+/// The per-field result of the [hpersist](https://redis.io/commands/hpersist) command.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FieldPersistResult {
+  /// The field does not exist, or the key does not exist.
+  NoField,
+  /// The field exists but has no associated expiration.
+  NoExpiration,
+  /// The expiration was removed.
+  Persisted,
+}
+
+impl TryFrom<RedisValue> for FieldPersistResult {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    match value.as_i64() {
+      Some(-2) => Ok(FieldPersistResult::NoField),
+      Some(-1) => Ok(FieldPersistResult::NoExpiration),
+      Some(1) => Ok(FieldPersistResult::Persisted),
+      _ => Err(RedisError::new_parse("Invalid field persist result.")),
+    }
+  }
+}
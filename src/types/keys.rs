@@ -0,0 +1,207 @@
+use crate::{
+  error::RedisError,
+  types::{RedisKey, RedisValue, SortOrder},
+  utils,
+};
+use bytes::Bytes;
+use bytes_utils::Str;
+use std::convert::TryFrom;
+
+/// The type of value stored at a key, as returned by the [type](https://redis.io/commands/type) command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeyType {
+  String,
+  List,
+  Set,
+  ZSet,
+  Hash,
+  Stream,
+  /// The key does not exist.
+  None,
+  /// A type registered by a module, such as `ReJSON-RL` or `MBbloom--`.
+  Unknown(Str),
+}
+
+impl KeyType {
+  pub(crate) fn from_str(s: Str) -> Self {
+    match &*s {
+      "string" => KeyType::String,
+      "list" => KeyType::List,
+      "set" => KeyType::Set,
+      "zset" => KeyType::ZSet,
+      "hash" => KeyType::Hash,
+      "stream" => KeyType::Stream,
+      "none" => KeyType::None,
+      _ => KeyType::Unknown(s),
+    }
+  }
+}
+
+impl TryFrom<RedisValue> for KeyType {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    value
+      .into_bytes_str()
+      .map(KeyType::from_str)
+      .ok_or(RedisError::new_parse("Expected string key type."))
+  }
+}
+
+/// The internal encoding used to store a value, as returned by the [object encoding](https://redis.io/commands/object-encoding) command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ObjectEncoding {
+  Raw,
+  Int,
+  EmbStr,
+  ListPack,
+  QuickList,
+  HashTable,
+  IntSet,
+  SkipList,
+  ZipList,
+  LinkedList,
+  ZipMap,
+  Stream,
+  /// An encoding not known to this client.
+  Unknown(Str),
+}
+
+impl ObjectEncoding {
+  pub(crate) fn from_str(s: Str) -> Self {
+    match &*s {
+      "raw" => ObjectEncoding::Raw,
+      "int" => ObjectEncoding::Int,
+      "embstr" => ObjectEncoding::EmbStr,
+      "listpack" => ObjectEncoding::ListPack,
+      "quicklist" => ObjectEncoding::QuickList,
+      "hashtable" => ObjectEncoding::HashTable,
+      "intset" => ObjectEncoding::IntSet,
+      "skiplist" => ObjectEncoding::SkipList,
+      "ziplist" => ObjectEncoding::ZipList,
+      "linkedlist" => ObjectEncoding::LinkedList,
+      "zipmap" => ObjectEncoding::ZipMap,
+      "stream" => ObjectEncoding::Stream,
+      _ => ObjectEncoding::Unknown(s),
+    }
+  }
+}
+
+impl TryFrom<RedisValue> for ObjectEncoding {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    value
+      .into_bytes_str()
+      .map(ObjectEncoding::from_str)
+      .ok_or(RedisError::new_parse("Expected string object encoding."))
+  }
+}
+
+/// Arguments for the [sort](https://redis.io/commands/sort) and [sort_ro](https://redis.io/commands/sort_ro) commands.
+///
+/// ```rust
+/// # use fred::types::{SortOptions, SortOrder};
+/// let options = SortOptions::default()
+///   .by("weight_*")
+///   .limit(0, 10)
+///   .get("#")
+///   .get("object_*")
+///   .order(SortOrder::Desc)
+///   .alpha();
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SortOptions {
+  /// An external key pattern used to weight the elements.
+  pub by:    Option<Str>,
+  /// An offset and count used to limit the results.
+  pub limit: Option<(i64, i64)>,
+  /// External key patterns used to read the returned values.
+  pub get:   Vec<Str>,
+  pub order: Option<SortOrder>,
+  /// Sort elements lexicographically rather than numerically.
+  pub alpha: bool,
+  /// Store the results in the provided key rather than returning them.
+  ///
+  /// Not supported with `SORT_RO`.
+  pub store: Option<RedisKey>,
+}
+
+impl SortOptions {
+  /// Set the `BY` pattern.
+  pub fn by<S: Into<Str>>(mut self, pattern: S) -> Self {
+    self.by = Some(pattern.into());
+    self
+  }
+
+  /// Set the `LIMIT` offset and count.
+  pub fn limit(mut self, offset: i64, count: i64) -> Self {
+    self.limit = Some((offset, count));
+    self
+  }
+
+  /// Add a `GET` pattern.
+  pub fn get<S: Into<Str>>(mut self, pattern: S) -> Self {
+    self.get.push(pattern.into());
+    self
+  }
+
+  /// Set the sort order.
+  pub fn order(mut self, order: SortOrder) -> Self {
+    self.order = Some(order);
+    self
+  }
+
+  /// Sort elements lexicographically.
+  pub fn alpha(mut self) -> Self {
+    self.alpha = true;
+    self
+  }
+
+  /// Set the `STORE` destination key.
+  pub fn store<K: Into<RedisKey>>(mut self, key: K) -> Self {
+    self.store = Some(key.into());
+    self
+  }
+}
+
+/// Authentication arguments for the [migrate](https://redis.io/commands/migrate) command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MigrateAuth {
+  /// Authenticate with the `default` user.
+  Password(Str),
+  /// Authenticate with the provided username and password.
+  UsernamePassword { username: Str, password: Str },
+}
+
+impl MigrateAuth {
+  pub(crate) fn into_args(self) -> Vec<RedisValue> {
+    match self {
+      MigrateAuth::Password(password) => vec![utils::static_str("AUTH").into(), password.into()],
+      MigrateAuth::UsernamePassword { username, password } => {
+        vec![utils::static_str("AUTH2").into(), username.into(), password.into()]
+      },
+    }
+  }
+}
+
+/// Optional arguments for the [migrate](https://redis.io/commands/migrate) command.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MigrateOptions {
+  /// Do not remove the keys from the source instance.
+  pub copy:    bool,
+  /// Replace existing keys on the destination instance.
+  pub replace: bool,
+  pub auth:    Option<MigrateAuth>,
+}
+
+/// A key serialized with the [dump](https://redis.io/commands/dump) command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyDump {
+  /// The name of the key.
+  pub key:   RedisKey,
+  /// The remaining time to live in milliseconds, or `None` if the key does not expire.
+  pub ttl:   Option<i64>,
+  /// The serialized value, in the format used by [restore](https://redis.io/commands/restore).
+  pub value: Bytes,
+}
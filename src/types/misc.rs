@@ -38,6 +38,281 @@ pub struct KeyspaceEvent {
   pub key:       RedisKey,
 }
 
+impl KeyspaceEvent {
+  /// Read the typed operation that triggered the event.
+  pub fn kind(&self) -> KeyspaceOperation {
+    KeyspaceOperation::from_str(&self.operation)
+  }
+}
+
+/// The operation that triggered a [keyspace notification](https://redis.io/docs/manual/keyspace-notifications/).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum KeyspaceOperation {
+  Del,
+  RenameFrom,
+  RenameTo,
+  MoveFrom,
+  MoveTo,
+  CopyTo,
+  Restore,
+  Expire,
+  Persist,
+  Expired,
+  Evicted,
+  New,
+  KeyMiss,
+  SortStore,
+  Set,
+  SetRange,
+  IncrBy,
+  IncrByFloat,
+  Append,
+  Lpush,
+  Rpush,
+  Lpop,
+  Rpop,
+  Linsert,
+  Lset,
+  Lrem,
+  Ltrim,
+  Hset,
+  Hincrby,
+  Hincrbyfloat,
+  Hdel,
+  Sadd,
+  Srem,
+  Spop,
+  SinterStore,
+  SunionStore,
+  SdiffStore,
+  Zadd,
+  Zincr,
+  Zrem,
+  ZremRangeByScore,
+  ZremRangeByRank,
+  ZremRangeByLex,
+  ZinterStore,
+  ZunionStore,
+  ZdiffStore,
+  Xadd,
+  Xtrim,
+  Xdel,
+  XgroupCreate,
+  XgroupCreateConsumer,
+  XgroupDelConsumer,
+  XgroupDestroy,
+  XgroupSetId,
+  XsetId,
+  /// Any other event, such as events emitted by modules.
+  Other(String),
+}
+
+impl KeyspaceOperation {
+  pub(crate) fn from_str(s: &str) -> Self {
+    match s {
+      "del" => KeyspaceOperation::Del,
+      "rename_from" => KeyspaceOperation::RenameFrom,
+      "rename_to" => KeyspaceOperation::RenameTo,
+      "move_from" => KeyspaceOperation::MoveFrom,
+      "move_to" => KeyspaceOperation::MoveTo,
+      "copy_to" => KeyspaceOperation::CopyTo,
+      "restore" => KeyspaceOperation::Restore,
+      "expire" => KeyspaceOperation::Expire,
+      "persist" => KeyspaceOperation::Persist,
+      "expired" => KeyspaceOperation::Expired,
+      "evicted" => KeyspaceOperation::Evicted,
+      "new" => KeyspaceOperation::New,
+      "keymiss" => KeyspaceOperation::KeyMiss,
+      "sortstore" => KeyspaceOperation::SortStore,
+      "set" => KeyspaceOperation::Set,
+      "setrange" => KeyspaceOperation::SetRange,
+      "incrby" => KeyspaceOperation::IncrBy,
+      "incrbyfloat" => KeyspaceOperation::IncrByFloat,
+      "append" => KeyspaceOperation::Append,
+      "lpush" => KeyspaceOperation::Lpush,
+      "rpush" => KeyspaceOperation::Rpush,
+      "lpop" => KeyspaceOperation::Lpop,
+      "rpop" => KeyspaceOperation::Rpop,
+      "linsert" => KeyspaceOperation::Linsert,
+      "lset" => KeyspaceOperation::Lset,
+      "lrem" => KeyspaceOperation::Lrem,
+      "ltrim" => KeyspaceOperation::Ltrim,
+      "hset" => KeyspaceOperation::Hset,
+      "hincrby" => KeyspaceOperation::Hincrby,
+      "hincrbyfloat" => KeyspaceOperation::Hincrbyfloat,
+      "hdel" => KeyspaceOperation::Hdel,
+      "sadd" => KeyspaceOperation::Sadd,
+      "srem" => KeyspaceOperation::Srem,
+      "spop" => KeyspaceOperation::Spop,
+      "sinterstore" => KeyspaceOperation::SinterStore,
+      "sunionstore" => KeyspaceOperation::SunionStore,
+      "sdiffstore" => KeyspaceOperation::SdiffStore,
+      "zadd" => KeyspaceOperation::Zadd,
+      "zincr" => KeyspaceOperation::Zincr,
+      "zrem" => KeyspaceOperation::Zrem,
+      "zremrangebyscore" => KeyspaceOperation::ZremRangeByScore,
+      "zremrangebyrank" => KeyspaceOperation::ZremRangeByRank,
+      "zremrangebylex" => KeyspaceOperation::ZremRangeByLex,
+      "zinterstore" => KeyspaceOperation::ZinterStore,
+      "zunionstore" => KeyspaceOperation::ZunionStore,
+      "zdiffstore" => KeyspaceOperation::ZdiffStore,
+      "xadd" => KeyspaceOperation::Xadd,
+      "xtrim" => KeyspaceOperation::Xtrim,
+      "xdel" => KeyspaceOperation::Xdel,
+      "xgroup-create" => KeyspaceOperation::XgroupCreate,
+      "xgroup-createconsumer" => KeyspaceOperation::XgroupCreateConsumer,
+      "xgroup-delconsumer" => KeyspaceOperation::XgroupDelConsumer,
+      "xgroup-destroy" => KeyspaceOperation::XgroupDestroy,
+      "xgroup-setid" => KeyspaceOperation::XgroupSetId,
+      "xsetid" => KeyspaceOperation::XsetId,
+      _ => KeyspaceOperation::Other(s.to_owned()),
+    }
+  }
+
+  pub(crate) fn to_str(&self) -> &str {
+    match self {
+      KeyspaceOperation::Del => "del",
+      KeyspaceOperation::RenameFrom => "rename_from",
+      KeyspaceOperation::RenameTo => "rename_to",
+      KeyspaceOperation::MoveFrom => "move_from",
+      KeyspaceOperation::MoveTo => "move_to",
+      KeyspaceOperation::CopyTo => "copy_to",
+      KeyspaceOperation::Restore => "restore",
+      KeyspaceOperation::Expire => "expire",
+      KeyspaceOperation::Persist => "persist",
+      KeyspaceOperation::Expired => "expired",
+      KeyspaceOperation::Evicted => "evicted",
+      KeyspaceOperation::New => "new",
+      KeyspaceOperation::KeyMiss => "keymiss",
+      KeyspaceOperation::SortStore => "sortstore",
+      KeyspaceOperation::Set => "set",
+      KeyspaceOperation::SetRange => "setrange",
+      KeyspaceOperation::IncrBy => "incrby",
+      KeyspaceOperation::IncrByFloat => "incrbyfloat",
+      KeyspaceOperation::Append => "append",
+      KeyspaceOperation::Lpush => "lpush",
+      KeyspaceOperation::Rpush => "rpush",
+      KeyspaceOperation::Lpop => "lpop",
+      KeyspaceOperation::Rpop => "rpop",
+      KeyspaceOperation::Linsert => "linsert",
+      KeyspaceOperation::Lset => "lset",
+      KeyspaceOperation::Lrem => "lrem",
+      KeyspaceOperation::Ltrim => "ltrim",
+      KeyspaceOperation::Hset => "hset",
+      KeyspaceOperation::Hincrby => "hincrby",
+      KeyspaceOperation::Hincrbyfloat => "hincrbyfloat",
+      KeyspaceOperation::Hdel => "hdel",
+      KeyspaceOperation::Sadd => "sadd",
+      KeyspaceOperation::Srem => "srem",
+      KeyspaceOperation::Spop => "spop",
+      KeyspaceOperation::SinterStore => "sinterstore",
+      KeyspaceOperation::SunionStore => "sunionstore",
+      KeyspaceOperation::SdiffStore => "sdiffstore",
+      KeyspaceOperation::Zadd => "zadd",
+      KeyspaceOperation::Zincr => "zincr",
+      KeyspaceOperation::Zrem => "zrem",
+      KeyspaceOperation::ZremRangeByScore => "zremrangebyscore",
+      KeyspaceOperation::ZremRangeByRank => "zremrangebyrank",
+      KeyspaceOperation::ZremRangeByLex => "zremrangebylex",
+      KeyspaceOperation::ZinterStore => "zinterstore",
+      KeyspaceOperation::ZunionStore => "zunionstore",
+      KeyspaceOperation::ZdiffStore => "zdiffstore",
+      KeyspaceOperation::Xadd => "xadd",
+      KeyspaceOperation::Xtrim => "xtrim",
+      KeyspaceOperation::Xdel => "xdel",
+      KeyspaceOperation::XgroupCreate => "xgroup-create",
+      KeyspaceOperation::XgroupCreateConsumer => "xgroup-createconsumer",
+      KeyspaceOperation::XgroupDelConsumer => "xgroup-delconsumer",
+      KeyspaceOperation::XgroupDestroy => "xgroup-destroy",
+      KeyspaceOperation::XgroupSetId => "xgroup-setid",
+      KeyspaceOperation::XsetId => "xsetid",
+      KeyspaceOperation::Other(ref s) => s,
+    }
+  }
+}
+
+/// Flags for the `notify-keyspace-events` configuration parameter.
+///
+/// <https://redis.io/docs/manual/keyspace-notifications/#configuration>
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct KeyspaceEventFlags {
+  /// Keyspace events, published with the `__keyspace@<db>__` prefix. (`K`)
+  pub keyspace:   bool,
+  /// Keyevent events, published with the `__keyevent@<db>__` prefix. (`E`)
+  pub keyevent:   bool,
+  /// Generic commands such as `DEL`, `EXPIRE`, `RENAME`, etc. (`g`)
+  pub generic:    bool,
+  /// String commands. (`$`)
+  pub string:     bool,
+  /// List commands. (`l`)
+  pub list:       bool,
+  /// Set commands. (`s`)
+  pub set:        bool,
+  /// Hash commands. (`h`)
+  pub hash:       bool,
+  /// Sorted set commands. (`z`)
+  pub sorted_set: bool,
+  /// Expired events. (`x`)
+  pub expired:    bool,
+  /// Evicted events. (`e`)
+  pub evicted:    bool,
+  /// Stream commands. (`t`)
+  pub stream:     bool,
+  /// Key miss events. (`m`)
+  pub key_miss:   bool,
+  /// New key events. (`n`)
+  pub new:        bool,
+  /// Module key type events. (`d`)
+  pub module:     bool,
+}
+
+impl KeyspaceEventFlags {
+  /// Enable keyspace and keyevent notifications for all the event classes included in the `A` alias.
+  pub fn all() -> Self {
+    KeyspaceEventFlags {
+      keyspace:   true,
+      keyevent:   true,
+      generic:    true,
+      string:     true,
+      list:       true,
+      set:        true,
+      hash:       true,
+      sorted_set: true,
+      expired:    true,
+      evicted:    true,
+      stream:     true,
+      module:     true,
+      key_miss:   false,
+      new:        false,
+    }
+  }
+
+  pub(crate) fn to_str(&self) -> Str {
+    let flags = [
+      (self.keyspace, 'K'),
+      (self.keyevent, 'E'),
+      (self.generic, 'g'),
+      (self.string, '$'),
+      (self.list, 'l'),
+      (self.set, 's'),
+      (self.hash, 'h'),
+      (self.sorted_set, 'z'),
+      (self.expired, 'x'),
+      (self.evicted, 'e'),
+      (self.stream, 't'),
+      (self.key_miss, 'm'),
+      (self.new, 'n'),
+      (self.module, 'd'),
+    ];
+
+    flags
+      .into_iter()
+      .filter_map(|(enabled, flag)| if enabled { Some(flag) } else { None })
+      .collect::<String>()
+      .into()
+  }
+}
+
 /// Aggregate options for the [zinterstore](https://redis.io/commands/zinterstore) (and related) commands.
 pub enum AggregateOptions {
   Sum,
@@ -150,6 +425,40 @@ pub enum ClusterStateChange {
   Rebalance,
 }
 
+/// An enum describing changes to the set of clients in a [RedisPool](crate::clients::RedisPool), or events from
+/// individual clients in the pool.
+///
+/// Each variant includes the ID of the client that emitted the event.
+///
+/// See [pool_event_rx](crate::clients::RedisPool::pool_event_rx) for more information.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PoolEvent {
+  /// A client was connected and added to the pool.
+  Add(Str),
+  /// A client was removed from the pool and disconnected.
+  Remove(Str),
+  /// A client stayed disconnected for too long and was replaced with a new client.
+  ///
+  /// Contains the IDs of the old and new clients.
+  Replace(Str, Str),
+  /// A client received a protocol or connection error.
+  ///
+  /// See [error_rx](crate::interfaces::EventInterface::error_rx) for more information.
+  Error(Str, RedisError),
+  /// A client connected or reconnected to a server.
+  ///
+  /// See [reconnect_rx](crate::interfaces::EventInterface::reconnect_rx) for more information.
+  Reconnect(Str, Server),
+  /// A client detected a change in the cluster state.
+  ///
+  /// See [cluster_change_rx](crate::interfaces::EventInterface::cluster_change_rx) for more information.
+  ClusterChange(Str, Vec<ClusterStateChange>),
+  /// A client detected an unresponsive connection.
+  ///
+  /// See [unresponsive_rx](crate::interfaces::EventInterface::unresponsive_rx) for more information.
+  Unresponsive(Str, Server),
+}
+
 /// Options for the [set](https://redis.io/commands/set) command.
 ///
 /// <https://redis.io/commands/set>
@@ -197,6 +506,59 @@ impl Expiration {
   }
 }
 
+/// Expiration options for the `GETEX` family of commands.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum GetExpiration {
+  /// Expiration in seconds.
+  EX(i64),
+  /// Expiration in milliseconds.
+  PX(i64),
+  /// Expiration time, in seconds.
+  EXAT(i64),
+  /// Expiration time, in milliseconds.
+  PXAT(i64),
+  /// Remove the existing TTL.
+  PERSIST,
+}
+
+impl GetExpiration {
+  pub(crate) fn into_args(self) -> (Str, Option<i64>) {
+    let (prefix, value) = match self {
+      GetExpiration::EX(i) => ("EX", Some(i)),
+      GetExpiration::PX(i) => ("PX", Some(i)),
+      GetExpiration::EXAT(i) => ("EXAT", Some(i)),
+      GetExpiration::PXAT(i) => ("PXAT", Some(i)),
+      GetExpiration::PERSIST => ("PERSIST", None),
+    };
+
+    (utils::static_str(prefix), value)
+  }
+}
+
+/// Conditions for the `EXPIRE` family of commands.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ExpireOptions {
+  /// Set the expiration only when there is no existing expiration.
+  NX,
+  /// Set the expiration only when there is an existing expiration.
+  XX,
+  /// Set the expiration only when the new expiration is greater than the current one.
+  GT,
+  /// Set the expiration only when the new expiration is less than the current one.
+  LT,
+}
+
+impl ExpireOptions {
+  pub(crate) fn to_str(&self) -> Str {
+    utils::static_str(match *self {
+      ExpireOptions::NX => "NX",
+      ExpireOptions::XX => "XX",
+      ExpireOptions::GT => "GT",
+      ExpireOptions::LT => "LT",
+    })
+  }
+}
+
 /// The state of the underlying connection to the Redis server.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ClientState {
@@ -527,6 +889,21 @@ impl SentinelFailureKind {
   }
 }
 
+/// The behavior of a [subscribe_stream](crate::clients::SubscriberClient::subscribe_stream) when its buffer is full.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg(feature = "subscriber-client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subscriber-client")))]
+pub enum LagPolicy {
+  /// Drop the oldest buffered message to make room for the new message.
+  DropOldest,
+  /// Drop the new message and emit a `Backpressure` error on the stream before the next buffered message.
+  Error,
+  /// Wait for the stream to read a message before reading more frames from the connection.
+  ///
+  /// This will delay the responses to any other commands on the same connection until the stream is read.
+  Block,
+}
+
 /// The sort order for redis commands that take or return a sorted list.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SortOrder {
@@ -4,37 +4,59 @@ pub use redis_protocol::resp3::types::{Frame, RespVersion};
 use tokio::task::JoinHandle;
 
 mod args;
+mod bitmaps;
 mod builder;
 mod client;
 mod cluster;
 mod config;
 mod from_tuple;
 mod geo;
+mod hashes;
+mod keys;
 mod lists;
 mod misc;
 mod multiple;
+mod pool;
+#[cfg(feature = "redis-bloom")]
+mod redis_bloom;
+#[cfg(feature = "redis-search")]
+mod redisearch;
 mod scan;
 mod scripts;
+mod server;
 mod sorted_sets;
 mod streams;
+mod strings;
 #[cfg(feature = "time-series")]
 mod timeseries;
 
 pub use args::*;
+pub use bitmaps::*;
 pub use builder::*;
 pub use client::*;
 pub use cluster::*;
 pub use config::*;
 pub use geo::*;
+pub use hashes::*;
+pub use keys::*;
 pub use lists::*;
 pub use misc::*;
 pub use multiple::*;
+pub use pool::*;
 pub use scan::*;
 pub use scripts::*;
 pub use semver::Version;
+pub use server::*;
 pub use sorted_sets::*;
 pub use streams::*;
+pub use strings::*;
 
+#[cfg(feature = "redis-bloom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+pub use redis_bloom::*;
+#[cfg(feature = "redis-search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+pub use redisearch::*;
 #[cfg(feature = "time-series")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time-series")))]
 pub use timeseries::*;
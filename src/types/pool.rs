@@ -0,0 +1,210 @@
+use crate::{clients::RedisClient, utils};
+use rand::Rng;
+use std::{
+  fmt::Debug,
+  sync::{atomic::AtomicUsize, Arc},
+};
+
+#[cfg(feature = "metrics")]
+use std::time::Instant;
+
+/// An interface used to select the client in a [RedisPool](crate::clients::RedisPool) that should run the next
+/// command.
+///
+/// See [set_routing_policy](crate::clients::RedisPool::set_routing_policy) for more information.
+pub trait PoolRoutingPolicy: Debug + Send + Sync + 'static {
+  /// Select the index of the client that should run the next command.
+  ///
+  /// The `clients` slice is never empty. Indexes outside the slice will wrap around.
+  fn select(&self, clients: &[RedisClient]) -> usize;
+
+  /// Whether the policy reads the decaying latency average of each client.
+  ///
+  /// Clients only sample this average while their pool uses a policy that returns `true`.
+  fn uses_latency(&self) -> bool {
+    false
+  }
+}
+
+/// Select clients in order, one after the other.
+///
+/// This is the default policy.
+#[derive(Debug, Default)]
+pub struct RoundRobin {
+  counter: Arc<AtomicUsize>,
+}
+
+impl PoolRoutingPolicy for RoundRobin {
+  fn select(&self, clients: &[RedisClient]) -> usize {
+    utils::incr_atomic(&self.counter) % clients.len()
+  }
+}
+
+/// Select the client with the fewest in-flight commands.
+///
+/// See [command_queue_len](crate::interfaces::MetricsInterface::command_queue_len) for more information.
+#[derive(Debug, Default)]
+pub struct LeastInFlight;
+
+impl PoolRoutingPolicy for LeastInFlight {
+  fn select(&self, clients: &[RedisClient]) -> usize {
+    clients
+      .iter()
+      .enumerate()
+      .min_by_key(|(_, client)| client.inner.counters.read_cmd_buffer_len())
+      .map(|(idx, _)| idx)
+      .unwrap_or(0)
+  }
+}
+
+/// Select two clients at random and use the one with fewer in-flight commands.
+///
+/// This avoids scanning every client on each command while still routing around busy connections.
+#[derive(Debug, Default)]
+pub struct PowerOfTwoChoices;
+
+impl PoolRoutingPolicy for PowerOfTwoChoices {
+  fn select(&self, clients: &[RedisClient]) -> usize {
+    if clients.len() < 2 {
+      return 0;
+    }
+
+    let mut rng = rand::thread_rng();
+    let (first, second) = (rng.gen_range(0 .. clients.len()), rng.gen_range(0 .. clients.len()));
+    if clients[second].inner.counters.read_cmd_buffer_len() < clients[first].inner.counters.read_cmd_buffer_len() {
+      second
+    } else {
+      first
+    }
+  }
+}
+
+/// Select the client with the lowest recent command latency, weighted by the number of in-flight commands.
+///
+/// Each client tracks an exponentially weighted moving average of its command latency that decays while the client
+/// is not used, so clients that were slow are eventually selected again. Clients without any latency samples are
+/// treated as if they had the average latency of the other clients.
+#[cfg(feature = "metrics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+#[derive(Debug, Default)]
+pub struct LowestLatency;
+
+#[cfg(feature = "metrics")]
+impl PoolRoutingPolicy for LowestLatency {
+  fn select(&self, clients: &[RedisClient]) -> usize {
+    let now = Instant::now();
+    let latencies: Vec<Option<f64>> = clients
+      .iter()
+      .map(|client| client.inner.latency_average.read().read(now))
+      .collect();
+    let known: Vec<f64> = latencies.iter().filter_map(|latency| *latency).collect();
+    let default = if known.is_empty() {
+      0.0
+    } else {
+      known.iter().sum::<f64>() / known.len() as f64
+    };
+
+    clients
+      .iter()
+      .zip(latencies)
+      .map(|(client, latency)| {
+        let in_flight = client.inner.counters.read_cmd_buffer_len();
+        (latency.unwrap_or(default) * (in_flight + 1) as f64, in_flight)
+      })
+      .enumerate()
+      .min_by(|(_, lhs), (_, rhs)| lhs.0.total_cmp(&rhs.0).then(lhs.1.cmp(&rhs.1)))
+      .map(|(idx, _)| idx)
+      .unwrap_or(0)
+  }
+
+  fn uses_latency(&self) -> bool {
+    true
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  #[cfg(feature = "metrics")]
+  use std::time::Duration;
+
+  fn clients_with_load(load: &[usize]) -> Vec<RedisClient> {
+    load
+      .iter()
+      .map(|count| {
+        let client = RedisClient::default();
+        for _ in 0 .. *count {
+          client.inner.counters.incr_cmd_buffer_len();
+        }
+        client
+      })
+      .collect()
+  }
+
+  #[test]
+  fn should_select_round_robin() {
+    let clients = clients_with_load(&[0, 0, 0]);
+    let policy = RoundRobin::default();
+
+    let selected: Vec<usize> = (0 .. 4).map(|_| policy.select(&clients)).collect();
+    assert_eq!(selected, vec![1, 2, 0, 1]);
+  }
+
+  #[test]
+  fn should_select_least_in_flight() {
+    let clients = clients_with_load(&[3, 1, 2]);
+    assert_eq!(LeastInFlight.select(&clients), 1);
+  }
+
+  #[test]
+  fn should_select_power_of_two_choices() {
+    // the busy client is only selected when both random choices pick it
+    let clients = clients_with_load(&[5, 0]);
+    let idle = (0 .. 1000).filter(|_| PowerOfTwoChoices.select(&clients) == 1).count();
+    assert!(idle > 600);
+
+    let clients = clients_with_load(&[5]);
+    assert_eq!(PowerOfTwoChoices.select(&clients), 0);
+  }
+
+  #[cfg(feature = "metrics")]
+  fn clients_with_latency(latency: &[Option<f64>], sampled: Instant) -> Vec<RedisClient> {
+    latency
+      .iter()
+      .map(|latency| {
+        let client = RedisClient::default();
+        if let Some(latency) = latency {
+          client.inner.latency_average.write().sample(*latency, sampled);
+        }
+        client
+      })
+      .collect()
+  }
+
+  #[test]
+  #[cfg(feature = "metrics")]
+  fn should_select_lowest_latency_with_cold_clients() {
+    let clients = clients_with_latency(&[Some(1.0), None, Some(10.0)], Instant::now());
+    assert_eq!(LowestLatency.select(&clients), 0);
+
+    // clients without samples are spread by in-flight commands
+    let clients = clients_with_latency(&[None, None, None], Instant::now());
+    clients[0].inner.counters.incr_cmd_buffer_len();
+    assert_eq!(LowestLatency.select(&clients), 1);
+  }
+
+  #[test]
+  #[cfg(feature = "metrics")]
+  fn should_select_lowest_latency_after_recovery() {
+    let clients = clients_with_latency(&[Some(5.0), Some(100.0)], Instant::now());
+    assert_eq!(LowestLatency.select(&clients), 0);
+
+    // the slow client's latency decays while it is not used
+    let clients = clients_with_latency(&[None, Some(100.0)], Instant::now() - Duration::from_secs(10));
+    clients[0].inner.latency_average.write().sample(5.0, Instant::now());
+    assert_eq!(LowestLatency.select(&clients), 1);
+
+    clients[1].inner.latency_average.write().sample(100.0, Instant::now());
+    assert_eq!(LowestLatency.select(&clients), 0);
+  }
+}
@@ -0,0 +1,57 @@
+use crate::utils;
+use bytes_utils::Str;
+
+/// A single field to read with `BF.INFO`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BfInfoField {
+  Capacity,
+  Size,
+  Filters,
+  Items,
+  Expansion,
+}
+
+impl BfInfoField {
+  pub(crate) fn to_str(&self) -> Str {
+    utils::static_str(match *self {
+      BfInfoField::Capacity => "CAPACITY",
+      BfInfoField::Size => "SIZE",
+      BfInfoField::Filters => "FILTERS",
+      BfInfoField::Items => "ITEMS",
+      BfInfoField::Expansion => "EXPANSION",
+    })
+  }
+}
+
+/// Arguments used to create a bloom filter with `BF.INSERT`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BfInsertOptions {
+  pub capacity:   Option<u64>,
+  pub error:      Option<f64>,
+  pub expansion:  Option<u64>,
+  pub nocreate:   bool,
+  pub nonscaling: bool,
+}
+
+/// Arguments used to create a cuckoo filter with `CF.RESERVE`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CfReserveOptions {
+  pub bucket_size:    Option<u64>,
+  pub max_iterations: Option<u64>,
+  pub expansion:      Option<u64>,
+}
+
+/// Optional sketch dimensions used with `TOPK.RESERVE`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TopKDimensions {
+  /// The number of counters in each array.
+  pub width: u64,
+  /// The number of arrays.
+  pub depth: u64,
+  /// The probability of reducing a counter in an occupied bucket.
+  pub decay: f64,
+}
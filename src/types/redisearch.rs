@@ -0,0 +1,1070 @@
+use crate::{
+  error::RedisError,
+  types::{GeoPosition, GeoUnit, RedisKey, RedisValue, SortOrder, StringOrNumber},
+  utils,
+};
+use bytes::Bytes;
+use bytes_utils::Str;
+use std::collections::HashMap;
+
+/// The attribute name used for the distance score in KNN queries.
+pub(crate) static KNN_SCORE_FIELD: &str = "__knn_score";
+
+/// The type of data structure indexed by `FT.CREATE`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IndexKind {
+  Hash,
+  JSON,
+}
+
+impl IndexKind {
+  pub(crate) fn to_str(&self) -> Str {
+    utils::static_str(match *self {
+      IndexKind::Hash => "HASH",
+      IndexKind::JSON => "JSON",
+    })
+  }
+}
+
+/// The field type and field-specific arguments for a schema attribute used in `FT.CREATE` and `FT.ALTER`.
+///
+/// <https://redis.io/docs/interact/search-and-query/basic-constructs/field-and-type-options/>
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SearchSchemaKind {
+  Text {
+    sortable:       bool,
+    unf:            bool,
+    nostem:         bool,
+    phonetic:       Option<Str>,
+    weight:         Option<f64>,
+    withsuffixtrie: bool,
+    noindex:        bool,
+  },
+  Tag {
+    sortable:       bool,
+    unf:            bool,
+    separator:      Option<char>,
+    casesensitive:  bool,
+    withsuffixtrie: bool,
+    noindex:        bool,
+  },
+  Numeric {
+    sortable: bool,
+    unf:      bool,
+    noindex:  bool,
+  },
+  Geo {
+    sortable: bool,
+    unf:      bool,
+    noindex:  bool,
+  },
+  Vector(VectorField),
+  /// A field type not covered by the other variants, followed by any field arguments.
+  Custom {
+    name:      Str,
+    arguments: Vec<RedisValue>,
+  },
+}
+
+impl SearchSchemaKind {
+  pub(crate) fn args_len(&self) -> usize {
+    match *self {
+      SearchSchemaKind::Text {
+        ref weight,
+        ref phonetic,
+        ..
+      } => {
+        let mut count = 7;
+        if weight.is_some() {
+          count += 1;
+        }
+        if phonetic.is_some() {
+          count += 1;
+        }
+        count
+      },
+      SearchSchemaKind::Tag { ref separator, .. } => 7 + if separator.is_some() { 1 } else { 0 },
+      SearchSchemaKind::Numeric { .. } | SearchSchemaKind::Geo { .. } => 4,
+      SearchSchemaKind::Vector(ref field) => 4 + field.args_len(),
+      SearchSchemaKind::Custom { ref arguments, .. } => 1 + arguments.len(),
+    }
+  }
+}
+
+/// A schema attribute used in `FT.CREATE` and `FT.ALTER`.
+///
+/// ```rust
+/// # use fred::types::{SearchSchema, SearchSchemaKind};
+/// let schema = SearchSchema {
+///   field_name: "title".into(),
+///   alias:      None,
+///   kind:       SearchSchemaKind::Text {
+///     sortable:       true,
+///     unf:            false,
+///     nostem:         false,
+///     phonetic:       None,
+///     weight:         Some(2.0),
+///     withsuffixtrie: false,
+///     noindex:        false,
+///   },
+/// };
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchSchema {
+  /// The hash field name or JSON path to index.
+  pub field_name: Str,
+  /// An optional attribute name, equivalent to `AS alias`.
+  pub alias:      Option<Str>,
+  pub kind:       SearchSchemaKind,
+}
+
+impl SearchSchema {
+  /// Create a `TEXT` field with the default options.
+  pub fn text<S: Into<Str>>(field_name: S) -> Self {
+    SearchSchema {
+      field_name: field_name.into(),
+      alias:      None,
+      kind:       SearchSchemaKind::Text {
+        sortable:       false,
+        unf:            false,
+        nostem:         false,
+        phonetic:       None,
+        weight:         None,
+        withsuffixtrie: false,
+        noindex:        false,
+      },
+    }
+  }
+
+  /// Create a `TAG` field with the default options.
+  pub fn tag<S: Into<Str>>(field_name: S) -> Self {
+    SearchSchema {
+      field_name: field_name.into(),
+      alias:      None,
+      kind:       SearchSchemaKind::Tag {
+        sortable:       false,
+        unf:            false,
+        separator:      None,
+        casesensitive:  false,
+        withsuffixtrie: false,
+        noindex:        false,
+      },
+    }
+  }
+
+  /// Create a `NUMERIC` field with the default options.
+  pub fn numeric<S: Into<Str>>(field_name: S) -> Self {
+    SearchSchema {
+      field_name: field_name.into(),
+      alias:      None,
+      kind:       SearchSchemaKind::Numeric {
+        sortable: false,
+        unf:      false,
+        noindex:  false,
+      },
+    }
+  }
+
+  /// Create a `GEO` field with the default options.
+  pub fn geo<S: Into<Str>>(field_name: S) -> Self {
+    SearchSchema {
+      field_name: field_name.into(),
+      alias:      None,
+      kind:       SearchSchemaKind::Geo {
+        sortable: false,
+        unf:      false,
+        noindex:  false,
+      },
+    }
+  }
+
+  /// Create a `VECTOR` field.
+  pub fn vector<S: Into<Str>>(field_name: S, field: VectorField) -> Self {
+    SearchSchema {
+      field_name: field_name.into(),
+      alias:      None,
+      kind:       SearchSchemaKind::Vector(field),
+    }
+  }
+
+  /// Set the attribute name, equivalent to `AS alias`.
+  pub fn alias<S: Into<Str>>(mut self, alias: S) -> Self {
+    self.alias = Some(alias.into());
+    self
+  }
+
+  /// Set the `SORTABLE` flag on `TEXT`, `TAG`, `NUMERIC`, or `GEO` fields.
+  pub fn sortable(mut self) -> Self {
+    match self.kind {
+      SearchSchemaKind::Text { ref mut sortable, .. }
+      | SearchSchemaKind::Tag { ref mut sortable, .. }
+      | SearchSchemaKind::Numeric { ref mut sortable, .. }
+      | SearchSchemaKind::Geo { ref mut sortable, .. } => *sortable = true,
+      _ => {},
+    };
+    self
+  }
+
+  pub(crate) fn args_len(&self) -> usize {
+    1 + if self.alias.is_some() { 2 } else { 0 } + self.kind.args_len()
+  }
+}
+
+/// The indexing algorithm used by a `VECTOR` field.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum VectorAlgorithm {
+  /// A brute force index.
+  Flat { block_size: Option<u64> },
+  /// A Hierarchical Navigable Small World graph index.
+  Hnsw {
+    m:               Option<u64>,
+    ef_construction: Option<u64>,
+    ef_runtime:      Option<u64>,
+    epsilon:         Option<f64>,
+  },
+}
+
+impl VectorAlgorithm {
+  pub(crate) fn to_str(&self) -> Str {
+    utils::static_str(match *self {
+      VectorAlgorithm::Flat { .. } => "FLAT",
+      VectorAlgorithm::Hnsw { .. } => "HNSW",
+    })
+  }
+}
+
+/// The element type of a `VECTOR` field.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VectorType {
+  Float32,
+  Float64,
+}
+
+impl VectorType {
+  pub(crate) fn to_str(&self) -> Str {
+    utils::static_str(match *self {
+      VectorType::Float32 => "FLOAT32",
+      VectorType::Float64 => "FLOAT64",
+    })
+  }
+}
+
+/// The distance metric used by a `VECTOR` field.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DistanceMetric {
+  /// Euclidean distance.
+  L2,
+  /// Inner product.
+  IP,
+  /// Cosine distance.
+  Cosine,
+}
+
+impl DistanceMetric {
+  pub(crate) fn to_str(&self) -> Str {
+    utils::static_str(match *self {
+      DistanceMetric::L2 => "L2",
+      DistanceMetric::IP => "IP",
+      DistanceMetric::Cosine => "COSINE",
+    })
+  }
+}
+
+/// The arguments for a `VECTOR` field in `FT.CREATE` or `FT.ALTER`.
+///
+/// ```rust
+/// # use fred::types::{DistanceMetric, SearchSchema, VectorAlgorithm, VectorField, VectorType};
+/// let schema = SearchSchema::vector("embedding", VectorField {
+///   algorithm:       VectorAlgorithm::Hnsw {
+///     m:               Some(16),
+///     ef_construction: None,
+///     ef_runtime:      None,
+///     epsilon:         None,
+///   },
+///   data_type:       VectorType::Float32,
+///   dim:             384,
+///   distance_metric: DistanceMetric::Cosine,
+///   initial_cap:     None,
+///   noindex:         false,
+/// });
+/// ```
+///
+/// <https://redis.io/docs/interact/search-and-query/advanced-concepts/vectors/>
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VectorField {
+  pub algorithm:       VectorAlgorithm,
+  pub data_type:       VectorType,
+  pub dim:             u64,
+  pub distance_metric: DistanceMetric,
+  pub initial_cap:     Option<u64>,
+  /// Store the field without indexing it.
+  pub noindex:         bool,
+}
+
+impl VectorField {
+  /// Create a `FLAT` vector field with the default algorithm options.
+  pub fn flat(data_type: VectorType, dim: u64, distance_metric: DistanceMetric) -> Self {
+    VectorField {
+      algorithm: VectorAlgorithm::Flat { block_size: None },
+      data_type,
+      dim,
+      distance_metric,
+      initial_cap: None,
+      noindex: false,
+    }
+  }
+
+  /// Create an `HNSW` vector field with the default algorithm options.
+  pub fn hnsw(data_type: VectorType, dim: u64, distance_metric: DistanceMetric) -> Self {
+    VectorField {
+      algorithm: VectorAlgorithm::Hnsw {
+        m:               None,
+        ef_construction: None,
+        ef_runtime:      None,
+        epsilon:         None,
+      },
+      data_type,
+      dim,
+      distance_metric,
+      initial_cap: None,
+      noindex: false,
+    }
+  }
+
+  pub(crate) fn args_len(&self) -> usize {
+    let optional = match self.algorithm {
+      VectorAlgorithm::Flat { ref block_size } => block_size.is_some() as usize,
+      VectorAlgorithm::Hnsw {
+        ref m,
+        ref ef_construction,
+        ref ef_runtime,
+        ref epsilon,
+      } => {
+        m.is_some() as usize
+          + ef_construction.is_some() as usize
+          + ef_runtime.is_some() as usize
+          + epsilon.is_some() as usize
+      },
+    } + self.initial_cap.is_some() as usize;
+
+    (3 + optional) * 2
+  }
+}
+
+/// Encode a vector as a little-endian `FLOAT32` blob, as expected by `VECTOR` fields and KNN query parameters.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+pub fn encode_f32_vector(vector: &[f32]) -> Bytes {
+  let mut out = Vec::with_capacity(vector.len() * 4);
+  for value in vector.iter() {
+    out.extend_from_slice(&value.to_le_bytes());
+  }
+  out.into()
+}
+
+/// Encode a vector as a little-endian `FLOAT64` blob, as expected by `VECTOR` fields and KNN query parameters.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+pub fn encode_f64_vector(vector: &[f64]) -> Bytes {
+  let mut out = Vec::with_capacity(vector.len() * 8);
+  for value in vector.iter() {
+    out.extend_from_slice(&value.to_le_bytes());
+  }
+  out.into()
+}
+
+/// Arguments for the `FT.CREATE` command.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct FtCreateOptions {
+  pub on:              Option<IndexKind>,
+  pub prefixes:        Vec<Str>,
+  pub filter:          Option<Str>,
+  pub language:        Option<Str>,
+  pub language_field:  Option<Str>,
+  pub score:           Option<f64>,
+  pub score_field:     Option<Str>,
+  pub payload_field:   Option<Str>,
+  pub maxtextfields:   bool,
+  pub temporary:       Option<u64>,
+  pub nooffsets:       bool,
+  pub nohl:            bool,
+  pub nofields:        bool,
+  pub nofreqs:         bool,
+  /// An empty array is equivalent to `STOPWORDS 0`.
+  pub stopwords:       Option<Vec<Str>>,
+  pub skipinitialscan: bool,
+}
+
+/// A `FILTER numeric_field min max` argument in `FT.SEARCH`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SearchFilter {
+  pub attribute: Str,
+  pub min:       StringOrNumber,
+  pub max:       StringOrNumber,
+}
+
+/// A `GEOFILTER geo_field lon lat radius unit` argument in `FT.SEARCH`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchGeoFilter {
+  pub attribute: Str,
+  pub position:  GeoPosition,
+  pub radius:    StringOrNumber,
+  pub units:     GeoUnit,
+}
+
+/// An attribute identifier with an optional `AS property` alias, used in `RETURN` and `LOAD` arguments.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SearchField {
+  pub identifier: Str,
+  pub property:   Option<Str>,
+}
+
+impl SearchField {
+  pub(crate) fn args_len(&self) -> usize {
+    if self.property.is_some() {
+      3
+    } else {
+      1
+    }
+  }
+}
+
+impl<S: Into<Str>> From<S> for SearchField {
+  fn from(identifier: S) -> Self {
+    SearchField {
+      identifier: identifier.into(),
+      property:   None,
+    }
+  }
+}
+
+/// A `SUMMARIZE` argument in `FT.SEARCH`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct SearchSummarize {
+  pub fields:    Vec<Str>,
+  pub frags:     Option<u64>,
+  pub len:       Option<u64>,
+  pub separator: Option<Str>,
+}
+
+/// A `HIGHLIGHT` argument in `FT.SEARCH`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct SearchHighlight {
+  pub fields: Vec<Str>,
+  /// The `(open, close)` tags.
+  pub tags:   Option<(Str, Str)>,
+}
+
+/// A `SORTBY` argument in `FT.SEARCH`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SearchSortBy {
+  pub attribute: Str,
+  pub order:     Option<SortOrder>,
+  pub withcount: bool,
+}
+
+/// A `PARAMS` argument in `FT.SEARCH` and `FT.AGGREGATE`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchParameter {
+  pub name:  Str,
+  pub value: RedisValue,
+}
+
+impl<S, V> From<(S, V)> for SearchParameter
+where
+  S: Into<Str>,
+  V: Into<RedisValue>,
+{
+  fn from((name, value): (S, V)) -> Self {
+    SearchParameter {
+      name:  name.into(),
+      value: value.into(),
+    }
+  }
+}
+
+/// Arguments for the `FT.SEARCH` command.
+///
+/// <https://redis.io/commands/ft.search/>
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct FtSearchOptions {
+  pub nocontent:    bool,
+  pub verbatim:     bool,
+  pub nostopwords:  bool,
+  pub withscores:   bool,
+  pub withpayloads: bool,
+  pub withsortkeys: bool,
+  pub filters:      Vec<SearchFilter>,
+  pub geofilters:   Vec<SearchGeoFilter>,
+  pub inkeys:       Vec<RedisKey>,
+  pub infields:     Vec<Str>,
+  pub r#return:     Vec<SearchField>,
+  pub summarize:    Option<SearchSummarize>,
+  pub highlight:    Option<SearchHighlight>,
+  pub slop:         Option<i64>,
+  pub timeout:      Option<i64>,
+  pub inorder:      bool,
+  pub language:     Option<Str>,
+  pub expander:     Option<Str>,
+  pub scorer:       Option<Str>,
+  pub explainscore: bool,
+  pub payload:      Option<Bytes>,
+  pub sortby:       Option<SearchSortBy>,
+  /// The `(offset, num)` values for the `LIMIT` argument.
+  pub limit:        Option<(i64, i64)>,
+  pub params:       Vec<SearchParameter>,
+  pub dialect:      Option<i64>,
+}
+
+/// A `LOAD` argument in `FT.AGGREGATE`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Load {
+  /// Equivalent to `LOAD *`.
+  All,
+  Some(Vec<SearchField>),
+}
+
+/// A `WITHCURSOR` argument in `FT.AGGREGATE`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct WithCursor {
+  pub count:    Option<u64>,
+  /// The max idle time of the cursor, in milliseconds.
+  pub max_idle: Option<u64>,
+}
+
+/// A reducer function used with `GROUPBY` in `FT.AGGREGATE`.
+///
+/// <https://redis.io/docs/interact/search-and-query/search/aggregations/#supported-groupby-reducers>
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReducerFunc {
+  Count,
+  CountDistinct,
+  CountDistinctIsh,
+  Sum,
+  Min,
+  Max,
+  Avg,
+  StdDev,
+  Quantile,
+  ToList,
+  FirstValue,
+  RandomSample,
+  Custom(Str),
+}
+
+impl ReducerFunc {
+  pub(crate) fn to_str(&self) -> Str {
+    utils::static_str(match *self {
+      ReducerFunc::Count => "COUNT",
+      ReducerFunc::CountDistinct => "COUNT_DISTINCT",
+      ReducerFunc::CountDistinctIsh => "COUNT_DISTINCTISH",
+      ReducerFunc::Sum => "SUM",
+      ReducerFunc::Min => "MIN",
+      ReducerFunc::Max => "MAX",
+      ReducerFunc::Avg => "AVG",
+      ReducerFunc::StdDev => "STDDEV",
+      ReducerFunc::Quantile => "QUANTILE",
+      ReducerFunc::ToList => "TOLIST",
+      ReducerFunc::FirstValue => "FIRST_VALUE",
+      ReducerFunc::RandomSample => "RANDOM_SAMPLE",
+      ReducerFunc::Custom(ref s) => return s.clone(),
+    })
+  }
+}
+
+/// A `REDUCE function nargs arg... [AS name]` argument in `FT.AGGREGATE`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SearchReducer {
+  pub func: ReducerFunc,
+  pub args: Vec<Str>,
+  pub name: Option<Str>,
+}
+
+/// A step in the `FT.AGGREGATE` processing pipeline.
+///
+/// Operations are sent to the server in the order they are provided.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AggregateOperation {
+  GroupBy {
+    fields:   Vec<Str>,
+    reducers: Vec<SearchReducer>,
+  },
+  SortBy {
+    properties: Vec<(Str, SortOrder)>,
+    max:        Option<u64>,
+  },
+  Apply {
+    expression: Str,
+    name:       Str,
+  },
+  Limit {
+    offset: i64,
+    num:    i64,
+  },
+  Filter {
+    expression: Str,
+  },
+}
+
+/// Arguments for the `FT.AGGREGATE` command.
+///
+/// <https://redis.io/commands/ft.aggregate/>
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct FtAggregateOptions {
+  pub verbatim: bool,
+  pub load:     Option<Load>,
+  pub timeout:  Option<i64>,
+  pub pipeline: Vec<AggregateOperation>,
+  pub cursor:   Option<WithCursor>,
+  pub params:   Vec<SearchParameter>,
+  pub dialect:  Option<i64>,
+}
+
+/// A `TERMS INCLUDE|EXCLUDE dictionary [terms...]` argument in `FT.SPELLCHECK`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SpellcheckTerms {
+  Include { dictionary: Str, terms: Vec<Str> },
+  Exclude { dictionary: Str, terms: Vec<Str> },
+}
+
+fn search_result_fields(value: RedisValue) -> Result<HashMap<Str, RedisValue>, RedisError> {
+  if value.is_null() {
+    Ok(HashMap::new())
+  } else {
+    value.convert()
+  }
+}
+
+fn take_map_value(values: &mut HashMap<Str, RedisValue>, key: &str) -> RedisValue {
+  values.remove(key).unwrap_or(RedisValue::Null)
+}
+
+/// A document returned by `FT.SEARCH`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchResult {
+  /// The document key.
+  pub id:       Str,
+  /// The document score, if `WITHSCORES` was provided.
+  pub score:    Option<f64>,
+  /// The document payload, if `WITHPAYLOADS` was provided.
+  pub payload:  Option<RedisValue>,
+  /// The sort key, if `WITHSORTKEYS` was provided.
+  pub sort_key: Option<RedisValue>,
+  /// The returned document fields. This is empty with `NOCONTENT`.
+  pub fields:   HashMap<Str, RedisValue>,
+}
+
+impl SearchResult {
+  fn from_resp3(value: RedisValue) -> Result<Self, RedisError> {
+    let mut values: HashMap<Str, RedisValue> = value.convert()?;
+    let id = match take_map_value(&mut values, "id").into_bytes_str() {
+      Some(id) => id,
+      None => return Err(RedisError::new_parse("Missing search result ID.")),
+    };
+    let score = take_map_value(&mut values, "score").as_f64();
+    let payload = values.remove("payload");
+    let sort_key = values.remove("sortkey");
+    let fields = search_result_fields(take_map_value(&mut values, "extra_attributes"))?;
+
+    Ok(SearchResult {
+      id,
+      score,
+      payload,
+      sort_key,
+      fields,
+    })
+  }
+}
+
+/// The parsed response from `FT.SEARCH`.
+///
+/// The RESP2 response format depends on the arguments provided to `FT.SEARCH`. When parsing RESP2 responses any
+/// values between a document ID and its fields are read as the score, payload, and sort key, in that order. Callers
+/// that combine `NOCONTENT` with `WITHSCORES`, `WITHPAYLOADS`, or `WITHSORTKEYS` in RESP2 mode should parse the
+/// response as a `RedisValue` instead.
+///
+/// ```rust no_run
+/// # use fred::prelude::*;
+/// # use fred::types::{FtSearchOptions, SearchResults};
+/// async fn example(client: &RedisClient) -> Result<(), RedisError> {
+///   let results: SearchResults = client
+///     .ft_search("idx", "@title:hello", FtSearchOptions {
+///       withscores: true,
+///       ..Default::default()
+///     })
+///     .await?;
+///
+///   for result in results.results.iter() {
+///     println!("{}: {:?} {:?}", result.id, result.score, result.fields);
+///   }
+///   Ok(())
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchResults {
+  /// The total number of matching documents.
+  pub total:   u64,
+  pub results: Vec<SearchResult>,
+}
+
+impl SearchResults {
+  fn from_resp2(values: Vec<RedisValue>) -> Result<Self, RedisError> {
+    let mut values = values.into_iter();
+    let total = match values.next().and_then(|v| v.as_u64()) {
+      Some(total) => total,
+      None => return Err(RedisError::new_parse("Expected total result count.")),
+    };
+    let values: Vec<RedisValue> = values.collect();
+    let nocontent = !values.iter().any(|v| v.is_array() || v.is_map());
+
+    let mut results = Vec::with_capacity(values.len());
+    let mut values = values.into_iter();
+    while let Some(id) = values.next() {
+      let id = match id.into_bytes_str() {
+        Some(id) => id,
+        None => return Err(RedisError::new_parse("Expected search result ID.")),
+      };
+      let mut result = SearchResult {
+        id,
+        score: None,
+        payload: None,
+        sort_key: None,
+        fields: HashMap::new(),
+      };
+
+      if !nocontent {
+        let mut extra = Vec::new();
+        for value in values.by_ref() {
+          if value.is_array() || value.is_map() || value.is_null() {
+            result.fields = search_result_fields(value)?;
+            break;
+          } else {
+            extra.push(value);
+          }
+        }
+
+        let mut extra = extra.into_iter().peekable();
+        if let Some(score) = extra.peek().and_then(|v| v.as_f64()) {
+          result.score = Some(score);
+          let _ = extra.next();
+        }
+        result.payload = extra.next();
+        result.sort_key = extra.next();
+      }
+
+      results.push(result);
+    }
+
+    Ok(SearchResults { total, results })
+  }
+
+  fn from_resp3(value: RedisValue) -> Result<Self, RedisError> {
+    let mut values: HashMap<Str, RedisValue> = value.convert()?;
+    let total = match take_map_value(&mut values, "total_results").as_u64() {
+      Some(total) => total,
+      None => return Err(RedisError::new_parse("Expected total result count.")),
+    };
+    let results = take_map_value(&mut values, "results")
+      .into_array()
+      .into_iter()
+      .map(SearchResult::from_resp3)
+      .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(SearchResults { total, results })
+  }
+}
+
+impl TryFrom<RedisValue> for SearchResults {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    match value {
+      RedisValue::Array(values) => SearchResults::from_resp2(values),
+      RedisValue::Map(_) => SearchResults::from_resp3(value),
+      _ => Err(RedisError::new_parse("Expected array or map search results.")),
+    }
+  }
+}
+
+/// A document returned by a KNN vector similarity query.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KnnResult {
+  /// The document key.
+  pub key:    Str,
+  /// The distance between the document vector and the query vector.
+  pub score:  f64,
+  /// The returned document fields, not including the score.
+  pub fields: HashMap<Str, RedisValue>,
+}
+
+/// The parsed response from [ft_search_knn](crate::interfaces::RediSearchInterface::ft_search_knn).
+///
+/// Results are sorted by score, with the nearest documents first.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KnnResults {
+  /// The total number of matching documents.
+  pub total:   u64,
+  pub results: Vec<KnnResult>,
+}
+
+impl KnnResults {
+  /// Convert the results to a list of `(key, score, fields)` tuples.
+  pub fn into_tuples(self) -> Vec<(Str, f64, HashMap<Str, RedisValue>)> {
+    self
+      .results
+      .into_iter()
+      .map(|result| (result.key, result.score, result.fields))
+      .collect()
+  }
+}
+
+impl TryFrom<RedisValue> for KnnResults {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    let results = SearchResults::try_from(value)?;
+    let total = results.total;
+    let results = results
+      .results
+      .into_iter()
+      .map(|mut result| {
+        let score = match result.fields.remove(KNN_SCORE_FIELD).and_then(|v| v.as_f64()) {
+          Some(score) => score,
+          None => return Err(RedisError::new_parse("Missing or invalid KNN score.")),
+        };
+
+        Ok(KnnResult {
+          key: result.id,
+          score,
+          fields: result.fields,
+        })
+      })
+      .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(KnnResults { total, results })
+  }
+}
+
+/// The parsed response from `FT.AGGREGATE` or `FT.CURSOR READ`.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggregateResults {
+  /// The number of results reported by the server.
+  pub total:  u64,
+  /// The rows returned by the aggregation pipeline.
+  pub rows:   Vec<HashMap<Str, RedisValue>>,
+  /// The cursor ID, if `WITHCURSOR` was provided. A value of `0` means the cursor is exhausted.
+  pub cursor: Option<u64>,
+}
+
+impl AggregateResults {
+  fn from_results(value: RedisValue) -> Result<Self, RedisError> {
+    match value {
+      RedisValue::Array(values) => {
+        let mut values = values.into_iter();
+        let total = values.next().and_then(|v| v.as_u64()).unwrap_or(0);
+        let rows = values.map(search_result_fields).collect::<Result<Vec<_>, _>>()?;
+
+        Ok(AggregateResults {
+          total,
+          rows,
+          cursor: None,
+        })
+      },
+      RedisValue::Map(_) => {
+        let mut values: HashMap<Str, RedisValue> = value.convert()?;
+        let total = take_map_value(&mut values, "total_results").as_u64().unwrap_or(0);
+        let rows = take_map_value(&mut values, "results")
+          .into_array()
+          .into_iter()
+          .map(|row| {
+            let mut row: HashMap<Str, RedisValue> = row.convert()?;
+            search_result_fields(take_map_value(&mut row, "extra_attributes"))
+          })
+          .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(AggregateResults {
+          total,
+          rows,
+          cursor: None,
+        })
+      },
+      _ => Err(RedisError::new_parse("Expected array or map aggregate results.")),
+    }
+  }
+}
+
+impl TryFrom<RedisValue> for AggregateResults {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    // responses with a cursor are `[results, cursor]` in both RESP2 and RESP3
+    let is_cursor_response = match value {
+      RedisValue::Array(ref values) => {
+        values.len() == 2 && (values[0].is_array() || values[0].is_map()) && values[1].as_u64().is_some()
+      },
+      _ => false,
+    };
+
+    if is_cursor_response {
+      let mut values = value.into_array();
+      let cursor = values.pop().and_then(|v| v.as_u64());
+      let mut results = AggregateResults::from_results(values.pop().unwrap_or(RedisValue::Null))?;
+      results.cursor = cursor;
+      Ok(results)
+    } else {
+      AggregateResults::from_results(value)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::types::RedisMap;
+
+  fn map(values: Vec<(&'static str, RedisValue)>) -> RedisValue {
+    RedisValue::Map(RedisMap::try_from(values).unwrap())
+  }
+
+  #[test]
+  fn should_parse_resp2_search_results() {
+    let value = RedisValue::Array(vec![
+      2.into(),
+      "doc:1".into(),
+      "1.5".into(),
+      RedisValue::Array(vec!["title".into(), "foo".into()]),
+      "doc:2".into(),
+      "0.5".into(),
+      RedisValue::Array(vec!["title".into(), "bar".into()]),
+    ]);
+    let results = SearchResults::try_from(value).unwrap();
+
+    assert_eq!(results.total, 2);
+    assert_eq!(results.results[0].id, "doc:1");
+    assert_eq!(results.results[0].score, Some(1.5));
+    assert_eq!(results.results[1].fields.get("title"), Some(&"bar".into()));
+  }
+
+  #[test]
+  fn should_parse_resp2_nocontent_search_results() {
+    let value = RedisValue::Array(vec![2.into(), "doc:1".into(), "doc:2".into()]);
+    let results = SearchResults::try_from(value).unwrap();
+
+    assert_eq!(results.total, 2);
+    assert_eq!(results.results[1].id, "doc:2");
+    assert!(results.results[1].fields.is_empty());
+  }
+
+  #[test]
+  fn should_parse_resp3_search_results() {
+    let value = map(vec![
+      ("total_results", 1.into()),
+      ("format", "STRING".into()),
+      (
+        "results",
+        RedisValue::Array(vec![map(vec![
+          ("id", "doc:1".into()),
+          ("score", 1.5.into()),
+          ("extra_attributes", map(vec![("title", "foo".into())])),
+          ("values", RedisValue::Array(vec![])),
+        ])]),
+      ),
+    ]);
+    let results = SearchResults::try_from(value).unwrap();
+
+    assert_eq!(results.total, 1);
+    assert_eq!(results.results[0].id, "doc:1");
+    assert_eq!(results.results[0].score, Some(1.5));
+    assert_eq!(results.results[0].fields.get("title"), Some(&"foo".into()));
+  }
+
+  #[test]
+  fn should_parse_aggregate_results_with_cursor() {
+    let resp2 = RedisValue::Array(vec![
+      RedisValue::Array(vec![1.into(), RedisValue::Array(vec!["genre".into(), "scifi".into()])]),
+      123.into(),
+    ]);
+    let resp3 = RedisValue::Array(vec![
+      map(vec![
+        ("total_results", 1.into()),
+        (
+          "results",
+          RedisValue::Array(vec![map(vec![(
+            "extra_attributes",
+            map(vec![("genre", "scifi".into())]),
+          )])]),
+        ),
+      ]),
+      123.into(),
+    ]);
+
+    for value in [resp2, resp3].into_iter() {
+      let results = AggregateResults::try_from(value).unwrap();
+      assert_eq!(results.total, 1);
+      assert_eq!(results.cursor, Some(123));
+      assert_eq!(results.rows[0].get("genre"), Some(&"scifi".into()));
+    }
+  }
+
+  #[test]
+  fn should_encode_vectors_as_little_endian() {
+    assert_eq!(&encode_f32_vector(&[1.0, -2.5])[..], &[
+      0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x20, 0xc0
+    ]);
+    assert_eq!(&encode_f64_vector(&[1.0])[..], &[
+      0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f
+    ]);
+  }
+
+  #[test]
+  fn should_parse_knn_results() {
+    let value = RedisValue::Array(vec![
+      1.into(),
+      "doc:1".into(),
+      RedisValue::Array(vec!["__knn_score".into(), "0.25".into(), "title".into(), "foo".into()]),
+    ]);
+    let results = KnnResults::try_from(value).unwrap().into_tuples();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].0, "doc:1");
+    assert_eq!(results[0].1, 0.25);
+    assert_eq!(results[0].2.get("title"), Some(&"foo".into()));
+    assert!(!results[0].2.contains_key("__knn_score"));
+  }
+
+  #[test]
+  fn should_error_parsing_knn_results_without_score() {
+    let value = RedisValue::Array(vec![
+      1.into(),
+      "doc:1".into(),
+      RedisValue::Array(vec!["title".into(), "foo".into()]),
+    ]);
+    assert!(KnnResults::try_from(value).is_err());
+  }
+}
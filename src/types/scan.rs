@@ -1,6 +1,7 @@
 use crate::{
   clients::RedisClient,
-  error::RedisError,
+  commands,
+  error::{RedisError, RedisErrorKind},
   interfaces,
   modules::inner::RedisClientInner,
   protocol::{
@@ -8,11 +9,18 @@ use crate::{
     responders::ResponseKind,
     types::{KeyScanInner, ValueScanInner},
   },
-  types::{RedisKey, RedisMap, RedisValue},
+  types::{RedisKey, RedisMap, RedisValue, Server},
   utils,
 };
 use bytes_utils::Str;
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, collections::BTreeMap, fmt, str::FromStr, sync::Arc, time::Duration};
+use tokio::sync::{mpsc::UnboundedSender, watch};
+
+#[cfg(feature = "serde-json")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The marker used in the text format of a [ScanCheckpoint](ScanCheckpoint) for a finished scan.
+const FINISHED: &str = "-";
 
 /// The types of values supported by the [type](https://redis.io/commands/type) command.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -38,6 +46,133 @@ impl ScanType {
   }
 }
 
+/// A handle used to pause and resume a scan operation.
+///
+/// Pausing a scan delays requests for new pages until the scan is resumed. Requests that were already sent are not
+/// affected.
+#[derive(Clone, Debug)]
+pub struct ScanHandle {
+  paused: Arc<watch::Sender<bool>>,
+}
+
+impl Default for ScanHandle {
+  fn default() -> Self {
+    ScanHandle {
+      paused: Arc::new(watch::channel(false).0),
+    }
+  }
+}
+
+impl ScanHandle {
+  /// Stop requesting new pages.
+  pub fn pause(&self) {
+    self.paused.send_replace(true);
+  }
+
+  /// Resume requesting new pages.
+  pub fn resume(&self) {
+    self.paused.send_replace(false);
+  }
+
+  /// Whether the scan is paused.
+  pub fn is_paused(&self) -> bool {
+    *self.paused.borrow()
+  }
+
+  /// Wait until the scan is not paused.
+  pub(crate) async fn wait_for_resume(&self) {
+    let mut rx = self.paused.subscribe();
+    let _ = rx.wait_for(|paused| !*paused).await;
+  }
+}
+
+/// Options to limit the load on the cluster from a
+/// [scan_cluster_with_options](crate::clients::RedisClient::scan_cluster_with_options) operation.
+#[derive(Clone, Debug, Default)]
+pub struct ScanOptions {
+  /// The maximum number of nodes scanned concurrently.
+  ///
+  /// The next node is scanned once the scan on a node finishes. Default: `None` (all nodes)
+  pub max_concurrency:      Option<usize>,
+  /// The maximum number of pages read from each node per second.
+  ///
+  /// Default: `None` (unlimited)
+  pub max_pages_per_second: Option<u32>,
+  /// A handle used to pause and resume the scan.
+  pub handle:               ScanHandle,
+  /// Scan replica nodes instead of primary nodes.
+  ///
+  /// Replicas are selected according to the [ReplicaConfig](crate::types::ReplicaConfig), and each node is scanned
+  /// on the same replica while it remains a replica of the same primary node. Commands fall back to the primary node
+  /// if configured via `primary_fallback`. Cursors from a replica scan are only valid on the replica that returned
+  /// them, so [checkpoints](crate::types::ScanCheckpoint) should be resumed with the same options.
+  ///
+  /// Default: `false`
+  #[cfg(feature = "replicas")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "replicas")))]
+  pub replicas:             bool,
+}
+
+impl ScanOptions {
+  /// The minimum time between page requests on each node.
+  pub(crate) fn page_interval(&self) -> Option<Duration> {
+    self
+      .max_pages_per_second
+      .filter(|pages| *pages > 0)
+      .map(|pages| Duration::from_secs(1) / pages)
+  }
+}
+
+/// Options for bulk operations on the keys matching a pattern, such as
+/// [unlink_matching](crate::clients::RedisClient::unlink_matching).
+#[derive(Clone, Debug)]
+pub struct BulkOptions {
+  /// The `COUNT` argument sent with each `SCAN` command and the maximum number of keys in each pipeline.
+  ///
+  /// Default: `100`
+  pub batch_size: u32,
+  /// Scan and count the matching keys without sending any other commands.
+  ///
+  /// Default: `false`
+  pub dry_run:    bool,
+  /// Resume the operation from the [checkpoint](crate::types::BulkProgress::checkpoint) in the last progress update
+  /// from a previous operation.
+  ///
+  /// Default: `None`
+  pub checkpoint: Option<ScanCheckpoint>,
+  /// Options that limit the load on the cluster when scanning keys on clustered deployments.
+  pub scan:       ScanOptions,
+  /// A channel that receives a progress update after each page of keys is processed.
+  ///
+  /// Default: `None`
+  pub progress:   Option<UnboundedSender<BulkProgress>>,
+}
+
+impl Default for BulkOptions {
+  fn default() -> Self {
+    BulkOptions {
+      batch_size: 100,
+      dry_run:    false,
+      checkpoint: None,
+      scan:       ScanOptions::default(),
+      progress:   None,
+    }
+  }
+}
+
+/// The progress of a bulk operation on the keys matching a pattern.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BulkProgress {
+  /// The number of matching keys returned by `SCAN`.
+  ///
+  /// Keys may be counted more than once if they are returned more than once by `SCAN`.
+  pub scanned:    u64,
+  /// The number of keys that were unlinked, expired, copied, or dumped. This is always `0` in dry run mode.
+  pub processed:  u64,
+  /// A checkpoint that can be used to resume the operation after the last processed page.
+  pub checkpoint: ScanCheckpoint,
+}
+
 /// An interface for interacting with the results of a scan operation.
 pub trait Scanner {
   /// The type of results from the scan operation.
@@ -77,6 +212,139 @@ pub trait Scanner {
   /// If this function returns an error the scan call cannot continue as the client has been closed, or some other
   /// fatal error has occurred. If this happens the error will appear in the stream from the original SCAN call.
   fn next(self) -> Result<(), RedisError>;
+
+  /// Read the progress of the scan operation, assuming the current page has been processed.
+  ///
+  /// See [ScanCheckpoint](crate::types::ScanCheckpoint) for more information.
+  fn checkpoint(&self) -> ScanCheckpoint;
+}
+
+/// A snapshot of the progress of a scan operation that can be used to resume the scan later, possibly in a
+/// different process.
+///
+/// Checkpoints are read from a page of results via [checkpoint](Scanner::checkpoint) and can be passed to
+/// [scan_from](crate::clients::RedisClient::scan_from),
+/// [scan_cluster_from](crate::clients::RedisClient::scan_cluster_from),
+/// [hscan_from](crate::clients::RedisClient::hscan_from), etc. The `Display` and `FromStr` implementations convert
+/// checkpoints to and from a compact text format for storage, and the `serde-json` feature adds `serde` support
+/// using the same format.
+///
+/// Resuming from a checkpoint provides the same guarantees as the underlying `SCAN` cursor: elements present for the
+/// entire scan are returned at least once, but elements may be returned more than once. This includes elements on
+/// pages that were read but not yet processed when the checkpoint was taken.
+///
+/// Cursors are specific to each node, so a cluster checkpoint can only be resumed if the set of primary nodes is the
+/// same as when the checkpoint was taken. [scan_cluster_from](crate::clients::RedisClient::scan_cluster_from) returns
+/// an error if nodes were added, removed, or replaced by a failover since then. Hash slot migrations between existing
+/// nodes cannot be detected and may cause keys in the migrated slots to be missed or returned more than once.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ScanCheckpoint {
+  /// The next cursor for a `SCAN`, `HSCAN`, `SSCAN`, or `ZSCAN` operation, or `None` if the scan finished.
+  Cursor(Option<Str>),
+  /// The next cursor on each primary node in a [scan_cluster](crate::clients::RedisClient::scan_cluster) operation,
+  /// or `None` if the scan on that node finished.
+  Cluster(BTreeMap<Server, Option<Str>>),
+}
+
+impl ScanCheckpoint {
+  /// Whether the scan operation finished.
+  pub fn is_finished(&self) -> bool {
+    match self {
+      ScanCheckpoint::Cursor(cursor) => cursor.is_none(),
+      ScanCheckpoint::Cluster(cursors) => cursors.values().all(|cursor| cursor.is_none()),
+    }
+  }
+}
+
+fn cursor_or_finished(cursor: &Option<Str>) -> &str {
+  cursor.as_ref().map(|s| &**s).unwrap_or(FINISHED)
+}
+
+fn next_cursor(args: &[RedisValue], cursor_idx: usize, can_continue: bool) -> Option<Str> {
+  if can_continue {
+    args[cursor_idx].as_bytes_str()
+  } else {
+    None
+  }
+}
+
+fn parse_cursor(value: &str) -> Result<Option<Str>, RedisError> {
+  if value == FINISHED {
+    Ok(None)
+  } else if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+    Ok(Some(value.into()))
+  } else {
+    Err(RedisError::new(RedisErrorKind::Parse, "Invalid scan cursor."))
+  }
+}
+
+/// Checkpoints are written as the cursor, or `-` if the scan finished. Cluster checkpoints are written as a
+/// comma-separated list of `host:port=cursor` pairs.
+impl fmt::Display for ScanCheckpoint {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ScanCheckpoint::Cursor(cursor) => write!(f, "{}", cursor_or_finished(cursor)),
+      ScanCheckpoint::Cluster(cursors) => {
+        for (idx, (server, cursor)) in cursors.iter().enumerate() {
+          if idx > 0 {
+            write!(f, ",")?;
+          }
+          write!(f, "{}={}", server, cursor_or_finished(cursor))?;
+        }
+        Ok(())
+      },
+    }
+  }
+}
+
+impl FromStr for ScanCheckpoint {
+  type Err = RedisError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if !s.contains('=') {
+      return parse_cursor(s).map(ScanCheckpoint::Cursor);
+    }
+
+    let mut cursors = BTreeMap::new();
+    for part in s.split(',') {
+      let (server, cursor) = match part.rsplit_once('=') {
+        Some(parts) => parts,
+        None => {
+          return Err(RedisError::new(
+            RedisErrorKind::Parse,
+            "Invalid cluster scan checkpoint.",
+          ))
+        },
+      };
+      let server = Server::try_from(server).map_err(|_| RedisError::new(RedisErrorKind::Parse, "Invalid server."))?;
+
+      cursors.insert(server, parse_cursor(cursor)?);
+    }
+    Ok(ScanCheckpoint::Cluster(cursors))
+  }
+}
+
+#[cfg(feature = "serde-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+impl Serialize for ScanCheckpoint {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
+#[cfg(feature = "serde-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+impl<'de> Deserialize<'de> for ScanCheckpoint {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(de::Error::custom)
+  }
 }
 
 /// The result of a SCAN operation.
@@ -113,16 +381,40 @@ impl Scanner for ScanResult {
   }
 
   fn next(self) -> Result<(), RedisError> {
+    self.update_cluster_cursors();
     if !self.can_continue {
       return Ok(());
     }
 
-    let cluster_node = self.scan_state.server.clone();
-    let response = ResponseKind::KeyScan(self.scan_state);
-    let mut cmd: RedisCommand = (RedisCommandKind::Scan, Vec::new(), response).into();
-    cmd.cluster_node = cluster_node;
+    commands::scan::send_next_page(&self.inner, self.scan_state)
+  }
 
-    interfaces::default_send_command(&self.inner, cmd)
+  fn checkpoint(&self) -> ScanCheckpoint {
+    match self.update_cluster_cursors() {
+      Some(cursors) => ScanCheckpoint::Cluster(cursors),
+      None => ScanCheckpoint::Cursor(next_cursor(
+        &self.scan_state.args,
+        self.scan_state.cursor_idx,
+        self.can_continue,
+      )),
+    }
+  }
+}
+
+impl ScanResult {
+  /// Update the shared cursor state in a `scan_cluster` operation, returning the new state.
+  fn update_cluster_cursors(&self) -> Option<BTreeMap<Server, Option<Str>>> {
+    self.scan_state.cluster.as_ref().map(|state| {
+      let mut guard = state.cursors.lock();
+      let server = self.scan_state.hash_slot.and_then(|slot| state.nodes.get(&slot));
+      if let Some(server) = server {
+        guard.insert(
+          server.clone(),
+          next_cursor(&self.scan_state.args, self.scan_state.cursor_idx, self.can_continue),
+        );
+      }
+      guard.clone()
+    })
   }
 }
 
@@ -168,6 +460,14 @@ impl Scanner for HScanResult {
     let cmd: RedisCommand = (RedisCommandKind::Hscan, Vec::new(), response).into();
     interfaces::default_send_command(&self.inner, cmd)
   }
+
+  fn checkpoint(&self) -> ScanCheckpoint {
+    ScanCheckpoint::Cursor(next_cursor(
+      &self.scan_state.args,
+      self.scan_state.cursor_idx,
+      self.can_continue,
+    ))
+  }
 }
 
 /// The result of a SSCAN operation.
@@ -212,6 +512,14 @@ impl Scanner for SScanResult {
     let cmd: RedisCommand = (RedisCommandKind::Sscan, Vec::new(), response).into();
     interfaces::default_send_command(&self.inner, cmd)
   }
+
+  fn checkpoint(&self) -> ScanCheckpoint {
+    ScanCheckpoint::Cursor(next_cursor(
+      &self.scan_state.args,
+      self.scan_state.cursor_idx,
+      self.can_continue,
+    ))
+  }
 }
 
 /// The result of a ZSCAN operation.
@@ -256,4 +564,50 @@ impl Scanner for ZScanResult {
     let cmd: RedisCommand = (RedisCommandKind::Zscan, Vec::new(), response).into();
     interfaces::default_send_command(&self.inner, cmd)
   }
+
+  fn checkpoint(&self) -> ScanCheckpoint {
+    ScanCheckpoint::Cursor(next_cursor(
+      &self.scan_state.args,
+      self.scan_state.cursor_idx,
+      self.can_continue,
+    ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_convert_cursor_checkpoints() {
+    let checkpoint = ScanCheckpoint::Cursor(Some("1234".into()));
+    assert_eq!(checkpoint.to_string(), "1234");
+    assert_eq!("1234".parse::<ScanCheckpoint>().unwrap(), checkpoint);
+    assert!(!checkpoint.is_finished());
+
+    let checkpoint = ScanCheckpoint::Cursor(None);
+    assert_eq!(checkpoint.to_string(), "-");
+    assert_eq!("-".parse::<ScanCheckpoint>().unwrap(), checkpoint);
+    assert!(checkpoint.is_finished());
+
+    assert!("".parse::<ScanCheckpoint>().is_err());
+    assert!("abc".parse::<ScanCheckpoint>().is_err());
+  }
+
+  #[test]
+  fn should_convert_cluster_checkpoints() {
+    let mut cursors = BTreeMap::new();
+    cursors.insert(Server::new("127.0.0.1", 30001), Some("12".into()));
+    cursors.insert(Server::new("127.0.0.1", 30002), None);
+    let checkpoint = ScanCheckpoint::Cluster(cursors);
+
+    let value = checkpoint.to_string();
+    assert_eq!(value, "127.0.0.1:30001=12,127.0.0.1:30002=-");
+    assert_eq!(value.parse::<ScanCheckpoint>().unwrap(), checkpoint);
+    assert!(!checkpoint.is_finished());
+    assert!("127.0.0.1:30001=-".parse::<ScanCheckpoint>().unwrap().is_finished());
+
+    assert!("127.0.0.1=12".parse::<ScanCheckpoint>().is_err());
+    assert!("127.0.0.1:30001=12,".parse::<ScanCheckpoint>().is_err());
+  }
 }
@@ -0,0 +1,289 @@
+use crate::{error::RedisError, types::RedisValue};
+use bytes_utils::Str;
+use std::{collections::HashMap, convert::TryFrom, time::Duration};
+
+fn take_i64(values: &mut HashMap<Str, RedisValue>, field: &str) -> Result<i64, RedisError> {
+  values
+    .remove(field)
+    .and_then(|v| v.as_i64())
+    .ok_or(RedisError::new_parse(format!("Missing or invalid `{}`.", field)))
+}
+
+fn take_str(values: &mut HashMap<Str, RedisValue>, field: &str) -> Result<Str, RedisError> {
+  values
+    .remove(field)
+    .and_then(|v| v.into_bytes_str())
+    .ok_or(RedisError::new_parse(format!("Missing or invalid `{}`.", field)))
+}
+
+/// The `begin_search` section of a [key specification](https://redis.io/docs/reference/key-specs/).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeySpecBeginSearch {
+  /// The first key is at the provided argument index.
+  Index(i64),
+  /// The first key follows the provided keyword, searching from `start_from`.
+  Keyword { keyword: Str, start_from: i64 },
+  /// The keys cannot be found without parsing the command arguments.
+  Unknown,
+}
+
+impl TryFrom<RedisValue> for KeySpecBeginSearch {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    let mut values: HashMap<Str, RedisValue> = value.convert()?;
+    let kind = take_str(&mut values, "type")?;
+    let mut spec: HashMap<Str, RedisValue> = values
+      .remove("spec")
+      .map(|v| v.convert())
+      .transpose()?
+      .unwrap_or_default();
+
+    Ok(match &*kind {
+      "index" => KeySpecBeginSearch::Index(take_i64(&mut spec, "index")?),
+      "keyword" => KeySpecBeginSearch::Keyword {
+        keyword:    take_str(&mut spec, "keyword")?,
+        start_from: take_i64(&mut spec, "startfrom")?,
+      },
+      _ => KeySpecBeginSearch::Unknown,
+    })
+  }
+}
+
+/// The `find_keys` section of a [key specification](https://redis.io/docs/reference/key-specs/).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeySpecFindKeys {
+  /// The keys are found in a range relative to the `begin_search` index.
+  Range {
+    last_key: i64,
+    key_step: i64,
+    limit:    i64,
+  },
+  /// The number of keys is read from an argument relative to the `begin_search` index.
+  KeyNum {
+    key_num_idx: i64,
+    first_key:   i64,
+    key_step:    i64,
+  },
+  /// The keys cannot be found without parsing the command arguments.
+  Unknown,
+}
+
+impl TryFrom<RedisValue> for KeySpecFindKeys {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    let mut values: HashMap<Str, RedisValue> = value.convert()?;
+    let kind = take_str(&mut values, "type")?;
+    let mut spec: HashMap<Str, RedisValue> = values
+      .remove("spec")
+      .map(|v| v.convert())
+      .transpose()?
+      .unwrap_or_default();
+
+    Ok(match &*kind {
+      "range" => KeySpecFindKeys::Range {
+        last_key: take_i64(&mut spec, "lastkey")?,
+        key_step: take_i64(&mut spec, "keystep")?,
+        limit:    take_i64(&mut spec, "limit")?,
+      },
+      "keynum" => KeySpecFindKeys::KeyNum {
+        key_num_idx: take_i64(&mut spec, "keynumidx")?,
+        first_key:   take_i64(&mut spec, "firstkey")?,
+        key_step:    take_i64(&mut spec, "keystep")?,
+      },
+      _ => KeySpecFindKeys::Unknown,
+    })
+  }
+}
+
+/// A [key specification](https://redis.io/docs/reference/key-specs/) describing where a command's keys are found.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeySpec {
+  pub begin_search: KeySpecBeginSearch,
+  pub find_keys:    KeySpecFindKeys,
+  pub flags:        Vec<Str>,
+  pub notes:        Option<Str>,
+}
+
+impl TryFrom<RedisValue> for KeySpec {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    let mut values: HashMap<Str, RedisValue> = value.convert()?;
+
+    let begin_search = match values.remove("begin_search") {
+      Some(value) => KeySpecBeginSearch::try_from(value)?,
+      None => return Err(RedisError::new_parse("Missing `begin_search`.")),
+    };
+    let find_keys = match values.remove("find_keys") {
+      Some(value) => KeySpecFindKeys::try_from(value)?,
+      None => return Err(RedisError::new_parse("Missing `find_keys`.")),
+    };
+    let flags = values
+      .remove("flags")
+      .map(|v| v.convert())
+      .transpose()?
+      .unwrap_or_default();
+    let notes = values.remove("notes").and_then(|v| v.into_bytes_str());
+
+    Ok(KeySpec {
+      begin_search,
+      find_keys,
+      flags,
+      notes,
+    })
+  }
+}
+
+/// The output of the [command info](https://redis.io/commands/command-info) command.
+///
+/// Fields added in Redis 7 are empty when connected to older servers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommandInfo {
+  pub name:           Str,
+  /// The number of arguments, including the command name. Negative values represent a minimum.
+  pub arity:          i64,
+  pub flags:          Vec<Str>,
+  pub first_key:      i64,
+  pub last_key:       i64,
+  pub step:           i64,
+  pub acl_categories: Vec<Str>,
+  pub tips:           Vec<Str>,
+  pub key_specs:      Vec<KeySpec>,
+  pub subcommands:    Vec<CommandInfo>,
+}
+
+impl TryFrom<RedisValue> for CommandInfo {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    let mut values = match value {
+      RedisValue::Array(values) if values.len() >= 6 => values.into_iter(),
+      _ => return Err(RedisError::new_parse("Expected command info array.")),
+    };
+
+    let name = values
+      .next()
+      .and_then(|v| v.into_bytes_str())
+      .ok_or(RedisError::new_parse("Expected command name."))?;
+    let arity = values.next().and_then(|v| v.as_i64()).unwrap_or(0);
+    let flags = values.next().map(|v| v.convert()).transpose()?.unwrap_or_default();
+    let first_key = values.next().and_then(|v| v.as_i64()).unwrap_or(0);
+    let last_key = values.next().and_then(|v| v.as_i64()).unwrap_or(0);
+    let step = values.next().and_then(|v| v.as_i64()).unwrap_or(0);
+    let acl_categories = values.next().map(|v| v.convert()).transpose()?.unwrap_or_default();
+    let tips = values.next().map(|v| v.convert()).transpose()?.unwrap_or_default();
+    let key_specs = values
+      .next()
+      .map(|v| v.into_array().into_iter().map(KeySpec::try_from).collect())
+      .transpose()?
+      .unwrap_or_default();
+    let subcommands = values
+      .next()
+      .map(|v| v.into_array().into_iter().map(CommandInfo::try_from).collect())
+      .transpose()?
+      .unwrap_or_default();
+
+    Ok(CommandInfo {
+      name,
+      arity,
+      flags,
+      first_key,
+      last_key,
+      step,
+      acl_categories,
+      tips,
+      key_specs,
+      subcommands,
+    })
+  }
+}
+
+/// The latest latency spike for an event, as returned by the [latency latest](https://redis.io/commands/latency-latest) command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LatencyEvent {
+  pub name:      Str,
+  /// The UNIX timestamp, in seconds, of the latest spike.
+  pub timestamp: i64,
+  pub latest:    Duration,
+  pub max:       Duration,
+}
+
+impl TryFrom<RedisValue> for LatencyEvent {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    let values = value.into_array();
+    if values.len() < 4 {
+      return Err(RedisError::new_parse("Expected at least 4 latency event values."));
+    }
+
+    let name = values[0]
+      .as_bytes_str()
+      .ok_or(RedisError::new_parse("Expected event name."))?;
+    let timestamp = values[1]
+      .as_i64()
+      .ok_or(RedisError::new_parse("Expected integer timestamp."))?;
+    let latest = values[2]
+      .as_u64()
+      .map(Duration::from_millis)
+      .ok_or(RedisError::new_parse("Expected integer latency."))?;
+    let max = values[3]
+      .as_u64()
+      .map(Duration::from_millis)
+      .ok_or(RedisError::new_parse("Expected integer latency."))?;
+
+    Ok(LatencyEvent {
+      name,
+      timestamp,
+      latest,
+      max,
+    })
+  }
+}
+
+/// A latency sample, as returned by the [latency history](https://redis.io/commands/latency-history) command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LatencySample {
+  /// The UNIX timestamp, in seconds, of the sample.
+  pub timestamp: i64,
+  pub latency:   Duration,
+}
+
+impl TryFrom<RedisValue> for LatencySample {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    let (timestamp, latency): (i64, u64) = value.convert()?;
+
+    Ok(LatencySample {
+      timestamp,
+      latency: Duration::from_millis(latency),
+    })
+  }
+}
+
+/// A loaded module, as returned by the [module list](https://redis.io/commands/module-list) command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModuleInfo {
+  pub name:    Str,
+  pub version: i64,
+  pub path:    Option<Str>,
+  pub args:    Vec<RedisValue>,
+}
+
+impl TryFrom<RedisValue> for ModuleInfo {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    let mut values: HashMap<Str, RedisValue> = value.convert()?;
+
+    Ok(ModuleInfo {
+      name:    take_str(&mut values, "name")?,
+      version: take_i64(&mut values, "ver")?,
+      path:    values.remove("path").and_then(|v| v.into_bytes_str()),
+      args:    values.remove("args").map(|v| v.into_array()).unwrap_or_default(),
+    })
+  }
+}
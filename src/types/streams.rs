@@ -10,6 +10,18 @@ use std::{
   convert::{TryFrom, TryInto},
 };
 
+#[cfg(feature = "serde-json")]
+use crate::types::FromRedis;
+#[cfg(feature = "serde-json")]
+use serde::{
+  de::{self, value::MapDeserializer, DeserializeOwned, IntoDeserializer, Visitor},
+  forward_to_deserialize_any,
+  Deserializer,
+  Serialize,
+};
+#[cfg(feature = "serde-json")]
+use serde_json::Value;
+
 /// Representation for the "=" or "~" operator in `XADD`, etc.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum XCapTrim {
@@ -473,6 +485,36 @@ where
   }
 }
 
+/// An entry read from a stream by a [StreamConsumer](crate::clients::StreamConsumer).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamEntry {
+  /// The ID of the entry.
+  pub id:         Str,
+  /// The fields and values in the entry.
+  pub fields:     HashMap<Str, RedisValue>,
+  /// The number of times the entry has been delivered to a consumer in the group.
+  pub deliveries: u64,
+}
+
+impl StreamEntry {
+  /// Deserialize the fields in the entry into a struct.
+  ///
+  /// See [StreamFields](crate::types::StreamFields) for more information.
+  #[cfg(feature = "serde-json")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+  pub fn deserialize_fields<T>(&self) -> Result<T, RedisError>
+  where
+    T: DeserializeOwned,
+  {
+    let mut fields = Vec::with_capacity(self.fields.len());
+    for (key, value) in self.fields.iter() {
+      fields.push((key.to_string(), value.clone().convert::<String>()?));
+    }
+
+    from_stream_fields(fields)
+  }
+}
+
 /// A generic helper type describing the ID and associated map for each record in a stream.
 ///
 /// See the [XReadResponse](crate::types::XReadResponse) type for more information.
@@ -490,3 +532,298 @@ pub type XReadValue<I, K, V> = (I, HashMap<K, V>);
 /// To support heterogeneous values in the map describing each stream element it is recommended to declare the last
 /// type as `RedisValue` and [convert](crate::types::RedisValue::convert) as needed.
 pub type XReadResponse<K1, I, K2, V> = HashMap<K1, Vec<XReadValue<I, K2, V>>>;
+
+/// A wrapper type that converts a struct to or from the flat field/value pairs in a stream entry.
+///
+/// The wrapped value can be used as the `fields` argument to [xadd](crate::interfaces::StreamsInterface::xadd),
+/// or as the type of the fields in a response from `XRANGE`, `XCLAIM`, etc. The `*_typed` functions on the
+/// [StreamsInterface](crate::interfaces::StreamsInterface) use this type to deserialize entries directly.
+///
+/// When serializing the value must be a struct or map. String fields are sent as-is, numbers and booleans are
+/// formatted as strings, `null` fields are skipped, and nested sequences or maps are JSON-encoded. Deserialization
+/// reverses this process based on the type of each field.
+///
+/// ```rust no_run
+/// # use fred::prelude::*;
+/// # use fred::types::StreamFields;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Debug, Deserialize, Serialize)]
+/// struct Order {
+///   id:    u64,
+///   items: Vec<String>,
+/// }
+///
+/// async fn example(client: &RedisClient) -> Result<(), RedisError> {
+///   let order = Order { id: 1, items: vec!["a".into(), "b".into()] };
+///   let _: () = client.xadd("orders", false, None, "*", StreamFields(order)).await?;
+///
+///   let entries: Vec<(String, StreamFields<Order>)> = client.xrange("orders", "-", "+", None).await?;
+///   for (id, StreamFields(order)) in entries.into_iter() {
+///     println!("{}: {:?}", id, order);
+///   }
+///   Ok(())
+/// }
+/// ```
+#[cfg(feature = "serde-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamFields<T>(pub T);
+
+#[cfg(feature = "serde-json")]
+impl<T> StreamFields<T> {
+  /// Read the inner value.
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+#[cfg(feature = "serde-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+impl<T> TryFrom<StreamFields<T>> for MultipleOrderedPairs
+where
+  T: Serialize,
+{
+  type Error = RedisError;
+
+  fn try_from(value: StreamFields<T>) -> Result<Self, Self::Error> {
+    let map = match serde_json::to_value(&value.0)? {
+      Value::Object(map) => map,
+      _ => {
+        return Err(RedisError::new(
+          RedisErrorKind::InvalidArgument,
+          "Expected a struct or map of stream fields.",
+        ))
+      },
+    };
+
+    let mut values = Vec::with_capacity(map.len());
+    for (key, value) in map.into_iter() {
+      let value = match value {
+        Value::Null => continue,
+        Value::String(s) => s,
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        value => value.to_string(),
+      };
+
+      values.push((RedisKey::from(key), RedisValue::from(value)));
+    }
+
+    Ok(MultipleOrderedPairs { values })
+  }
+}
+
+#[cfg(feature = "serde-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+impl<T> FromRedis for StreamFields<T>
+where
+  T: DeserializeOwned,
+{
+  fn from_value(value: RedisValue) -> Result<Self, RedisError> {
+    let fields: Vec<(String, String)> = value.convert()?;
+    from_stream_fields(fields).map(StreamFields)
+  }
+}
+
+#[cfg(feature = "serde-json")]
+fn from_stream_fields<T>(fields: Vec<(String, String)>) -> Result<T, RedisError>
+where
+  T: DeserializeOwned,
+{
+  let fields = fields.into_iter().map(|(key, value)| (key, FieldDeserializer(value)));
+  T::deserialize(MapDeserializer::<_, serde_json::Error>::new(fields)).map_err(|e| e.into())
+}
+
+/// A deserializer for a stream field value that uses the target type to decide whether to parse the value as JSON.
+#[cfg(feature = "serde-json")]
+struct FieldDeserializer(String);
+
+#[cfg(feature = "serde-json")]
+impl<'de> IntoDeserializer<'de, serde_json::Error> for FieldDeserializer {
+  type Deserializer = Self;
+
+  fn into_deserializer(self) -> Self::Deserializer {
+    self
+  }
+}
+
+#[cfg(feature = "serde-json")]
+impl<'de> Deserializer<'de> for FieldDeserializer {
+  type Error = serde_json::Error;
+
+  forward_to_deserialize_any! {
+    bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 unit unit_struct seq tuple tuple_struct map struct ignored_any
+  }
+
+  fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+  where
+    V: Visitor<'de>,
+  {
+    match serde_json::from_str::<Value>(&self.0) {
+      Ok(value) => value.deserialize_any(visitor),
+      Err(_) => visitor.visit_string(self.0),
+    }
+  }
+
+  fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+  where
+    V: Visitor<'de>,
+  {
+    visitor.visit_string(self.0)
+  }
+
+  fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+  where
+    V: Visitor<'de>,
+  {
+    visitor.visit_string(self.0)
+  }
+
+  fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+  where
+    V: Visitor<'de>,
+  {
+    visitor.visit_string(self.0)
+  }
+
+  fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+  where
+    V: Visitor<'de>,
+  {
+    visitor.visit_string(self.0)
+  }
+
+  fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+  where
+    V: Visitor<'de>,
+  {
+    visitor.visit_byte_buf(self.0.into_bytes())
+  }
+
+  fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+  where
+    V: Visitor<'de>,
+  {
+    visitor.visit_byte_buf(self.0.into_bytes())
+  }
+
+  fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+  where
+    V: Visitor<'de>,
+  {
+    visitor.visit_some(self)
+  }
+
+  fn deserialize_newtype_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, Self::Error>
+  where
+    V: Visitor<'de>,
+  {
+    visitor.visit_newtype_struct(self)
+  }
+
+  fn deserialize_enum<V>(
+    self,
+    name: &'static str,
+    variants: &'static [&'static str],
+    visitor: V,
+  ) -> Result<V::Value, Self::Error>
+  where
+    V: Visitor<'de>,
+  {
+    match serde_json::from_str::<Value>(&self.0) {
+      Ok(value @ Value::Object(_)) => value.deserialize_enum(name, variants, visitor),
+      _ => de::Deserializer::deserialize_enum(self.0.into_deserializer(), name, variants, visitor),
+    }
+  }
+}
+
+#[cfg(all(test, feature = "serde-json"))]
+mod tests {
+  use super::*;
+  use serde::Deserialize;
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  enum Status {
+    Active,
+    Paused { until: u64 },
+  }
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct Order {
+    id:     u64,
+    name:   String,
+    paid:   bool,
+    items:  Vec<String>,
+    note:   Option<String>,
+    status: Status,
+    paused: Status,
+  }
+
+  fn order() -> Order {
+    Order {
+      id:     1,
+      name:   "123".into(),
+      paid:   true,
+      items:  vec!["a".into(), "b".into()],
+      note:   None,
+      status: Status::Active,
+      paused: Status::Paused { until: 10 },
+    }
+  }
+
+  #[test]
+  fn should_flatten_stream_fields() {
+    let pairs: MultipleOrderedPairs = StreamFields(order()).try_into().unwrap();
+    let expected: Vec<(RedisKey, RedisValue)> = vec![
+      ("id".into(), "1".into()),
+      ("items".into(), r#"["a","b"]"#.into()),
+      ("name".into(), "123".into()),
+      ("paid".into(), "true".into()),
+      ("paused".into(), r#"{"Paused":{"until":10}}"#.into()),
+      ("status".into(), "Active".into()),
+    ];
+
+    assert_eq!(pairs.inner(), expected);
+  }
+
+  #[test]
+  fn should_deserialize_stream_fields() {
+    let pairs: MultipleOrderedPairs = StreamFields(order()).try_into().unwrap();
+    let mut values = Vec::new();
+    for (key, value) in pairs.inner().into_iter() {
+      values.push(RedisValue::String(key.as_str().unwrap().into()));
+      values.push(value);
+    }
+
+    let fields: StreamFields<Order> = RedisValue::Array(values).convert().unwrap();
+    assert_eq!(fields.into_inner(), order());
+  }
+
+  #[test]
+  fn should_deserialize_stream_entry_fields() {
+    let mut fields = HashMap::new();
+    fields.insert("id".into(), RedisValue::Integer(2));
+    fields.insert("name".into(), "foo".into());
+    fields.insert("paid".into(), "false".into());
+    fields.insert("items".into(), "[]".into());
+    fields.insert("note".into(), "bar".into());
+    fields.insert("status".into(), "Active".into());
+    fields.insert("paused".into(), r#"{"Paused":{"until":1}}"#.into());
+    let entry = StreamEntry {
+      id: "1-0".into(),
+      fields,
+      deliveries: 1,
+    };
+
+    let expected = Order {
+      id:     2,
+      name:   "foo".into(),
+      paid:   false,
+      items:  vec![],
+      note:   Some("bar".into()),
+      status: Status::Active,
+      paused: Status::Paused { until: 1 },
+    };
+    assert_eq!(entry.deserialize_fields::<Order>().unwrap(), expected);
+  }
+}
@@ -0,0 +1,65 @@
+use crate::{error::RedisError, types::RedisValue};
+use bytes_utils::Str;
+use std::{collections::HashMap, convert::TryFrom};
+
+/// A matching range from the [lcs](https://redis.io/commands/lcs) command with the `IDX` argument.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LcsMatch {
+  /// The inclusive start and end offsets of the match in the first key.
+  pub first:  (i64, i64),
+  /// The inclusive start and end offsets of the match in the second key.
+  pub second: (i64, i64),
+  /// The length of the match, if `WITHMATCHLEN` was provided.
+  pub len:    Option<i64>,
+}
+
+impl TryFrom<RedisValue> for LcsMatch {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    let mut values: Vec<RedisValue> = value.convert()?;
+    if values.len() < 2 || values.len() > 3 {
+      return Err(RedisError::new_parse("Invalid LCS match."));
+    }
+
+    let len = if values.len() == 3 {
+      Some(values.pop().unwrap().convert()?)
+    } else {
+      None
+    };
+    let second: (i64, i64) = values.pop().unwrap().convert()?;
+    let first: (i64, i64) = values.pop().unwrap().convert()?;
+
+    Ok(LcsMatch { first, second, len })
+  }
+}
+
+/// The response from the [lcs](https://redis.io/commands/lcs) command with the `IDX` argument.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LcsResult {
+  pub matches: Vec<LcsMatch>,
+  /// The length of the longest common subsequence.
+  pub len:     i64,
+}
+
+impl TryFrom<RedisValue> for LcsResult {
+  type Error = RedisError;
+
+  fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+    let mut values: HashMap<Str, RedisValue> = value.convert()?;
+
+    let matches = match values.remove("matches") {
+      Some(RedisValue::Array(matches)) => matches
+        .into_iter()
+        .map(LcsMatch::try_from)
+        .collect::<Result<Vec<_>, _>>()?,
+      _ => return Err(RedisError::new_parse("Missing LCS matches.")),
+    };
+    let len = match values.remove("len") {
+      Some(len) => len.convert()?,
+      None => return Err(RedisError::new_parse("Missing LCS length.")),
+    };
+
+    Ok(LcsResult { matches, len })
+  }
+}
@@ -36,6 +36,7 @@ use tokio::{
   sync::{
     broadcast::{channel as broadcast_channel, Sender as BroadcastSender},
     oneshot::channel as oneshot_channel,
+    Semaphore,
   },
   time::sleep,
 };
@@ -46,6 +47,8 @@ use urlencoding::decode as percent_decode;
 use crate::protocol::tls::{TlsConfig, TlsConnector};
 #[cfg(any(feature = "full-tracing", feature = "partial-tracing"))]
 use crate::trace;
+#[cfg(feature = "credential-provider")]
+use crate::{clients::RedisClient, commands};
 #[cfg(feature = "transactions")]
 use parking_lot::Mutex;
 #[cfg(feature = "transactions")]
@@ -206,6 +209,14 @@ pub fn incr_atomic(size: &Arc<AtomicUsize>) -> usize {
   size.fetch_add(1, Ordering::AcqRel).saturating_add(1)
 }
 
+pub fn dedicated_semaphore(connection: &ConnectionConfig) -> Semaphore {
+  if connection.max_dedicated_connections == 0 {
+    Semaphore::new(Semaphore::MAX_PERMITS)
+  } else {
+    Semaphore::new(connection.max_dedicated_connections)
+  }
+}
+
 pub fn read_atomic(size: &Arc<AtomicUsize>) -> usize {
   size.load(Ordering::Acquire)
 }
@@ -334,6 +345,62 @@ pub fn reset_router_task(inner: &Arc<RedisClientInner>) {
   }
 }
 
+/// Spawn a task that re-authenticates existing connections on the credential provider's refresh interval, replacing
+/// any previous refresh task.
+///
+/// The task skips refreshes while the client is reconnecting and exits when the connection(s) are closed or the
+/// connection task stops.
+#[cfg(feature = "credential-provider")]
+pub fn spawn_credential_refresh(inner: &Arc<RedisClientInner>) {
+  abort_credential_refresh(inner);
+  let interval = match inner.config.credential_provider {
+    Some(ref provider) => match provider.refresh_interval() {
+      Some(interval) => interval,
+      None => return,
+    },
+    None => return,
+  };
+  let mut close_rx = inner.notifications.close.subscribe();
+  let client = RedisClient { inner: inner.clone() };
+
+  let task = tokio::spawn(async move {
+    let inner = client.inner.clone();
+
+    loop {
+      tokio::select! {
+        _ = sleep(interval) => {},
+        _ = close_rx.recv() => break,
+      };
+      if client.state() != ClientState::Connected {
+        // the router stores the command receiver when it exits instead of reconnecting
+        if inner.has_command_rx() {
+          break;
+        }
+        continue;
+      }
+
+      _debug!(inner, "Refreshing credentials on existing connections.");
+      let result = match inner.read_credentials(None).await {
+        Ok((username, Some(password))) => commands::server::auth(&client, username, password.into()).await,
+        Ok((_, None)) => Ok(()),
+        Err(e) => Err(e),
+      };
+      if let Err(e) = result {
+        _warn!(inner, "Failed to refresh credentials: {:?}", e);
+      }
+    }
+  });
+  inner.credential_refresh.lock().replace(task);
+}
+
+/// Stop the credential refresh task, if running.
+#[cfg(feature = "credential-provider")]
+pub fn abort_credential_refresh(inner: &Arc<RedisClientInner>) {
+  if let Some(task) = inner.credential_refresh.lock().take() {
+    task.abort();
+  }
+}
+
 /// Whether the router should check and interrupt the blocked command.
 async fn should_enforce_blocking_policy(inner: &Arc<RedisClientInner>, command: &RedisCommand) -> bool {
   if command.kind.closes_connection() {
@@ -524,6 +591,110 @@ pub async fn backchannel_request_response(
   backchannel.request_response(inner, &server, command).await
 }
 
+/// Read the primary nodes in the cluster, or `None` for the centralized or sentinel primary server.
+pub fn primary_nodes<C: ClientLike>(client: &C) -> Vec<Option<Server>> {
+  if client.is_clustered() {
+    client
+      .inner()
+      .with_cluster_state(|state| Ok(state.unique_primary_nodes()))
+      .unwrap_or_default()
+      .into_iter()
+      .map(Some)
+      .collect()
+  } else {
+    vec![None]
+  }
+}
+
+/// Send a command to `server`, or route it normally if `None`, and convert the response.
+pub async fn node_request<C: ClientLike>(
+  client: &C,
+  server: Option<Server>,
+  kind: RedisCommandKind,
+  args: Vec<RedisValue>,
+) -> Result<RedisValue, RedisError> {
+  let frame = request_response(client, move || {
+    let mut command: RedisCommand = (kind, args).into();
+    command.cluster_node = server;
+    Ok(command)
+  })
+  .await?;
+
+  protocol_utils::frame_to_results(frame)
+}
+
+/// Check whether `value` matches the glob-style `pattern`, following the same rules as the server's `KEYS` and
+/// `PSUBSCRIBE` commands.
+pub fn glob_match(pattern: &[u8], value: &[u8]) -> bool {
+  let (mut p, mut v) = (0, 0);
+
+  while p < pattern.len() && v < value.len() {
+    match pattern[p] {
+      b'*' => {
+        while p + 1 < pattern.len() && pattern[p + 1] == b'*' {
+          p += 1;
+        }
+        if p + 1 == pattern.len() {
+          return true;
+        }
+
+        return (v .. value.len()).any(|idx| glob_match(&pattern[p + 1 ..], &value[idx ..]));
+      },
+      b'?' => {},
+      b'[' => {
+        p += 1;
+        let not = pattern.get(p) == Some(&b'^');
+        if not {
+          p += 1;
+        }
+
+        let mut matched = false;
+        loop {
+          if p >= pattern.len() {
+            p -= 1;
+            break;
+          } else if pattern[p] == b'\\' && p + 1 < pattern.len() {
+            p += 1;
+            matched |= pattern[p] == value[v];
+          } else if pattern[p] == b']' {
+            break;
+          } else if p + 2 < pattern.len() && pattern[p + 1] == b'-' {
+            let (start, end) = (pattern[p].min(pattern[p + 2]), pattern[p].max(pattern[p + 2]));
+            matched |= value[v] >= start && value[v] <= end;
+            p += 2;
+          } else {
+            matched |= pattern[p] == value[v];
+          }
+          p += 1;
+        }
+
+        if matched == not {
+          return false;
+        }
+      },
+      b'\\' if p + 1 < pattern.len() => {
+        p += 1;
+        if pattern[p] != value[v] {
+          return false;
+        }
+      },
+      c => {
+        if c != value[v] {
+          return false;
+        }
+      },
+    }
+
+    p += 1;
+    v += 1;
+  }
+
+  while p < pattern.len() && pattern[p] == b'*' {
+    p += 1;
+  }
+  p == pattern.len() && v == value.len()
+}
+
 pub fn check_empty_keys(keys: &MultipleKeys) -> Result<(), RedisError> {
   if keys.len() == 0 {
     Err(RedisError::new(
@@ -866,6 +1037,40 @@ mod tests {
     RedisValue::Array(v)
   }
 
+  #[test]
+  fn should_glob_match_patterns() {
+    let cases = [
+      ("*", "foo", true),
+      ("foo*", "foobar", true),
+      ("foo*", "fo", false),
+      ("*bar", "foobar", true),
+      ("f*b*r", "foobar", true),
+      ("f?o", "foo", true),
+      ("f?o", "fo", false),
+      ("h[ae]llo", "hello", true),
+      ("h[ae]llo", "hillo", false),
+      ("h[^e]llo", "hallo", true),
+      ("h[^e]llo", "hello", false),
+      ("h[a-c]llo", "hbllo", true),
+      ("h[a-c]llo", "hdllo", false),
+      ("foo\\*", "foo*", true),
+      ("foo\\*", "foobar", false),
+      ("foo", "foo", true),
+      ("foo", "foobar", false),
+      ("a*?", "a", false),
+    ];
+
+    for (pattern, value, expected) in cases.into_iter() {
+      assert_eq!(
+        glob_match(pattern.as_bytes(), value.as_bytes()),
+        expected,
+        "{} {}",
+        pattern,
+        value
+      );
+    }
+  }
+
   #[test]
   fn should_not_panic_with_zero_jitter() {
     assert_eq!(add_jitter(10, 0), 10);
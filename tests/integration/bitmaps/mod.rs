@@ -0,0 +1,171 @@
+use fred::{
+  prelude::*,
+  types::{
+    BitOperation,
+    BitUnit,
+    BitfieldEncoding,
+    BitfieldOffset,
+    BitfieldOperation,
+    BitfieldOperations,
+    BitfieldOverflow,
+  },
+};
+
+pub async fn should_setbit_and_getbit(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_null!(client, "foo");
+
+  let old: u8 = client.setbit("foo", 7, 1).await?;
+  assert_eq!(old, 0);
+  let old: u8 = client.setbit("foo", 7, 0).await?;
+  assert_eq!(old, 1);
+  let _: () = client.setbit("foo", 100, 1).await?;
+
+  assert_eq!(client.getbit::<u8, _>("foo", 7).await?, 0);
+  assert_eq!(client.getbit::<u8, _>("foo", 100).await?, 1);
+  assert_eq!(client.getbit::<u8, _>("foo", 1000).await?, 0);
+  Ok(())
+}
+
+pub async fn should_bitcount_with_ranges(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_null!(client, "foo");
+
+  let _: () = client.set("foo", "foobar", None, None, false).await?;
+  assert_eq!(client.bitcount::<i64, _>("foo", None, None).await?, 26);
+  assert_eq!(client.bitcount::<i64, _>("foo", Some((1, 1)), None).await?, 6);
+
+  if client.server_version().unwrap().major >= 7 {
+    assert_eq!(
+      client
+        .bitcount::<i64, _>("foo", Some((1, 1)), Some(BitUnit::Byte))
+        .await?,
+      6
+    );
+    assert_eq!(
+      client
+        .bitcount::<i64, _>("foo", Some((5, 30)), Some(BitUnit::Bit))
+        .await?,
+      17
+    );
+  }
+  Ok(())
+}
+
+pub async fn should_bitpos_with_ranges(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_null!(client, "foo");
+
+  let _: () = client
+    .set("foo", vec![0xff_u8, 0xf0, 0x00].as_slice(), None, None, false)
+    .await?;
+  assert_eq!(client.bitpos::<i64, _>("foo", 0, None, None, None).await?, 12);
+  assert_eq!(client.bitpos::<i64, _>("foo", 1, Some(2), None, None).await?, -1);
+  assert_eq!(client.bitpos::<i64, _>("foo", 0, Some(1), Some(2), None).await?, 12);
+
+  if client.server_version().unwrap().major >= 7 {
+    assert_eq!(
+      client
+        .bitpos::<i64, _>("foo", 1, Some(7), Some(15), Some(BitUnit::Bit))
+        .await?,
+      7
+    );
+  }
+  Ok(())
+}
+
+pub async fn should_bitop_keys(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_null!(client, "foo{1}");
+  check_null!(client, "bar{1}");
+  check_null!(client, "baz{1}");
+
+  let _: () = client.set("foo{1}", "abc", None, None, false).await?;
+  let _: () = client.set("bar{1}", "abc", None, None, false).await?;
+
+  let len: i64 = client
+    .bitop(BitOperation::And, "baz{1}", vec!["foo{1}", "bar{1}"])
+    .await?;
+  assert_eq!(len, 3);
+  assert_eq!(client.get::<String, _>("baz{1}").await?, "abc");
+
+  let _: () = client
+    .bitop(BitOperation::Xor, "baz{1}", vec!["foo{1}", "bar{1}"])
+    .await?;
+  assert_eq!(client.bitcount::<i64, _>("baz{1}", None, None).await?, 0);
+
+  let _: () = client.bitop(BitOperation::Not, "baz{1}", "foo{1}").await?;
+  let value: Vec<u8> = client.get("baz{1}").await?;
+  assert_eq!(value, b"abc".iter().map(|b| !b).collect::<Vec<u8>>());
+  Ok(())
+}
+
+pub async fn should_error_bitop_not_with_multiple_keys(
+  client: RedisClient,
+  _: RedisConfig,
+) -> Result<(), RedisError> {
+  let _: () = client
+    .bitop(BitOperation::Not, "baz{1}", vec!["foo{1}", "bar{1}"])
+    .await?;
+  Ok(())
+}
+
+pub async fn should_run_bitfield_operations(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_null!(client, "foo");
+
+  let mut operations = BitfieldOperations::new();
+  operations
+    .set(BitfieldEncoding::Unsigned(8), 0_u64, 100)
+    .incrby(BitfieldEncoding::Signed(5), 100_u64, 1)
+    .get(BitfieldEncoding::Unsigned(4), 0_u64);
+  let results: Vec<i64> = client.bitfield("foo", &operations).await?;
+  assert_eq!(results, vec![0, 1, 6]);
+
+  let results: Vec<i64> = client
+    .bitfield("foo", vec![BitfieldOperation::Get {
+      encoding: BitfieldEncoding::Unsigned(8),
+      offset:   BitfieldOffset::Multiplied(0),
+    }])
+    .await?;
+  assert_eq!(results, vec![100]);
+  Ok(())
+}
+
+pub async fn should_run_bitfield_overflow_operations(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_null!(client, "foo");
+
+  let mut operations = BitfieldOperations::new();
+  operations
+    .incrby(BitfieldEncoding::Unsigned(2), 100_u64, 1)
+    .overflow(BitfieldOverflow::Sat)
+    .incrby(BitfieldEncoding::Unsigned(2), 102_u64, 1);
+  let results: Vec<i64> = client.bitfield("foo", &operations).await?;
+  assert_eq!(results, vec![1, 1]);
+  let results: Vec<i64> = client.bitfield("foo", &operations).await?;
+  assert_eq!(results, vec![2, 2]);
+  let results: Vec<i64> = client.bitfield("foo", &operations).await?;
+  assert_eq!(results, vec![3, 3]);
+  let results: Vec<i64> = client.bitfield("foo", &operations).await?;
+  assert_eq!(results, vec![0, 3]);
+
+  let mut operations = BitfieldOperations::new();
+  operations
+    .overflow(BitfieldOverflow::Fail)
+    .incrby(BitfieldEncoding::Unsigned(2), 102_u64, 1);
+  let results: Vec<Option<i64>> = client.bitfield("foo", &operations).await?;
+  assert_eq!(results, vec![None]);
+  Ok(())
+}
+
+pub async fn should_run_bitfield_ro(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_null!(client, "foo");
+
+  let _: () = client.set("foo", "abc", None, None, false).await?;
+  let mut operations = BitfieldOperations::new();
+  operations
+    .get(BitfieldEncoding::Unsigned(8), BitfieldOffset::Multiplied(0))
+    .get(BitfieldEncoding::Unsigned(8), BitfieldOffset::Multiplied(2));
+  let results: Vec<i64> = client.bitfield_ro("foo", &operations).await?;
+  assert_eq!(results, vec![97, 99]);
+
+  let mut operations = BitfieldOperations::new();
+  operations.set(BitfieldEncoding::Unsigned(8), 0_u64, 1);
+  assert!(client.bitfield_ro::<Vec<i64>, _, _>("foo", &operations).await.is_err());
+  Ok(())
+}
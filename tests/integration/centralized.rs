@@ -25,6 +25,12 @@ mod keys {
   centralized_test_panic!(keys, should_error_renamenx_does_not_exist);
   centralized_test!(keys, should_rename);
   centralized_test!(keys, should_renamenx);
+  centralized_test!(keys, should_read_key_type_and_encoding);
+  centralized_test!(keys, should_expire_with_options);
+  centralized_test!(keys, should_sort_values);
+  centralized_test!(keys, should_set_and_get_with_expiration);
+  centralized_test!(keys, should_get_substr);
+  centralized_test!(keys, should_read_lcs_matches);
 
   centralized_test!(keys, should_get_keys_from_pool_in_a_stream);
 }
@@ -33,9 +39,22 @@ mod keys {
 mod multi {
 
   centralized_test!(multi, should_run_get_set_trx);
+  centralized_test!(multi, should_retry_watched_transaction);
+  centralized_test!(multi, should_retry_watched_transaction_after_connection_closes);
   centralized_test_panic!(multi, should_run_error_get_set_trx);
 }
 
+mod bitmaps {
+  centralized_test!(bitmaps, should_setbit_and_getbit);
+  centralized_test!(bitmaps, should_bitcount_with_ranges);
+  centralized_test!(bitmaps, should_bitpos_with_ranges);
+  centralized_test!(bitmaps, should_bitop_keys);
+  centralized_test_panic!(bitmaps, should_error_bitop_not_with_multiple_keys);
+  centralized_test!(bitmaps, should_run_bitfield_operations);
+  centralized_test!(bitmaps, should_run_bitfield_overflow_operations);
+  centralized_test!(bitmaps, should_run_bitfield_ro);
+}
+
 mod other {
   centralized_test!(other, should_connect_correctly_via_init_interface);
   centralized_test!(other, should_fail_with_bad_host_via_init_interface);
@@ -86,11 +105,24 @@ mod other {
   centralized_test!(other, should_use_resp3_codec_example);
   #[cfg(feature = "codec")]
   centralized_test!(other, should_use_resp2_codec_example);
+  #[cfg(feature = "credential-provider")]
+  centralized_test!(other, should_use_credential_provider);
+  #[cfg(feature = "credential-provider")]
+  centralized_test!(other, should_refresh_credentials_after_reconnecting);
+  #[cfg(feature = "credential-provider")]
+  centralized_test!(other, should_run_one_credential_refresh_task);
 }
 
 mod pool {
   centralized_test!(pool, should_connect_and_ping_static_pool_single_conn);
   centralized_test!(pool, should_connect_and_ping_static_pool_two_conn);
+  #[cfg(feature = "transactions")]
+  centralized_test!(pool, should_acquire_dedicated_client_from_pool);
+  centralized_test!(pool, should_reuse_dedicated_clients);
+  centralized_test!(pool, should_resize_elastic_pool);
+  centralized_test!(pool, should_replace_unhealthy_clients);
+  centralized_test!(pool, should_route_with_pool_routing_policies);
+  centralized_test!(pool, should_merge_pool_events_and_messages);
 }
 
 mod hashes {
@@ -107,12 +139,20 @@ mod hashes {
   centralized_test!(hashes, should_get_random_field);
   centralized_test!(hashes, should_get_strlen);
   centralized_test!(hashes, should_get_values);
+  centralized_test!(hashes, should_expire_and_persist_fields);
+  centralized_test!(hashes, should_get_field_expire_time);
+  centralized_test!(hashes, should_getdel_getex_and_setex_fields);
 }
 
 mod pubsub {
   centralized_test!(pubsub, should_publish_and_recv_messages);
   centralized_test!(pubsub, should_psubscribe_and_recv_messages);
   centralized_test!(pubsub, should_unsubscribe_from_all);
+  #[cfg(feature = "subscriber-client")]
+  centralized_test!(pubsub, should_subscribe_with_channel_streams);
+  #[cfg(feature = "subscriber-client")]
+  centralized_test!(pubsub, should_resubscribe_after_dropping_channel_stream);
+  centralized_test!(pubsub, should_recv_typed_keyspace_notifications);
 
   centralized_test!(pubsub, should_get_pubsub_channels);
   centralized_test!(pubsub, should_get_pubsub_numpat);
@@ -134,6 +174,10 @@ mod scanning {
   centralized_test!(scanning, should_hscan_hash);
   centralized_test!(scanning, should_sscan_set);
   centralized_test!(scanning, should_zscan_sorted_set);
+  centralized_test!(scanning, should_scan_from_checkpoint);
+  centralized_test!(scanning, should_unlink_matching);
+  centralized_test!(scanning, should_expire_matching);
+  centralized_test!(scanning, should_dump_and_copy_matching);
 }
 
 mod slowlog {
@@ -149,10 +193,15 @@ mod server {
   centralized_test!(server, should_read_server_info);
   centralized_test!(server, should_ping_server);
   centralized_test!(server, should_run_custom_command);
+  centralized_test!(server, should_run_custom_command_with_key_specs);
+  centralized_test!(server, should_run_custom_command_with_key_specs_in_pipeline);
   centralized_test!(server, should_read_last_save);
   centralized_test!(server, should_read_db_size);
   centralized_test!(server, should_start_bgsave);
   centralized_test!(server, should_do_bgrewriteaof);
+  centralized_test!(server, should_read_command_info);
+  centralized_test!(server, should_read_latency_events);
+  centralized_test!(server, should_list_modules);
 }
 
 mod sets {
@@ -326,12 +375,19 @@ mod streams {
   centralized_test!(streams, should_xclaim_multiple_ids);
   centralized_test!(streams, should_xclaim_with_justid);
   centralized_test!(streams, should_xautoclaim_default);
+  centralized_test!(streams, should_consume_stream_with_dead_letter);
+  centralized_test!(streams, should_reject_zero_consumer_block);
+  #[cfg(feature = "serde-json")]
+  centralized_test!(streams, should_xadd_and_read_typed_entries);
 }
 
 #[cfg(feature = "client-tracking")]
 mod tracking {
   centralized_test!(tracking, should_invalidate_foo_resp3);
   centralized_test!(tracking, should_invalidate_foo_resp2_centralized);
+  centralized_test!(tracking, should_serve_and_invalidate_client_cache);
+  centralized_test!(tracking, should_clear_client_cache_after_select);
+  centralized_test!(tracking, should_invalidate_foo_resp2_redirect);
 }
 
 // The CI settings for redis-stack only support centralized configs for now.
@@ -362,3 +418,26 @@ mod timeseries {
   centralized_test!(timeseries, should_madd_and_mrange);
   centralized_test!(timeseries, should_madd_and_mrevrange);
 }
+
+#[cfg(feature = "redis-search")]
+mod redisearch {
+  centralized_test!(redisearch, should_list_create_and_drop_index);
+  centralized_test!(redisearch, should_search_with_typed_results);
+  centralized_test!(redisearch, should_aggregate_with_groupby);
+  centralized_test!(redisearch, should_aggregate_with_cursor_stream);
+  centralized_test!(redisearch, should_alter_index_and_explain);
+  centralized_test!(redisearch, should_manage_aliases);
+  centralized_test!(redisearch, should_update_synonyms_and_spellcheck);
+  centralized_test!(redisearch, should_search_knn_with_vector_index);
+}
+
+#[cfg(feature = "redis-bloom")]
+mod redis_bloom {
+  centralized_test!(redis_bloom, should_add_and_check_bloom_filter);
+  centralized_test!(redis_bloom, should_insert_into_bloom_filter);
+  centralized_test!(redis_bloom, should_migrate_bloom_filter_with_scandump);
+  centralized_test!(redis_bloom, should_use_cuckoo_filter);
+  centralized_test!(redis_bloom, should_use_count_min_sketch);
+  centralized_test!(redis_bloom, should_use_topk_sketch);
+  centralized_test!(redis_bloom, should_use_tdigest_sketch);
+}
@@ -26,6 +26,13 @@ mod keys {
   cluster_test_panic!(keys, should_error_renamenx_does_not_exist);
   cluster_test!(keys, should_rename);
   cluster_test!(keys, should_renamenx);
+  cluster_test!(keys, should_read_key_type_and_encoding);
+  cluster_test!(keys, should_expire_with_options);
+  cluster_test!(keys, should_sort_values);
+  cluster_test!(keys, should_set_and_get_with_expiration);
+  cluster_test!(keys, should_get_substr);
+  cluster_test!(keys, should_read_lcs_matches);
+  cluster_test!(keys, should_split_cross_slot_commands);
 
   cluster_test!(keys, should_get_keys_from_pool_in_a_stream);
 }
@@ -34,10 +41,23 @@ mod keys {
 mod multi {
 
   cluster_test!(multi, should_run_get_set_trx);
+  cluster_test!(multi, should_retry_watched_transaction);
+  cluster_test!(multi, should_retry_watched_transaction_after_connection_closes);
   cluster_test_panic!(multi, should_fail_with_hashslot_error);
   cluster_test_panic!(multi, should_run_error_get_set_trx);
 }
 
+mod bitmaps {
+  cluster_test!(bitmaps, should_setbit_and_getbit);
+  cluster_test!(bitmaps, should_bitcount_with_ranges);
+  cluster_test!(bitmaps, should_bitpos_with_ranges);
+  cluster_test!(bitmaps, should_bitop_keys);
+  cluster_test_panic!(bitmaps, should_error_bitop_not_with_multiple_keys);
+  cluster_test!(bitmaps, should_run_bitfield_operations);
+  cluster_test!(bitmaps, should_run_bitfield_overflow_operations);
+  cluster_test!(bitmaps, should_run_bitfield_ro);
+}
+
 mod other {
   cluster_test!(other, should_connect_correctly_via_init_interface);
   cluster_test!(other, should_fail_with_bad_host_via_init_interface);
@@ -89,11 +109,24 @@ mod other {
   cluster_test!(other, should_use_resp3_codec_example);
   #[cfg(feature = "codec")]
   cluster_test!(other, should_use_resp2_codec_example);
+  #[cfg(feature = "credential-provider")]
+  cluster_test!(other, should_use_credential_provider);
+  #[cfg(feature = "credential-provider")]
+  cluster_test!(other, should_refresh_credentials_after_reconnecting);
+  #[cfg(feature = "credential-provider")]
+  cluster_test!(other, should_run_one_credential_refresh_task);
 }
 
 mod pool {
   cluster_test!(pool, should_connect_and_ping_static_pool_single_conn);
   cluster_test!(pool, should_connect_and_ping_static_pool_two_conn);
+  #[cfg(feature = "transactions")]
+  cluster_test!(pool, should_acquire_dedicated_client_from_pool);
+  cluster_test!(pool, should_reuse_dedicated_clients);
+  cluster_test!(pool, should_resize_elastic_pool);
+  cluster_test!(pool, should_replace_unhealthy_clients);
+  cluster_test!(pool, should_route_with_pool_routing_policies);
+  cluster_test!(pool, should_merge_pool_events_and_messages);
 }
 
 mod hashes {
@@ -111,6 +144,9 @@ mod hashes {
   cluster_test!(hashes, should_get_random_field);
   cluster_test!(hashes, should_get_strlen);
   cluster_test!(hashes, should_get_values);
+  cluster_test!(hashes, should_expire_and_persist_fields);
+  cluster_test!(hashes, should_get_field_expire_time);
+  cluster_test!(hashes, should_getdel_getex_and_setex_fields);
 }
 
 mod pubsub {
@@ -118,6 +154,11 @@ mod pubsub {
   cluster_test!(pubsub, should_publish_and_recv_messages);
   cluster_test!(pubsub, should_psubscribe_and_recv_messages);
   cluster_test!(pubsub, should_unsubscribe_from_all);
+  #[cfg(feature = "subscriber-client")]
+  cluster_test!(pubsub, should_subscribe_with_channel_streams);
+  #[cfg(feature = "subscriber-client")]
+  cluster_test!(pubsub, should_resubscribe_after_dropping_channel_stream);
+  cluster_test!(pubsub, should_recv_typed_keyspace_notifications);
 
   // TODO fix these tests so they work with clusters. the connection management logic could be better.
   // cluster_test!(pubsub, should_get_pubsub_channels);
@@ -141,6 +182,14 @@ mod scanning {
   cluster_test!(scanning, should_sscan_set);
   cluster_test!(scanning, should_zscan_sorted_set);
   cluster_test!(scanning, should_scan_cluster);
+  cluster_test!(scanning, should_scan_from_checkpoint);
+  cluster_test!(scanning, should_scan_cluster_from_checkpoint);
+  cluster_test!(scanning, should_scan_cluster_with_options);
+  #[cfg(feature = "replicas")]
+  cluster_test!(scanning, should_scan_cluster_replicas);
+  cluster_test!(scanning, should_unlink_matching);
+  cluster_test!(scanning, should_expire_matching);
+  cluster_test!(scanning, should_dump_and_copy_matching);
 }
 
 mod slowlog {
@@ -156,10 +205,16 @@ mod server {
   cluster_test!(server, should_read_server_info);
   cluster_test!(server, should_ping_server);
   cluster_test!(server, should_run_custom_command);
+  cluster_test!(server, should_run_custom_command_with_key_specs);
+  cluster_test!(server, should_run_custom_command_with_key_specs_in_pipeline);
   cluster_test!(server, should_read_last_save);
   cluster_test!(server, should_read_db_size);
   cluster_test!(server, should_start_bgsave);
   cluster_test!(server, should_do_bgrewriteaof);
+  cluster_test!(server, should_read_command_info);
+  cluster_test!(server, should_read_latency_events);
+  cluster_test!(server, should_require_cluster_node_for_latency_reset);
+  cluster_test!(server, should_list_modules);
 }
 
 mod sets {
@@ -334,6 +389,10 @@ mod streams {
   cluster_test!(streams, should_xclaim_multiple_ids);
   cluster_test!(streams, should_xclaim_with_justid);
   cluster_test!(streams, should_xautoclaim_default);
+  cluster_test!(streams, should_consume_stream_with_dead_letter);
+  cluster_test!(streams, should_reject_zero_consumer_block);
+  #[cfg(feature = "serde-json")]
+  cluster_test!(streams, should_xadd_and_read_typed_entries);
 }
 
 mod cluster {
@@ -343,6 +402,8 @@ mod cluster {
 #[cfg(feature = "client-tracking")]
 mod tracking {
   cluster_test!(tracking, should_invalidate_foo_resp3);
+  cluster_test!(tracking, should_serve_and_invalidate_client_cache);
+  cluster_test!(tracking, should_invalidate_foo_resp2_redirect);
 }
 
 #[cfg(feature = "time-series")]
@@ -2,7 +2,16 @@ use fred::{
   clients::RedisClient,
   error::RedisError,
   interfaces::*,
-  types::{RedisConfig, RedisValue},
+  types::{
+    Expiration,
+    ExpireOptions,
+    FieldExpireResult,
+    FieldPersistResult,
+    GetExpiration,
+    HSetExOptions,
+    RedisConfig,
+    RedisValue,
+  },
 };
 use std::collections::{HashMap, HashSet};
 
@@ -220,3 +229,77 @@ pub async fn should_get_values(client: RedisClient, _: RedisConfig) -> Result<()
 
   Ok(())
 }
+
+pub async fn should_expire_and_persist_fields(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_redis_version!(client, 7, 4);
+  check_null!(client, "foo");
+  let _: () = client.hset("foo", vec![("a", "1"), ("b", "2")]).await?;
+
+  let results: Vec<FieldExpireResult> = client.hexpire("foo", 60, None, vec!["a", "c"]).await?;
+  assert_eq!(results, vec![FieldExpireResult::Set, FieldExpireResult::NoField]);
+  let result: FieldExpireResult = client.hexpire("foo", 60, Some(ExpireOptions::NX), "a").await?;
+  assert_eq!(result, FieldExpireResult::ConditionNotMet);
+  let result: FieldExpireResult = client.hpexpire("foo", 120_000, Some(ExpireOptions::GT), "a").await?;
+  assert_eq!(result, FieldExpireResult::Set);
+
+  let ttls: Vec<i64> = client.httl("foo", vec!["a", "b", "c"]).await?;
+  assert!(ttls[0] > 60 && ttls[0] <= 120);
+  assert_eq!(&ttls[1 ..], &[-1, -2]);
+  let ttls: Vec<i64> = client.hpttl("foo", "a").await?;
+  assert!(ttls[0] > 60_000);
+
+  let results: Vec<FieldPersistResult> = client.hpersist("foo", vec!["a", "b", "c"]).await?;
+  assert_eq!(results, vec![
+    FieldPersistResult::Persisted,
+    FieldPersistResult::NoExpiration,
+    FieldPersistResult::NoField
+  ]);
+
+  let result: FieldExpireResult = client.hexpire_at("foo", 1, None, "b").await?;
+  assert_eq!(result, FieldExpireResult::Deleted);
+  assert!(!client.hexists::<bool, _, _>("foo", "b").await?);
+  Ok(())
+}
+
+pub async fn should_get_field_expire_time(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_redis_version!(client, 7, 4);
+  check_null!(client, "foo");
+  let _: () = client.hset("foo", ("a", "1")).await?;
+
+  let timestamp = 4_102_444_800_i64;
+  let _: FieldExpireResult = client.hpexpire_at("foo", timestamp * 1000, None, "a").await?;
+  let times: Vec<i64> = client.hexpire_time("foo", "a").await?;
+  assert_eq!(times, vec![timestamp]);
+  let times: Vec<i64> = client.hpexpire_time("foo", vec!["a", "b"]).await?;
+  assert_eq!(times, vec![timestamp * 1000, -2]);
+  Ok(())
+}
+
+pub async fn should_getdel_getex_and_setex_fields(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_redis_version!(client, 8, 0);
+  check_null!(client, "foo");
+
+  let set: bool = client
+    .hsetex("foo", Some(HSetExOptions::FNX), Some(Expiration::EX(60)), vec![
+      ("a", "1"),
+      ("b", "2"),
+      ("c", "3"),
+    ])
+    .await?;
+  assert!(set);
+  let set: bool = client.hsetex("foo", Some(HSetExOptions::FNX), None, ("a", "4")).await?;
+  assert!(!set);
+
+  let values: Vec<String> = client
+    .hgetex("foo", Some(GetExpiration::PERSIST), vec!["a", "b"])
+    .await?;
+  assert_eq!(values, vec!["1".to_string(), "2".to_string()]);
+  let ttls: Vec<i64> = client.httl("foo", vec!["a", "c"]).await?;
+  assert_eq!(ttls[0], -1);
+  assert!(ttls[1] > 0);
+
+  let values: Vec<Option<String>> = client.hgetdel("foo", vec!["a", "d"]).await?;
+  assert_eq!(values, vec![Some("1".to_string()), None]);
+  assert!(!client.hexists::<bool, _, _>("foo", "a").await?);
+  Ok(())
+}
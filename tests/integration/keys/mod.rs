@@ -3,7 +3,22 @@ use fred::{
   clients::{RedisClient, RedisPool},
   error::RedisError,
   interfaces::*,
-  types::{Expiration, ReconnectPolicy, RedisConfig, RedisMap, RedisValue},
+  types::{
+    Expiration,
+    ExpireOptions,
+    GetExpiration,
+    KeyType,
+    LcsMatch,
+    LcsResult,
+    ObjectEncoding,
+    Options,
+    ReconnectPolicy,
+    RedisConfig,
+    RedisMap,
+    RedisValue,
+    SortOptions,
+    SortOrder,
+  },
 };
 use futures::{pin_mut, StreamExt};
 use std::{collections::HashMap, time::Duration};
@@ -357,6 +372,130 @@ pub async fn should_copy_values(client: RedisClient, _: RedisConfig) -> Result<(
   Ok(())
 }
 
+pub async fn should_read_key_type_and_encoding(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_null!(client, "foo{1}");
+  assert_eq!(client.r#type::<KeyType, _>("foo{1}").await?, KeyType::None);
+
+  let _: () = client.set("foo{1}", "123", None, None, false).await?;
+  let _: i64 = client.rpush("bar{1}", "a").await?;
+  assert_eq!(client.r#type::<KeyType, _>("foo{1}").await?, KeyType::String);
+  assert_eq!(client.r#type::<KeyType, _>("bar{1}").await?, KeyType::List);
+  assert_eq!(
+    client.object_encoding::<ObjectEncoding, _>("foo{1}").await?,
+    ObjectEncoding::Int
+  );
+  assert!(client.object_refcount::<i64, _>("foo{1}").await? >= 1);
+  assert!(client.object_idletime::<i64, _>("foo{1}").await? >= 0);
+
+  let touched: i64 = client.touch(vec!["foo{1}", "bar{1}", "baz{1}"]).await?;
+  assert_eq!(touched, 2);
+  Ok(())
+}
+
+pub async fn should_expire_with_options(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_redis_7!(client);
+  let _: () = client.set("foo", "bar", None, None, false).await?;
+
+  assert!(
+    !client
+      .expire_with_options::<bool, _>("foo", 100, ExpireOptions::XX)
+      .await?
+  );
+  assert!(
+    client
+      .expire_with_options::<bool, _>("foo", 100, ExpireOptions::NX)
+      .await?
+  );
+  assert!(
+    !client
+      .expire_with_options::<bool, _>("foo", 50, ExpireOptions::GT)
+      .await?
+  );
+  assert!(
+    client
+      .pexpire::<bool, _>("foo", 50_000, Some(ExpireOptions::LT))
+      .await?
+  );
+  assert!(client.pttl::<i64, _>("foo").await? <= 50_000);
+
+  let expire_time: i64 = client.expire_time("foo").await?;
+  let pexpire_time: i64 = client.pexpire_time("foo").await?;
+  assert_eq!(expire_time, pexpire_time / 1000);
+  assert!(client.pexpire_at::<bool, _>("foo", pexpire_time + 1000, None).await?);
+  assert_eq!(client.pexpire_time::<i64, _>("foo").await?, pexpire_time + 1000);
+  Ok(())
+}
+
+pub async fn should_sort_values(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let _: i64 = client.rpush("foo{1}", vec![3, 1, 2]).await?;
+
+  let values: Vec<i64> = client.sort("foo{1}", SortOptions::default()).await?;
+  assert_eq!(values, vec![1, 2, 3]);
+  let values: Vec<i64> = client
+    .sort_ro("foo{1}", SortOptions::default().order(SortOrder::Desc).limit(0, 2))
+    .await?;
+  assert_eq!(values, vec![3, 2]);
+  assert!(client
+    .sort_ro::<Vec<i64>, _>("foo{1}", SortOptions::default().store("bar{1}"))
+    .await
+    .is_err());
+
+  let stored: i64 = client
+    .sort("foo{1}", SortOptions::default().alpha().store("bar{1}"))
+    .await?;
+  assert_eq!(stored, 3);
+  let values: Vec<i64> = client.lrange("bar{1}", 0, -1).await?;
+  assert_eq!(values, vec![1, 2, 3]);
+  Ok(())
+}
+
+pub async fn should_set_and_get_with_expiration(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let _: () = client.setex("foo", 100, "bar").await?;
+  assert!(client.ttl::<i64, _>("foo").await? > 0);
+  let _: () = client.psetex("foo", 100_000, "baz").await?;
+  assert!(client.pttl::<i64, _>("foo").await? > 1000);
+
+  let value: String = client.getex("foo", Some(GetExpiration::PERSIST)).await?;
+  assert_eq!(value, "baz");
+  assert_eq!(client.ttl::<i64, _>("foo").await?, -1);
+  let value: String = client.getex("foo", Some(GetExpiration::EX(100))).await?;
+  assert_eq!(value, "baz");
+  assert!(client.ttl::<i64, _>("foo").await? > 0);
+  Ok(())
+}
+
+pub async fn should_get_substr(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let _: () = client.set("foo", "This is a string", None, None, false).await?;
+
+  assert_eq!(client.substr::<String, _>("foo", 0, 3).await?, "This");
+  assert_eq!(client.substr::<String, _>("foo", -3, -1).await?, "ing");
+  Ok(())
+}
+
+pub async fn should_read_lcs_matches(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_redis_7!(client);
+  let _: () = client.set("foo{1}", "ohmytext", None, None, false).await?;
+  let _: () = client.set("bar{1}", "mynewtext", None, None, false).await?;
+
+  let result: LcsResult = client.lcs("foo{1}", "bar{1}", false, true, Some(0), true).await?;
+  assert_eq!(result, LcsResult {
+    matches: vec![
+      LcsMatch {
+        first:  (4, 7),
+        second: (5, 8),
+        len:    Some(4),
+      },
+      LcsMatch {
+        first:  (2, 3),
+        second: (0, 1),
+        len:    Some(2),
+      },
+    ],
+    len:     6,
+  });
+  Ok(())
+}
+
 pub async fn should_get_keys_from_pool_in_a_stream(
   client: RedisClient,
   config: RedisConfig,
@@ -390,3 +529,31 @@ pub async fn should_get_keys_from_pool_in_a_stream(
 
   Ok(())
 }
+
+pub async fn should_split_cross_slot_commands(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let keys = vec!["a{1}", "b{2}", "c{3}", "d{1}"];
+  let values: Vec<(&str, i64)> = keys.iter().enumerate().map(|(i, k)| (*k, i as i64)).collect();
+
+  let error = client.mset(values.clone()).await.unwrap_err();
+  assert!(error.slot_errors().is_none());
+
+  let options = Options {
+    split_cross_slot: Some(true),
+    ..Default::default()
+  };
+  let client = client.with_options(&options);
+  let _: () = client.mset(values).await?;
+
+  let result: Vec<Option<i64>> = client.mget(vec!["a{1}", "b{2}", "missing{4}", "c{3}", "d{1}"]).await?;
+  assert_eq!(result, vec![Some(0), Some(1), None, Some(2), Some(3)]);
+  let count: i64 = client.exists(keys.clone()).await?;
+  assert_eq!(count, 4);
+  let count: i64 = client.touch(keys.clone()).await?;
+  assert_eq!(count, 4);
+  let count: i64 = client.unlink(vec!["a{1}", "b{2}"]).await?;
+  assert_eq!(count, 2);
+  let count: i64 = client.del(keys).await?;
+  assert_eq!(count, 2);
+
+  Ok(())
+}
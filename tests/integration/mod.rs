@@ -3,6 +3,7 @@ pub mod utils;
 pub mod docker;
 
 mod acl;
+mod bitmaps;
 mod client;
 mod cluster;
 mod geo;
@@ -24,9 +25,13 @@ mod slowlog;
 mod sorted_sets;
 mod streams;
 
+#[cfg(feature = "redis-bloom")]
+mod redis_bloom;
 #[cfg(feature = "redis-json")]
 mod redis_json;
 
+#[cfg(feature = "redis-search")]
+mod redisearch;
 #[cfg(feature = "time-series")]
 mod timeseries;
 
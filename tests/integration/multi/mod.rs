@@ -2,8 +2,17 @@ use fred::{
   clients::RedisClient,
   error::RedisError,
   interfaces::*,
-  types::{RedisConfig, RedisValue},
+  types::{ClientKillFilter, ReconnectPolicy, RedisConfig, RedisValue},
 };
+use futures::future::try_join_all;
+use std::{
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
+  time::Duration,
+};
+use tokio::time::sleep;
 
 pub async fn should_run_get_set_trx(client: RedisClient, _config: RedisConfig) -> Result<(), RedisError> {
   let trx = client.multi();
@@ -34,3 +43,69 @@ pub async fn should_fail_with_hashslot_error(client: RedisClient, _config: Redis
 
   Ok(())
 }
+
+pub async fn should_retry_watched_transaction(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let _: () = client.set("foo{1}", 0, None, None, false).await?;
+
+  let tasks = (0 .. 10).map(|_| {
+    let client = client.clone();
+    async move {
+      let policy = ReconnectPolicy::new_constant(0, 10);
+      client
+        .transaction_with_retry::<_, _, _, ((), i64)>("foo{1}", policy, |client, trx| async move {
+          let count: i64 = client.get("foo{1}").await?;
+          let _: () = trx.set("foo{1}", count + 1, None, None, false).await?;
+          let _: () = trx.get("foo{1}").await?;
+          Ok(())
+        })
+        .await
+    }
+  });
+  let results = try_join_all(tasks).await?;
+  assert_eq!(results.len(), 10);
+
+  let count: i64 = client.get("foo{1}").await?;
+  assert_eq!(count, 10);
+  Ok(())
+}
+
+pub async fn should_retry_watched_transaction_after_connection_closes(
+  client: RedisClient,
+  _: RedisConfig,
+) -> Result<(), RedisError> {
+  let _: () = client.set("foo{1}", 0, None, None, false).await?;
+  let attempts = Arc::new(AtomicUsize::new(0));
+
+  let policy = ReconnectPolicy::new_constant(0, 10);
+  let (other, counter) = (client.clone(), attempts.clone());
+  let (_, count): ((), i64) = client
+    .transaction_with_retry("foo{1}", policy, move |client, trx| {
+      let (other, attempts) = (other.clone(), counter.clone());
+      async move {
+        let count: i64 = client.get("foo{1}").await?;
+        if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+          // change the watched key and close the connection before EXEC
+          let _: () = other.incr("foo{1}").await?;
+          for (server, id) in client.connection_ids().await.into_iter() {
+            let filters = vec![ClientKillFilter::ID(id.to_string())];
+            if other.is_clustered() {
+              let _: () = other.with_cluster_node(server).client_kill(filters).await?;
+            } else {
+              let _: () = other.client_kill(filters).await?;
+            }
+          }
+          // wait for the dedicated connection to close so EXEC is not sent
+          sleep(Duration::from_millis(100)).await;
+        }
+
+        let _: () = trx.set("foo{1}", count + 10, None, None, false).await?;
+        let _: () = trx.get("foo{1}").await?;
+        Ok(())
+      }
+    })
+    .await?;
+
+  assert_eq!(attempts.load(Ordering::SeqCst), 2);
+  assert_eq!(count, 11);
+  Ok(())
+}
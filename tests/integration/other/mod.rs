@@ -41,6 +41,8 @@ use fred::types::ReplicaConfig;
 use fred::types::Resolve;
 #[cfg(feature = "partial-tracing")]
 use fred::types::TracingConfig;
+#[cfg(feature = "credential-provider")]
+use fred::types::{CredentialProvider, ReconnectPolicy, Server};
 #[cfg(feature = "dns")]
 use std::net::{IpAddr, SocketAddr};
 #[cfg(feature = "dns")]
@@ -763,3 +765,107 @@ pub async fn should_fail_with_bad_host_via_wait_interface(
   let _ = task.await;
   Ok(())
 }
+
+#[cfg(feature = "credential-provider")]
+#[derive(Debug, Default)]
+struct FakeCreds {
+  username: Option<String>,
+  password: Option<String>,
+  calls:    AtomicUsize,
+}
+
+#[cfg(feature = "credential-provider")]
+#[async_trait]
+impl CredentialProvider for FakeCreds {
+  async fn fetch(&self, _: Option<&Server>) -> Result<(Option<String>, Option<String>), RedisError> {
+    self.calls.fetch_add(1, Ordering::SeqCst);
+    Ok((self.username.clone(), self.password.clone()))
+  }
+
+  fn refresh_interval(&self) -> Option<Duration> {
+    Some(Duration::from_millis(500))
+  }
+}
+
+#[cfg(feature = "credential-provider")]
+pub async fn should_use_credential_provider(_: RedisClient, mut config: RedisConfig) -> Result<(), RedisError> {
+  let provider = Arc::new(FakeCreds {
+    username: config.username.take(),
+    password: config.password.take(),
+    ..Default::default()
+  });
+  config.credential_provider = Some(provider.clone());
+
+  let client = RedisClient::new(config, None, None, None);
+  client.init().await?;
+  let _: () = client.ping().await?;
+  let calls = provider.calls.load(Ordering::SeqCst);
+  assert!(calls > 0);
+
+  // the provider is called again on the refresh interval
+  sleep(Duration::from_millis(1200)).await;
+  let _: () = client.ping().await?;
+  assert!(provider.calls.load(Ordering::SeqCst) > calls);
+
+  client.quit().await?;
+  Ok(())
+}
+
+#[cfg(feature = "credential-provider")]
+pub async fn should_refresh_credentials_after_reconnecting(
+  _: RedisClient,
+  mut config: RedisConfig,
+) -> Result<(), RedisError> {
+  let provider = Arc::new(FakeCreds {
+    username: config.username.take(),
+    password: config.password.take(),
+    ..Default::default()
+  });
+  config.credential_provider = Some(provider.clone());
+
+  let policy = ReconnectPolicy::new_constant(0, 100);
+  let client = RedisClient::new(config, None, None, Some(policy));
+  client.init().await?;
+  client.force_reconnection().await?;
+  sleep(Duration::from_millis(600)).await;
+  let _: () = client.ping().await?;
+
+  // the refresh task keeps running after the reconnection
+  let calls = provider.calls.load(Ordering::SeqCst);
+  sleep(Duration::from_millis(1200)).await;
+  let _: () = client.ping().await?;
+  assert!(provider.calls.load(Ordering::SeqCst) > calls);
+
+  client.quit().await?;
+  Ok(())
+}
+
+#[cfg(feature = "credential-provider")]
+pub async fn should_run_one_credential_refresh_task(
+  _: RedisClient,
+  mut config: RedisConfig,
+) -> Result<(), RedisError> {
+  let provider = Arc::new(FakeCreds {
+    username: config.username.take(),
+    password: config.password.take(),
+    ..Default::default()
+  });
+  config.credential_provider = Some(provider.clone());
+
+  let client = RedisClient::new(config, None, None, None);
+  client.init().await?;
+  // connecting again replaces the previous refresh task
+  let _ = client.connect();
+  client.wait_for_connect().await?;
+
+  let calls = provider.calls.load(Ordering::SeqCst);
+  sleep(Duration::from_millis(1200)).await;
+  let refreshes = provider.calls.load(Ordering::SeqCst) - calls;
+  assert!(refreshes > 0 && refreshes < 4);
+
+  client.quit().await?;
+  let calls = provider.calls.load(Ordering::SeqCst);
+  sleep(Duration::from_millis(1200)).await;
+  assert_eq!(provider.calls.load(Ordering::SeqCst), calls);
+  Ok(())
+}
@@ -1,9 +1,19 @@
 use fred::{
   clients::{RedisClient, RedisPool},
-  error::RedisError,
+  error::{RedisError, RedisErrorKind},
   interfaces::*,
-  types::RedisConfig,
+  types::{
+    ConnectionConfig,
+    ElasticPoolConfig,
+    LeastInFlight,
+    PoolEvent,
+    PowerOfTwoChoices,
+    RedisConfig,
+    RedisValue,
+  },
 };
+use std::{sync::Arc, time::Duration};
+use tokio::time::timeout;
 
 async fn create_and_ping_pool(config: &RedisConfig, count: usize) -> Result<(), RedisError> {
   let pool = RedisPool::new(config.clone(), None, None, None, count)?;
@@ -32,3 +42,201 @@ pub async fn should_connect_and_ping_static_pool_two_conn(
 ) -> Result<(), RedisError> {
   create_and_ping_pool(&config, 2).await
 }
+
+pub async fn should_acquire_dedicated_client_from_pool(
+  _: RedisClient,
+  config: RedisConfig,
+) -> Result<(), RedisError> {
+  let connection = ConnectionConfig {
+    max_dedicated_connections: 1,
+    dedicated_wait_timeout: Duration::from_millis(100),
+    ..Default::default()
+  };
+  let pool = RedisPool::new(config, None, Some(connection), None, 2)?;
+  let _ = pool.init().await?;
+
+  let client = pool.acquire().await?;
+  let _: () = client.set("foo{1}", 1, None, None, false).await?;
+  let _: () = client.watch("foo{1}").await?;
+  let _: () = pool.incr("foo{1}").await?;
+  let trx = client.multi();
+  let _: () = trx.incr("foo{1}").await?;
+  let result: Option<i64> = trx.exec(true).await?;
+  assert!(result.is_none());
+
+  let error = pool.acquire().await.unwrap_err();
+  assert_eq!(*error.kind(), RedisErrorKind::Timeout);
+  drop(client);
+
+  let client = pool.acquire().await?;
+  let foo: i64 = client.get("foo{1}").await?;
+  assert_eq!(foo, 2);
+
+  let _ = pool.quit().await;
+  Ok(())
+}
+
+pub async fn should_reuse_dedicated_clients(_: RedisClient, config: RedisConfig) -> Result<(), RedisError> {
+  let connection = ConnectionConfig {
+    max_dedicated_connections: 1,
+    ..Default::default()
+  };
+  let pool = RedisPool::new(config, None, Some(connection), None, 2)?;
+  let _ = pool.init().await?;
+
+  let client = pool.acquire().await?;
+  let id = client.id().to_string();
+  let _: () = client.watch("foo{1}").await?;
+  drop(client);
+
+  // the connection is reused without the watched keys
+  let client = pool.acquire().await?;
+  assert_eq!(client.id(), id);
+  let _: () = pool.incr("foo{1}").await?;
+  let trx = client.multi();
+  let _: () = trx.incr("foo{1}").await?;
+  let result: RedisValue = trx.exec(true).await?;
+  assert!(!result.is_null());
+
+  // closed connections are replaced
+  let _: () = client.quit().await?;
+  drop(client);
+  let client = pool.acquire().await?;
+  assert_ne!(client.id(), id);
+  let _: () = client.ping().await?;
+
+  let _ = pool.quit().await;
+  Ok(())
+}
+
+pub async fn should_resize_elastic_pool(_: RedisClient, config: RedisConfig) -> Result<(), RedisError> {
+  let elastic = ElasticPoolConfig {
+    min_size: 1,
+    max_size: 3,
+    check_interval: Duration::from_secs(60),
+    ..Default::default()
+  };
+  let pool = RedisPool::new_elastic(config, None, None, None, elastic)?;
+  let mut events = pool.pool_event_rx();
+  let _ = pool.init().await?;
+  assert_eq!(pool.size(), 1);
+  assert_eq!(pool.max_size(), 3);
+
+  pool.resize(3).await?;
+  assert_eq!(pool.clients().len(), 3);
+  for client in pool.clients().iter() {
+    assert!(client.is_connected());
+    client.ping::<()>().await?;
+  }
+  for _ in 0 .. 2 {
+    assert!(matches!(events.recv().await.unwrap(), PoolEvent::Add(_)));
+  }
+
+  pool.resize(1).await?;
+  assert_eq!(pool.size(), 1);
+  for _ in 0 .. 2 {
+    assert!(matches!(events.recv().await.unwrap(), PoolEvent::Remove(_)));
+  }
+  assert!(pool.resize(4).await.is_err());
+  assert!(pool.resize(0).await.is_err());
+  pool.ping::<()>().await?;
+
+  let _ = pool.quit().await;
+  Ok(())
+}
+
+pub async fn should_replace_unhealthy_clients(_: RedisClient, config: RedisConfig) -> Result<(), RedisError> {
+  let elastic = ElasticPoolConfig {
+    min_size: 2,
+    max_size: 2,
+    check_interval: Duration::from_millis(100),
+    unhealthy_timeout: Duration::from_millis(200),
+    ..Default::default()
+  };
+  let pool = RedisPool::new_elastic(config, None, None, None, elastic)?;
+  let mut events = pool.pool_event_rx();
+  let _ = pool.init().await?;
+
+  let client = pool.clients()[0].clone();
+  client.quit().await?;
+  let (old_id, new_id) = timeout(Duration::from_secs(5), async {
+    loop {
+      if let PoolEvent::Replace(old_id, new_id) = events.recv().await.unwrap() {
+        break (old_id, new_id);
+      }
+    }
+  })
+  .await
+  .unwrap();
+
+  assert_eq!(&*old_id, client.id());
+  assert!(!client.is_connected());
+  let replacement = pool.clients()[0].clone();
+  assert_eq!(&*new_id, replacement.id());
+  assert!(replacement.is_connected());
+  replacement.ping::<()>().await?;
+  let _: () = pool.ping().await?;
+
+  let _ = pool.quit().await;
+  Ok(())
+}
+
+pub async fn should_route_with_pool_routing_policies(_: RedisClient, config: RedisConfig) -> Result<(), RedisError> {
+  let pool = RedisPool::new(config, None, None, None, 3)?;
+  let _ = pool.init().await?;
+
+  pool.set_routing_policy(Arc::new(LeastInFlight));
+  let _: () = pool.set("foo{1}", 1, None, None, false).await?;
+  let foo: i64 = pool.incr("foo{1}").await?;
+  assert_eq!(foo, 2);
+
+  pool.set_routing_policy(Arc::new(PowerOfTwoChoices));
+  for _ in 0 .. 10 {
+    let _: () = pool.incr("foo{1}").await?;
+  }
+  let foo: i64 = pool.get("foo{1}").await?;
+  assert_eq!(foo, 12);
+
+  let _ = pool.quit().await;
+  Ok(())
+}
+
+pub async fn should_merge_pool_events_and_messages(_: RedisClient, config: RedisConfig) -> Result<(), RedisError> {
+  let pool = RedisPool::new(config, None, None, None, 2)?;
+  let mut reconnect_rx = pool.reconnect_rx();
+  let mut events = pool.pool_event_rx();
+  let mut message_rx = pool.message_rx();
+  let _ = pool.init().await?;
+
+  let _ = tokio::time::timeout(Duration::from_secs(5), reconnect_rx.recv())
+    .await
+    .unwrap();
+  let event = tokio::time::timeout(Duration::from_secs(5), events.recv())
+    .await
+    .unwrap()
+    .unwrap();
+  if let PoolEvent::Reconnect(id, _) = event {
+    assert!(pool.clients().iter().any(|c| c.id() == &*id));
+  } else {
+    panic!("Unexpected pool event: {:?}", event);
+  }
+
+  pool.subscribe("foo").await?;
+  // the pooled connections can still run other commands
+  for client in pool.clients().iter() {
+    let _: Option<String> = client.get("bar{1}").await?;
+  }
+  let _: () = pool.publish("foo", "bar").await?;
+  let message = tokio::time::timeout(Duration::from_secs(5), message_rx.recv())
+    .await
+    .unwrap()
+    .unwrap();
+  assert_eq!(message.channel, "foo");
+  assert_eq!(message.value.as_str().unwrap(), "bar");
+  pool.unsubscribe("foo").await?;
+
+  assert_eq!(pool.command_queue_len(), 0);
+  assert_eq!(pool.read_redelivery_count(), 0);
+  let _ = pool.quit().await;
+  Ok(())
+}
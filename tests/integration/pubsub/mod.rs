@@ -228,3 +228,103 @@ pub async fn should_get_pubsub_shard_numsub(client: RedisClient, _: RedisConfig)
 
   Ok(())
 }
+
+#[cfg(feature = "subscriber-client")]
+pub async fn should_subscribe_with_channel_streams(
+  client: RedisClient,
+  config: RedisConfig,
+) -> Result<(), RedisError> {
+  use fred::{clients::SubscriberClient, types::LagPolicy};
+
+  let subscriber = SubscriberClient::new(config, None, None, None);
+  let _ = subscriber.init().await?;
+  let mut foo = subscriber.subscribe_stream(CHANNEL1, 32, LagPolicy::Block).await?;
+  let mut bar = subscriber.psubscribe_stream("ba*", 2, LagPolicy::DropOldest).await?;
+  assert!(subscriber.tracked_channels().contains(CHANNEL1));
+  assert!(subscriber.tracked_patterns().contains("ba*"));
+  wait_a_sec().await;
+
+  for idx in 0 .. 4 {
+    let _: () = client.publish(CHANNEL1, idx).await?;
+    let _: () = client.publish(CHANNEL2, idx).await?;
+  }
+  for idx in 0 .. 4 {
+    let message = foo.next().await.unwrap()?;
+    assert_eq!(message.channel, CHANNEL1);
+    assert_eq!(message.value.as_i64(), Some(idx));
+  }
+  wait_a_sec().await;
+  // the pattern stream only keeps the 2 most recent messages
+  for idx in 2 .. 4 {
+    let message = bar.next().await.unwrap()?;
+    assert_eq!(message.channel, CHANNEL2);
+    assert_eq!(message.kind, fred::types::MessageKind::PMessage);
+    assert_eq!(message.value.as_i64(), Some(idx));
+  }
+
+  drop(foo);
+  wait_a_sec().await;
+  assert!(!subscriber.tracked_channels().contains(CHANNEL1));
+  assert!(subscriber.tracked_patterns().contains("ba*"));
+
+  let _ = subscriber.quit().await;
+  assert!(bar.next().await.is_none());
+  Ok(())
+}
+
+#[cfg(feature = "subscriber-client")]
+pub async fn should_resubscribe_after_dropping_channel_stream(
+  client: RedisClient,
+  config: RedisConfig,
+) -> Result<(), RedisError> {
+  use fred::{clients::SubscriberClient, types::LagPolicy};
+
+  let subscriber = SubscriberClient::new(config, None, None, None);
+  let _ = subscriber.init().await?;
+  let foo = subscriber.subscribe_stream(CHANNEL1, 32, LagPolicy::Block).await?;
+  drop(foo);
+  let mut foo = subscriber.subscribe_stream(CHANNEL1, 32, LagPolicy::Block).await?;
+  wait_a_sec().await;
+  assert!(subscriber.tracked_channels().contains(CHANNEL1));
+
+  let _: () = client.publish(CHANNEL1, 1).await?;
+  let message = foo.next().await.unwrap()?;
+  assert_eq!(message.channel, CHANNEL1);
+  assert_eq!(message.value.as_i64(), Some(1));
+
+  let _ = subscriber.quit().await;
+  Ok(())
+}
+
+pub async fn should_recv_typed_keyspace_notifications(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  use fred::{
+    interfaces::KeyspaceNotificationsInterface,
+    types::{KeyspaceEventFlags, KeyspaceOperation},
+  };
+  use tokio::sync::mpsc::unbounded_channel;
+
+  let subscriber = client.clone_new();
+  let _ = subscriber.init().await?;
+  subscriber
+    .configure_keyspace_notifications(KeyspaceEventFlags::all())
+    .await?;
+  subscriber.keyspace_subscribe(None, "ks*").await?;
+
+  let (tx, mut rx) = unbounded_channel();
+  let _ = subscriber.on_keyspace_operation(Some("ks{1}*"), move |operation, event| {
+    let _ = tx.send((operation, event.key.as_str().unwrap().to_owned()));
+    Ok(())
+  });
+  wait_a_sec().await;
+
+  let _: () = client.set("ks{2}a", 1, None, None, false).await?;
+  let _: () = client.set("ks{1}a", 1, None, None, false).await?;
+  let _: () = client.del("ks{1}a").await?;
+
+  assert_eq!(rx.recv().await, Some((KeyspaceOperation::Set, "ks{1}a".into())));
+  assert_eq!(rx.recv().await, Some((KeyspaceOperation::Del, "ks{1}a".into())));
+
+  subscriber.keyspace_unsubscribe_all().await?;
+  let _ = subscriber.quit().await;
+  Ok(())
+}
@@ -0,0 +1,185 @@
+use fred::{
+  clients::RedisClient,
+  error::RedisError,
+  interfaces::*,
+  types::{BfInfoField, BfInsertOptions, CfReserveOptions, RedisConfig, RedisValue, TopKDimensions},
+};
+use futures::TryStreamExt;
+use std::collections::HashMap;
+
+pub async fn should_add_and_check_bloom_filter(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let _: () = client.bf_reserve("foo", 0.01, 1000, None, false).await?;
+  assert!(client.bf_add::<bool, _, _>("foo", "a").await?);
+  assert!(!client.bf_add::<bool, _, _>("foo", "a").await?);
+
+  let added: Vec<bool> = client.bf_madd("foo", vec!["b", "c"]).await?;
+  assert_eq!(added, vec![true, true]);
+  assert!(client.bf_exists::<bool, _, _>("foo", "b").await?);
+
+  let exists: Vec<bool> = client.bf_mexists("foo", vec!["a", "d"]).await?;
+  assert_eq!(exists, vec![true, false]);
+  assert_eq!(client.bf_card::<i64, _>("foo").await?, 3);
+
+  let capacity: Vec<i64> = client.bf_info("foo", Some(BfInfoField::Capacity)).await?;
+  assert_eq!(capacity, vec![1000]);
+  Ok(())
+}
+
+pub async fn should_insert_into_bloom_filter(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let result: Result<Vec<bool>, RedisError> = client
+    .bf_insert(
+      "foo",
+      BfInsertOptions {
+        nocreate: true,
+        ..Default::default()
+      },
+      "a",
+    )
+    .await;
+  assert!(result.is_err());
+
+  let added: Vec<bool> = client
+    .bf_insert(
+      "foo",
+      BfInsertOptions {
+        capacity: Some(100),
+        error: Some(0.001),
+        nonscaling: true,
+        ..Default::default()
+      },
+      vec!["a", "b"],
+    )
+    .await?;
+  assert_eq!(added, vec![true, true]);
+  Ok(())
+}
+
+pub async fn should_migrate_bloom_filter_with_scandump(
+  client: RedisClient,
+  _: RedisConfig,
+) -> Result<(), RedisError> {
+  let _: () = client.bf_reserve("foo", 0.01, 1000, None, false).await?;
+  let items: Vec<String> = (0 .. 100).map(|i| format!("item-{}", i)).collect();
+  let _: () = client.bf_madd("foo", items.clone()).await?;
+
+  let chunks: Vec<_> = client.bf_scandump_stream("foo").try_collect().await?;
+  assert!(!chunks.is_empty());
+  for (iterator, data) in chunks.into_iter() {
+    let _: () = client.bf_loadchunk("bar", iterator, data).await?;
+  }
+
+  let exists: Vec<bool> = client.bf_mexists("bar", items).await?;
+  assert!(exists.into_iter().all(|b| b));
+  Ok(())
+}
+
+pub async fn should_use_cuckoo_filter(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let _: () = client
+    .cf_reserve("foo", 1000, CfReserveOptions {
+      bucket_size: Some(2),
+      ..Default::default()
+    })
+    .await?;
+  assert!(client.cf_add::<bool, _, _>("foo", "a").await?);
+  assert!(client.cf_add::<bool, _, _>("foo", "a").await?);
+  assert!(!client.cf_addnx::<bool, _, _>("foo", "a").await?);
+  assert_eq!(client.cf_count::<i64, _, _>("foo", "a").await?, 2);
+
+  assert!(client.cf_del::<bool, _, _>("foo", "a").await?);
+  assert_eq!(client.cf_count::<i64, _, _>("foo", "a").await?, 1);
+
+  let added: Vec<i64> = client.cf_insertnx("foo", None, true, vec!["a", "b"]).await?;
+  assert_eq!(added, vec![0, 1]);
+  let exists: Vec<bool> = client.cf_mexists("foo", vec!["b", "c"]).await?;
+  assert_eq!(exists, vec![true, false]);
+  assert!(client.cf_exists::<bool, _, _>("foo", "b").await?);
+
+  let chunks: Vec<_> = client.cf_scandump_stream("foo").try_collect().await?;
+  for (iterator, data) in chunks.into_iter() {
+    let _: () = client.cf_loadchunk("bar", iterator, data).await?;
+  }
+  assert!(client.cf_exists::<bool, _, _>("bar", "b").await?);
+  Ok(())
+}
+
+pub async fn should_use_count_min_sketch(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let _: () = client.cms_initbydim("foo{1}", 2000, 5).await?;
+  let _: () = client.cms_initbyprob("bar{1}", 0.001, 0.01).await?;
+  let _: () = client.cms_initbydim("baz{1}", 2000, 5).await?;
+
+  let counts: Vec<i64> = client.cms_incrby("foo{1}", vec![("a", 5), ("b", 1)]).await?;
+  assert_eq!(counts, vec![5, 1]);
+  let _: Vec<i64> = client.cms_incrby("baz{1}", vec![("a", 1)]).await?;
+
+  let _: () = client.cms_merge("foo{1}", vec!["foo{1}", "baz{1}"], vec![1, 2]).await?;
+  let counts: Vec<i64> = client.cms_query("foo{1}", vec!["a", "b", "c"]).await?;
+  assert_eq!(counts, vec![7, 1, 0]);
+
+  let info: HashMap<String, i64> = client.cms_info("foo{1}").await?;
+  assert_eq!(info.get("width"), Some(&2000));
+  assert_eq!(info.get("depth"), Some(&5));
+  Ok(())
+}
+
+pub async fn should_use_topk_sketch(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let _: () = client
+    .topk_reserve(
+      "foo",
+      2,
+      Some(TopKDimensions {
+        width: 50,
+        depth: 4,
+        decay: 0.9,
+      }),
+    )
+    .await?;
+
+  let _: Vec<RedisValue> = client.topk_add("foo", vec!["a", "b", "a"]).await?;
+  let _: Vec<RedisValue> = client.topk_incrby("foo", vec![("c", 10)]).await?;
+
+  let list: Vec<String> = client.topk_list("foo", false).await?;
+  assert_eq!(list, vec!["c".to_string(), "a".to_string()]);
+  let list: Vec<RedisValue> = client.topk_list("foo", true).await?;
+  assert_eq!(list.len(), 4);
+
+  let in_topk: Vec<bool> = client.topk_query("foo", vec!["a", "b"]).await?;
+  assert_eq!(in_topk, vec![true, false]);
+
+  let info: HashMap<String, RedisValue> = client.topk_info("foo").await?;
+  assert_eq!(info.get("k").and_then(|v| v.as_i64()), Some(2));
+  Ok(())
+}
+
+pub async fn should_use_tdigest_sketch(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let _: () = client.tdigest_create("foo{1}", Some(100)).await?;
+  let _: () = client.tdigest_create("bar{1}", None).await?;
+  let _: () = client.tdigest_add("foo{1}", vec![1.0, 2.0, 3.0, 4.0, 5.0]).await?;
+  let _: () = client.tdigest_add("bar{1}", vec![6.0, 7.0]).await?;
+
+  assert_eq!(client.tdigest_min::<f64, _>("foo{1}").await?, 1.0);
+  assert_eq!(client.tdigest_max::<f64, _>("foo{1}").await?, 5.0);
+  let ranks: Vec<i64> = client.tdigest_rank("foo{1}", vec![0.0, 10.0]).await?;
+  assert_eq!(ranks, vec![-1, 5]);
+  let ranks: Vec<i64> = client.tdigest_revrank("foo{1}", vec![10.0]).await?;
+  assert_eq!(ranks, vec![-1]);
+  let values: Vec<f64> = client.tdigest_byrank("foo{1}", vec![0]).await?;
+  assert_eq!(values, vec![1.0]);
+  let values: Vec<f64> = client.tdigest_byrevrank("foo{1}", vec![0]).await?;
+  assert_eq!(values, vec![5.0]);
+  let fractions: Vec<f64> = client.tdigest_cdf("foo{1}", vec![0.0]).await?;
+  assert_eq!(fractions, vec![0.0]);
+  let quantiles: Vec<f64> = client.tdigest_quantile("foo{1}", vec![0.0, 1.0]).await?;
+  assert_eq!(quantiles, vec![1.0, 5.0]);
+  let mean: f64 = client.tdigest_trimmed_mean("foo{1}", 0.0, 1.0).await?;
+  assert_eq!(mean, 3.0);
+
+  let _: () = client
+    .tdigest_merge("baz{1}", vec!["foo{1}", "bar{1}"], None, false)
+    .await?;
+  assert_eq!(client.tdigest_max::<f64, _>("baz{1}").await?, 7.0);
+
+  let _: () = client.tdigest_reset("foo{1}").await?;
+  let info: HashMap<String, RedisValue> = client.tdigest_info("foo{1}").await?;
+  assert_eq!(info.get("Observations").and_then(|v| v.as_i64()), Some(0));
+  Ok(())
+}
@@ -0,0 +1,316 @@
+use bytes_utils::Str;
+use fred::{
+  clients::RedisClient,
+  error::RedisError,
+  interfaces::*,
+  types::{
+    encode_f32_vector,
+    AggregateOperation,
+    AggregateResults,
+    DistanceMetric,
+    FtAggregateOptions,
+    FtCreateOptions,
+    FtSearchOptions,
+    IndexKind,
+    KnnResults,
+    Load,
+    RedisConfig,
+    RedisValue,
+    ReducerFunc,
+    SearchReducer,
+    SearchResults,
+    SearchSchema,
+    SearchSortBy,
+    SortOrder,
+    VectorField,
+    VectorType,
+    WithCursor,
+  },
+};
+use futures::TryStreamExt;
+use std::{collections::HashMap, time::Duration};
+use tokio::time::sleep;
+
+async fn create_books_index(client: &RedisClient) -> Result<(), RedisError> {
+  let _: () = client
+    .ft_create(
+      "books",
+      FtCreateOptions {
+        on: Some(IndexKind::Hash),
+        prefixes: vec!["book:".into()],
+        ..Default::default()
+      },
+      vec![
+        SearchSchema::text("title").sortable(),
+        SearchSchema::tag("genre"),
+        SearchSchema::numeric("year").sortable(),
+      ],
+    )
+    .await?;
+
+  for (idx, (title, genre, year)) in [
+    ("the hobbit", "fantasy", "1937"),
+    ("the fellowship of the ring", "fantasy", "1954"),
+    ("dune", "scifi", "1965"),
+    ("foundation", "scifi", "1951"),
+  ]
+  .into_iter()
+  .enumerate()
+  {
+    let _: () = client
+      .hset(format!("book:{}", idx), [
+        ("title", title),
+        ("genre", genre),
+        ("year", year),
+      ])
+      .await?;
+  }
+
+  // indexing happens in the background
+  sleep(Duration::from_millis(100)).await;
+  Ok(())
+}
+
+pub async fn should_list_create_and_drop_index(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let indexes: Vec<String> = client.ft_list().await?;
+  assert!(indexes.is_empty());
+
+  create_books_index(&client).await?;
+  let indexes: Vec<String> = client.ft_list().await?;
+  assert_eq!(indexes, vec!["books".to_string()]);
+
+  let info: HashMap<String, RedisValue> = client.ft_info("books").await?;
+  assert_eq!(info.get("index_name").and_then(|v| v.as_string()), Some("books".into()));
+
+  let _: () = client.ft_dropindex("books", true).await?;
+  let indexes: Vec<String> = client.ft_list().await?;
+  assert!(indexes.is_empty());
+  assert_eq!(client.exists::<i64, _>("book:0").await?, 0);
+  Ok(())
+}
+
+pub async fn should_search_with_typed_results(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  create_books_index(&client).await?;
+
+  let results: SearchResults = client
+    .ft_search("books", "@genre:{scifi}", FtSearchOptions {
+      withscores: true,
+      sortby: Some(SearchSortBy {
+        attribute: "year".into(),
+        order:     Some(SortOrder::Asc),
+        withcount: false,
+      }),
+      ..Default::default()
+    })
+    .await?;
+
+  assert_eq!(results.total, 2);
+  let ids: Vec<&str> = results.results.iter().map(|r| &*r.id).collect();
+  assert_eq!(ids, vec!["book:3", "book:2"]);
+  assert!(results.results.iter().all(|r| r.score.is_some()));
+  assert_eq!(
+    results.results[0].fields.get("title").and_then(|v| v.as_string()),
+    Some("foundation".into())
+  );
+
+  let results: SearchResults = client
+    .ft_search("books", "@title:ring", FtSearchOptions {
+      nocontent: true,
+      ..Default::default()
+    })
+    .await?;
+  assert_eq!(results.total, 1);
+  assert_eq!(results.results[0].id, "book:1");
+  assert!(results.results[0].fields.is_empty());
+  Ok(())
+}
+
+pub async fn should_aggregate_with_groupby(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  create_books_index(&client).await?;
+
+  let results: AggregateResults = client
+    .ft_aggregate("books", "*", FtAggregateOptions {
+      pipeline: vec![
+        AggregateOperation::GroupBy {
+          fields:   vec!["@genre".into()],
+          reducers: vec![SearchReducer {
+            func: ReducerFunc::Count,
+            args: vec![],
+            name: Some("count".into()),
+          }],
+        },
+        AggregateOperation::SortBy {
+          properties: vec![("@genre".into(), SortOrder::Asc)],
+          max:        None,
+        },
+      ],
+      ..Default::default()
+    })
+    .await?;
+
+  let rows: Vec<(Option<String>, Option<i64>)> = results
+    .rows
+    .iter()
+    .map(|row| {
+      (
+        row.get("genre").and_then(|v| v.as_string()),
+        row.get("count").and_then(|v| v.as_i64()),
+      )
+    })
+    .collect();
+  assert_eq!(rows, vec![
+    (Some("fantasy".into()), Some(2)),
+    (Some("scifi".into()), Some(2))
+  ]);
+  Ok(())
+}
+
+pub async fn should_aggregate_with_cursor_stream(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  create_books_index(&client).await?;
+
+  let pages: Vec<AggregateResults> = client
+    .ft_aggregate_stream("books", "*", FtAggregateOptions {
+      load: Some(Load::Some(vec!["@title".into()])),
+      cursor: Some(WithCursor {
+        count:    Some(1),
+        max_idle: None,
+      }),
+      ..Default::default()
+    })
+    .try_collect()
+    .await?;
+
+  let mut titles: Vec<String> = pages
+    .iter()
+    .flat_map(|page| page.rows.iter())
+    .filter_map(|row| row.get("title").and_then(|v| v.as_string()))
+    .collect();
+  titles.sort();
+  assert_eq!(titles, vec![
+    "dune".to_string(),
+    "foundation".into(),
+    "the fellowship of the ring".into(),
+    "the hobbit".into()
+  ]);
+  assert_eq!(pages.last().and_then(|p| p.cursor), Some(0));
+  Ok(())
+}
+
+pub async fn should_alter_index_and_explain(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  create_books_index(&client).await?;
+
+  let _: () = client
+    .ft_alter("books", false, SearchSchema::text("author").alias("writer"))
+    .await?;
+  let _: () = client.hset("book:4", [("title", "emma"), ("author", "austen")]).await?;
+  sleep(Duration::from_millis(100)).await;
+
+  let results: SearchResults = client
+    .ft_search("books", "@writer:austen", FtSearchOptions::default())
+    .await?;
+  assert_eq!(results.total, 1);
+  assert_eq!(results.results[0].id, "book:4");
+
+  let plan: String = client.ft_explain("books", "@writer:austen", None).await?;
+  assert!(plan.contains("austen"));
+  Ok(())
+}
+
+pub async fn should_manage_aliases(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  create_books_index(&client).await?;
+
+  let _: () = client.ft_aliasadd("library", "books").await?;
+  let results: SearchResults = client.ft_search("library", "dune", FtSearchOptions::default()).await?;
+  assert_eq!(results.total, 1);
+
+  let _: () = client.ft_aliasupdate("library", "books").await?;
+  let _: () = client.ft_aliasdel("library").await?;
+  let result: Result<SearchResults, RedisError> =
+    client.ft_search("library", "dune", FtSearchOptions::default()).await;
+  assert!(result.is_err());
+  Ok(())
+}
+
+pub async fn should_update_synonyms_and_spellcheck(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  create_books_index(&client).await?;
+
+  let _: () = client
+    .ft_synupdate("books", "group1", false, vec!["hobbit", "halfling"])
+    .await?;
+  let synonyms: HashMap<Str, Vec<Str>> = client.ft_syndump("books").await?;
+  assert_eq!(synonyms.get("halfling"), Some(&vec![Str::from("group1")]));
+
+  let results: SearchResults = client
+    .ft_search("books", "halfling", FtSearchOptions::default())
+    .await?;
+  assert_eq!(results.total, 1);
+
+  let suggestions: RedisValue = client.ft_spellcheck("books", "dunee", None, None, None).await?;
+  assert!(!suggestions.is_null());
+  Ok(())
+}
+
+pub async fn should_search_knn_with_vector_index(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let _: () = client
+    .ft_create(
+      "points",
+      FtCreateOptions {
+        on: Some(IndexKind::Hash),
+        prefixes: vec!["point:".into()],
+        ..Default::default()
+      },
+      vec![
+        SearchSchema::tag("color"),
+        SearchSchema::vector(
+          "embedding",
+          VectorField::hnsw(VectorType::Float32, 2, DistanceMetric::L2),
+        ),
+      ],
+    )
+    .await?;
+
+  for (idx, (color, embedding)) in [("red", [0.0, 0.0]), ("blue", [1.0, 1.0]), ("red", [5.0, 5.0])]
+    .into_iter()
+    .enumerate()
+  {
+    let _: () = client
+      .hset(format!("point:{}", idx), [
+        ("color", RedisValue::from(color)),
+        ("embedding", RedisValue::Bytes(encode_f32_vector(&embedding))),
+      ])
+      .await?;
+  }
+  sleep(Duration::from_millis(100)).await;
+
+  let results: KnnResults = client
+    .ft_search_knn("points", "embedding", &[0.9, 0.9], 2, "*", FtSearchOptions {
+      r#return: vec!["color".into()],
+      ..Default::default()
+    })
+    .await?;
+  let results: Vec<(String, f64, Option<String>)> = results
+    .into_tuples()
+    .into_iter()
+    .map(|(key, score, fields)| (key.to_string(), score, fields.get("color").and_then(|v| v.as_string())))
+    .collect();
+
+  assert_eq!(results.len(), 2);
+  assert_eq!(results[0].0, "point:1");
+  assert_eq!(results[0].2, Some("blue".into()));
+  assert_eq!(results[1].0, "point:0");
+  assert!(results[0].1 < results[1].1);
+
+  let results: KnnResults = client
+    .ft_search_knn(
+      "points",
+      "embedding",
+      &[0.9, 0.9],
+      1,
+      "@color:{red}",
+      FtSearchOptions::default(),
+    )
+    .await?;
+  assert_eq!(results.results.len(), 1);
+  assert_eq!(results.results[0].key, "point:0");
+  Ok(())
+}
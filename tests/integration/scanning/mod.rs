@@ -1,5 +1,13 @@
-use fred::{prelude::*, types::Scanner};
+use fred::{
+  prelude::*,
+  types::{BulkOptions, ScanCheckpoint, ScanOptions, Scanner},
+};
 use futures::TryStreamExt;
+use std::{
+  collections::HashSet,
+  time::{Duration, Instant},
+};
+use tokio::time::sleep;
 use tokio_stream::StreamExt;
 
 const SCAN_KEYS: i64 = 100;
@@ -140,3 +148,227 @@ pub async fn should_scan_cluster(client: RedisClient, _: RedisConfig) -> Result<
   assert_eq!(count, 2000);
   Ok(())
 }
+
+pub async fn should_scan_from_checkpoint(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  for idx in 0 .. SCAN_KEYS {
+    let _: () = client
+      .set(format!("foo-{}-{}", idx, "{1}"), idx, None, None, false)
+      .await?;
+  }
+
+  let mut keys = HashSet::new();
+  let checkpoint = {
+    let mut scan_stream = client.scan("foo*{1}", Some(10), None);
+    let mut page = scan_stream.next().await.unwrap()?;
+    keys.extend(page.take_results().unwrap().into_iter());
+    page.checkpoint().to_string()
+  };
+  let checkpoint: ScanCheckpoint = checkpoint.parse()?;
+  assert!(!checkpoint.is_finished());
+
+  let mut scan_stream = client.scan_from("foo*{1}", Some(10), None, checkpoint);
+  let mut last_checkpoint = None;
+  while let Some(page) = scan_stream.next().await {
+    let mut page = page?;
+    keys.extend(page.take_results().unwrap().into_iter());
+    last_checkpoint = Some(page.checkpoint());
+    page.next()?;
+  }
+  assert_eq!(keys.len() as i64, SCAN_KEYS);
+  assert_eq!(last_checkpoint, Some(ScanCheckpoint::Cursor(None)));
+
+  let mut scan_stream = client.scan_from("foo*{1}", Some(10), None, "-".parse()?);
+  assert!(scan_stream.next().await.is_none());
+  Ok(())
+}
+
+pub async fn should_scan_cluster_from_checkpoint(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  for idx in 0 .. 2000 {
+    let _: () = client.set(idx, idx, None, None, false).await?;
+  }
+
+  let mut keys = HashSet::new();
+  let checkpoint = {
+    let mut checkpoint = None;
+    let mut scan_stream = client.scan_cluster("*", Some(10), None);
+    for _ in 0 .. 5 {
+      let mut page = scan_stream.next().await.unwrap()?;
+      keys.extend(page.take_results().unwrap().into_iter());
+      checkpoint = Some(page.checkpoint());
+      page.next()?;
+    }
+    checkpoint.unwrap().to_string()
+  };
+
+  let checkpoint: ScanCheckpoint = checkpoint.parse()?;
+  let nodes = match checkpoint {
+    ScanCheckpoint::Cluster(ref cursors) => cursors.len(),
+    _ => panic!("Expected cluster checkpoint."),
+  };
+  assert_eq!(
+    nodes,
+    client.cached_cluster_state().unwrap().unique_primary_nodes().len()
+  );
+
+  let mut scan_stream = client.scan_cluster_from("*", Some(10), None, checkpoint);
+  while let Some(page) = scan_stream.next().await {
+    let mut page = page?;
+    keys.extend(page.take_results().unwrap().into_iter());
+    page.next()?;
+  }
+
+  assert_eq!(keys.len(), 2000);
+  let result = client
+    .scan_cluster_from("*", Some(10), None, ScanCheckpoint::Cursor(None))
+    .next()
+    .await;
+  assert!(result.unwrap().is_err());
+  Ok(())
+}
+
+pub async fn should_scan_cluster_with_options(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  for idx in 0 .. 2000 {
+    let _: () = client.set(idx, idx, None, None, false).await?;
+  }
+
+  let options = ScanOptions {
+    max_concurrency: Some(1),
+    max_pages_per_second: Some(1000),
+    ..Default::default()
+  };
+  let handle = options.handle.clone();
+  handle.pause();
+  let _handle = handle.clone();
+  tokio::spawn(async move {
+    sleep(Duration::from_millis(200)).await;
+    _handle.resume();
+  });
+
+  let started = Instant::now();
+  let mut keys = HashSet::new();
+  let mut scan_stream = client.scan_cluster_with_options("*", Some(100), None, None, options);
+  while let Some(page) = scan_stream.next().await {
+    let mut page = page?;
+    if keys.is_empty() {
+      assert!(started.elapsed() >= Duration::from_millis(200));
+    }
+    keys.extend(page.take_results().unwrap().into_iter());
+
+    if let ScanCheckpoint::Cluster(cursors) = page.checkpoint() {
+      let in_progress = cursors
+        .values()
+        .filter(|cursor| cursor.as_ref().map(|c| &**c != "0").unwrap_or(false))
+        .count();
+      assert!(in_progress <= 1);
+    } else {
+      panic!("Expected cluster checkpoint.");
+    }
+    page.next()?;
+  }
+
+  assert_eq!(keys.len(), 2000);
+  Ok(())
+}
+
+#[cfg(feature = "replicas")]
+pub async fn should_scan_cluster_replicas(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  for idx in 0 .. 2000 {
+    let _: () = client.set(idx, idx, None, None, false).await?;
+  }
+  // wait for the writes to reach the replicas
+  sleep(Duration::from_millis(500)).await;
+
+  let options = ScanOptions {
+    replicas: true,
+    ..Default::default()
+  };
+  let mut keys = HashSet::new();
+  let mut scan_stream = client.scan_cluster_with_options("*", Some(100), None, None, options);
+  while let Some(page) = scan_stream.next().await {
+    let mut page = page?;
+    keys.extend(page.take_results().unwrap().into_iter());
+    page.next()?;
+  }
+
+  assert_eq!(keys.len(), 2000);
+  Ok(())
+}
+
+pub async fn should_unlink_matching(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  for idx in 0 .. SCAN_KEYS {
+    let _: () = client.set(format!("foo-{}", idx), idx, None, None, false).await?;
+  }
+  let _: () = client.set("bar", 1, None, None, false).await?;
+
+  let options = BulkOptions {
+    batch_size: 10,
+    dry_run: true,
+    ..Default::default()
+  };
+  let progress = client.unlink_matching("foo-*", options).await?;
+  assert_eq!(progress.scanned, SCAN_KEYS as u64);
+  assert_eq!(progress.processed, 0);
+  assert!(progress.checkpoint.is_finished());
+  assert_eq!(client.exists::<i64, _>("foo-1").await?, 1);
+
+  let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+  let options = BulkOptions {
+    batch_size: 10,
+    progress: Some(tx),
+    ..Default::default()
+  };
+  let progress = client.unlink_matching("foo-*", options).await?;
+  assert_eq!(progress.processed, SCAN_KEYS as u64);
+  assert_eq!(client.exists::<i64, _>("foo-1").await?, 0);
+  assert_eq!(client.exists::<i64, _>("bar").await?, 1);
+
+  let mut last = None;
+  while let Ok(update) = rx.try_recv() {
+    last = Some(update);
+  }
+  assert_eq!(last, Some(progress));
+  Ok(())
+}
+
+pub async fn should_expire_matching(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  for idx in 0 .. SCAN_KEYS {
+    let _: () = client.set(format!("foo-{}", idx), idx, None, None, false).await?;
+  }
+
+  let progress = client.expire_matching("foo-*", 60, BulkOptions::default()).await?;
+  assert_eq!(progress.scanned, SCAN_KEYS as u64);
+  assert_eq!(progress.processed, SCAN_KEYS as u64);
+  let ttl: i64 = client.ttl("foo-1").await?;
+  assert!(ttl > 0 && ttl <= 60);
+  Ok(())
+}
+
+pub async fn should_dump_and_copy_matching(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  for idx in 0 .. SCAN_KEYS {
+    let _: () = client.set(format!("foo-{}", idx), idx, None, None, false).await?;
+  }
+  let _: () = client.expire("foo-1", 60).await?;
+
+  let dumps = client.dump_matching("foo-*", BulkOptions::default()).await?;
+  assert_eq!(dumps.len(), SCAN_KEYS as usize);
+  let dump = dumps.iter().find(|dump| dump.key.as_str() == Some("foo-1")).unwrap();
+  assert!(dump.ttl.unwrap() > 0);
+  assert_eq!(dumps.iter().filter(|dump| dump.ttl.is_some()).count(), 1);
+
+  let destination = client.clone_new();
+  destination.init().await?;
+  let progress = client
+    .copy_matching("foo-*", &destination, false, BulkOptions::default())
+    .await?;
+  assert_eq!(progress.scanned, SCAN_KEYS as u64);
+  assert_eq!(progress.processed, 0);
+
+  let progress = client
+    .copy_matching("foo-*", &destination, true, BulkOptions::default())
+    .await?;
+  assert_eq!(progress.processed, SCAN_KEYS as u64);
+  assert!(destination.pttl::<i64, _>("foo-1").await? > 0);
+
+  destination.quit().await?;
+  Ok(())
+}
@@ -1,4 +1,16 @@
-use fred::prelude::*;
+use fred::{
+  prelude::*,
+  types::{
+    ClusterHash,
+    CommandInfo,
+    CustomCommand,
+    KeySpecBeginSearch,
+    KeySpecFindKeys,
+    LatencyEvent,
+    LatencySample,
+    ModuleInfo,
+  },
+};
 use std::time::Duration;
 use tokio::time::sleep;
 
@@ -35,6 +47,48 @@ pub async fn should_run_custom_command(_client: RedisClient, _: RedisConfig) ->
   Ok(())
 }
 
+pub async fn should_run_custom_command_with_key_specs(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_redis_7!(client);
+
+  let mset = CustomCommand::new_static("MSET", ClusterHash::KeySpecs, false);
+  let _: () = client.custom(mset.clone(), vec!["a{1}", "1", "b{1}", "2"]).await?;
+  let zunion = CustomCommand::new_static("ZUNION", ClusterHash::KeySpecs, false);
+  let _: Vec<String> = client.custom(zunion, vec!["2", "c{1}", "d{1}"]).await?;
+  let xinfo = CustomCommand::new_static("XINFO", ClusterHash::KeySpecs, false);
+  let _: i64 = client.xadd("e{1}", false, None, "*", ("foo", "bar")).await?;
+  let _: RedisValue = client.custom(xinfo, vec!["STREAM", "e{1}"]).await?;
+
+  let values: Vec<String> = client.mget(vec!["a{1}", "b{1}"]).await?;
+  assert_eq!(values, vec!["1", "2"]);
+
+  if client.is_clustered() {
+    let error = client
+      .custom::<(), _>(mset, vec!["a{1}", "1", "b{2}", "2"])
+      .await
+      .unwrap_err();
+    assert_eq!(*error.kind(), RedisErrorKind::Cluster);
+  }
+
+  Ok(())
+}
+
+pub async fn should_run_custom_command_with_key_specs_in_pipeline(
+  client: RedisClient,
+  _: RedisConfig,
+) -> Result<(), RedisError> {
+  check_redis_7!(client);
+
+  let mset = CustomCommand::new_static("MSET", ClusterHash::KeySpecs, false);
+  let pipeline = client.pipeline();
+  let _: () = pipeline.custom(mset, vec!["a{1}", "1", "b{1}", "2"]).await?;
+  let _: () = pipeline.mget(vec!["a{1}", "b{1}"]).await?;
+
+  let results: Vec<RedisValue> = pipeline.all().await?;
+  assert_eq!(results.len(), 2);
+  assert_eq!(results[1].clone().convert::<Vec<String>>()?, vec!["1", "2"]);
+  Ok(())
+}
+
 pub async fn should_read_last_save(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
   let lastsave: Option<i64> = client.lastsave().await?;
   assert!(lastsave.is_some());
@@ -72,3 +126,64 @@ pub async fn should_do_bgrewriteaof(client: RedisClient, _: RedisConfig) -> Resu
   sleep(Duration::from_millis(1000)).await;
   Ok(())
 }
+
+pub async fn should_read_command_info(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  check_redis_7!(client);
+  assert!(client.command_count::<i64>().await? > 0);
+
+  let info: Vec<Option<CommandInfo>> = client.command_info(vec!["get", "mset", "foo"]).await?;
+  assert_eq!(info.len(), 3);
+  let get = info[0].as_ref().unwrap();
+  assert_eq!(get.name, "get");
+  assert_eq!(get.arity, 2);
+  assert!(get.flags.iter().any(|f| &**f == "readonly"));
+  assert!(get.acl_categories.iter().any(|c| &**c == "@string"));
+  assert_eq!(get.key_specs[0].begin_search, KeySpecBeginSearch::Index(1));
+  assert_eq!(get.key_specs[0].find_keys, KeySpecFindKeys::Range {
+    last_key: 0,
+    key_step: 1,
+    limit:    0,
+  });
+  let mset = info[1].as_ref().unwrap();
+  assert_eq!(mset.arity, -3);
+  assert_eq!(mset.key_specs[0].find_keys, KeySpecFindKeys::Range {
+    last_key: -1,
+    key_step: 2,
+    limit:    0,
+  });
+  assert!(info[2].is_none());
+
+  let keys: Vec<String> = client.command_getkeys(vec!["MSET", "a", "b", "c", "d"]).await?;
+  assert_eq!(keys, vec!["a", "c"]);
+  Ok(())
+}
+
+pub async fn should_read_latency_events(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  for server in client.active_connections().await?.into_iter() {
+    let node = client.with_cluster_node(server);
+    let _: i64 = node.latency_reset(Vec::<String>::new()).await?;
+    let latest: Vec<LatencyEvent> = node.latency_latest().await?;
+    assert!(latest.is_empty());
+    let history: Vec<LatencySample> = node.latency_history("command").await?;
+    assert!(history.is_empty());
+  }
+
+  let report: String = client.latency_doctor().await?;
+  assert!(!report.is_empty());
+  Ok(())
+}
+
+pub async fn should_list_modules(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let modules: Vec<ModuleInfo> = client.module_list().await?;
+  assert!(modules.iter().all(|m| !m.name.is_empty()));
+  Ok(())
+}
+
+pub async fn should_require_cluster_node_for_latency_reset(
+  client: RedisClient,
+  _: RedisConfig,
+) -> Result<(), RedisError> {
+  let result: Result<i64, RedisError> = client.latency_reset(Vec::<String>::new()).await;
+  assert_eq!(result.unwrap_err().kind(), &RedisErrorKind::Cluster);
+  Ok(())
+}
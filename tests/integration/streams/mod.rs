@@ -1,8 +1,17 @@
 use fred::{
+  clients::StreamConsumer,
   prelude::*,
-  types::{XCapKind, XCapTrim, XReadResponse, XReadValue, XID},
+  types::{StreamConsumerConfig, StreamEntry, XCapKind, XCapTrim, XReadResponse, XReadValue, XID},
+};
+use std::{
+  collections::HashMap,
+  hash::Hash,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
+  time::Duration,
 };
-use std::{collections::HashMap, hash::Hash, time::Duration};
 use tokio::time::sleep;
 
 type FakeExpectedValues = Vec<HashMap<String, HashMap<String, usize>>>;
@@ -718,3 +727,97 @@ pub async fn should_xautoclaim_default(client: RedisClient, _: RedisConfig) -> R
 
   Ok(())
 }
+
+pub async fn should_consume_stream_with_dead_letter(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let _ = add_stream_entries(&client, "foo{1}", 3).await?;
+
+  let config = StreamConsumerConfig {
+    start_id: XID::Manual("0".into()),
+    block: Duration::from_millis(100),
+    claim_interval: Duration::from_millis(100),
+    claim_min_idle: Duration::from_millis(50),
+    dead_letter_key: Some("bar{1}".into()),
+    max_deliveries: 2,
+    ..Default::default()
+  };
+  let consumer = StreamConsumer::new(&client, "foo{1}", "group1", "consumer1", config);
+  let processed = Arc::new(AtomicUsize::new(0));
+
+  let (_consumer, _processed) = (consumer.clone(), processed.clone());
+  let jh = tokio::spawn(async move {
+    _consumer
+      .run(move |entry: StreamEntry| {
+        let processed = _processed.clone();
+        async move {
+          if entry.fields.get("count") == Some(&RedisValue::String("1".into())) {
+            Err(RedisError::new(RedisErrorKind::Unknown, "Failed to process entry."))
+          } else {
+            processed.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+          }
+        }
+      })
+      .await
+  });
+
+  sleep(Duration::from_secs(1)).await;
+  consumer.shutdown();
+  jh.await??;
+
+  assert_eq!(processed.load(Ordering::SeqCst), 2);
+  let dead: Vec<XReadValue<String, String, usize>> = client.xrange_values("bar{1}", "-", "+", None).await?;
+  assert_eq!(dead.len(), 1);
+  assert_eq!(dead[0].1.get("count"), Some(&1));
+  let (total_count, _, _, _): (u64, Option<String>, Option<String>, Vec<(String, u64)>) =
+    client.xpending("foo{1}", "group1", ()).await?;
+  assert_eq!(total_count, 0);
+
+  Ok(())
+}
+
+pub async fn should_reject_zero_consumer_block(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let config = StreamConsumerConfig {
+    block: Duration::from_millis(0),
+    ..Default::default()
+  };
+  let consumer = StreamConsumer::new(&client, "foo{1}", "group1", "consumer1", config);
+
+  let error = consumer.run(|_: StreamEntry| async { Ok(()) }).await.unwrap_err();
+  assert_eq!(*error.kind(), RedisErrorKind::Config);
+  Ok(())
+}
+
+#[cfg(feature = "serde-json")]
+pub async fn should_xadd_and_read_typed_entries(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  use fred::types::StreamFields;
+  use serde::{Deserialize, Serialize};
+
+  #[derive(Debug, Deserialize, Serialize, PartialEq)]
+  struct Order {
+    id:    u64,
+    name:  String,
+    items: Vec<String>,
+  }
+
+  let order = Order {
+    id:    1,
+    name:  "123".into(),
+    items: vec!["a".into(), "b".into()],
+  };
+  let id: String = client.xadd("foo{1}", false, None, "*", StreamFields(&order)).await?;
+  let values: Vec<XReadValue<String, String, String>> = client.xrange_values("foo{1}", "-", "+", None).await?;
+  assert_eq!(values[0].1.get("items"), Some(&r#"["a","b"]"#.to_owned()));
+
+  let entries: Vec<(String, Order)> = client.xrange_typed("foo{1}", "-", "+", None).await?;
+  assert_eq!(entries, vec![(id.clone(), order)]);
+  let entries: HashMap<String, Vec<(String, Order)>> = client.xread_typed(None, None, "foo{1}", "0").await?;
+  assert_eq!(entries.get("foo{1}").unwrap()[0].0, id);
+
+  let _: () = client.xgroup_create("foo{1}", "group1", "0", false).await?;
+  let entries: HashMap<String, Vec<(String, Order)>> = client
+    .xreadgroup_typed("group1", "consumer1", None, None, false, "foo{1}", ">")
+    .await?;
+  assert_eq!(entries.get("foo{1}").unwrap()[0].1.items, vec!["a", "b"]);
+
+  Ok(())
+}
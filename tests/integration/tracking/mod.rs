@@ -1,6 +1,6 @@
 use fred::{
   prelude::*,
-  types::{RedisKey, RespVersion},
+  types::{ClientCacheConfig, RedisKey, RespVersion},
 };
 use std::{
   sync::{
@@ -102,3 +102,82 @@ pub async fn should_invalidate_foo_resp2_centralized(client: RedisClient, _: Red
     panic!("Failed to invalidate foo");
   }
 }
+
+pub async fn should_serve_and_invalidate_client_cache(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  let other = client.clone_new();
+  let _ = other.connect();
+  let _ = other.wait_for_connect().await?;
+
+  client.enable_client_cache(ClientCacheConfig::default()).await?;
+  let _: () = client.set("foo{1}", "bar", None, None, false).await?;
+  let _: () = client.hset("baz{1}", ("a", 1)).await?;
+  assert_eq!(client.get::<String, _>("foo{1}").await?, "bar");
+  assert_eq!(client.get::<String, _>("foo{1}").await?, "bar");
+  assert_eq!(client.hget::<i64, _, _>("baz{1}", "a").await?, 1);
+  assert_eq!(client.hget::<i64, _, _>("baz{1}", "a").await?, 1);
+  let stats = client.client_cache_stats();
+  assert_eq!((stats.hits, stats.misses, stats.entries), (2, 2, 2));
+
+  let _: () = other.set("foo{1}", "qux", None, None, false).await?;
+  sleep(Duration::from_millis(100)).await;
+  assert_eq!(client.get::<String, _>("foo{1}").await?, "qux");
+  assert_eq!(client.client_cache_stats().invalidations, 1);
+
+  let _: () = other.flushall(false).await?;
+  sleep(Duration::from_millis(100)).await;
+  assert_eq!(client.client_cache_stats().entries, 0);
+  assert_eq!(client.hget::<Option<i64>, _, _>("baz{1}", "a").await?, None);
+
+  client.disable_client_cache().await?;
+  let _ = other.quit().await;
+  Ok(())
+}
+
+pub async fn should_clear_client_cache_after_select(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  client.enable_client_cache(ClientCacheConfig::default()).await?;
+  let _: () = client.set("foo", "bar", None, None, false).await?;
+  assert_eq!(client.get::<String, _>("foo").await?, "bar");
+  assert_eq!(client.client_cache_stats().entries, 1);
+
+  let _: () = client.select(1).await?;
+  assert_eq!(client.client_cache_stats().entries, 0);
+  assert_eq!(client.get::<Option<String>, _>("foo").await?, None);
+
+  let _: () = client.select(0).await?;
+  assert_eq!(client.get::<String, _>("foo").await?, "bar");
+  client.disable_client_cache().await?;
+  Ok(())
+}
+
+pub async fn should_invalidate_foo_resp2_redirect(client: RedisClient, _: RedisConfig) -> Result<(), RedisError> {
+  if client.protocol_version() == RespVersion::RESP3 {
+    return Ok(());
+  }
+
+  let key: RedisKey = "foo{1}".into();
+  check_null!(client, "foo{1}");
+
+  let invalidated = Arc::new(AtomicBool::new(false));
+  let _invalidated = invalidated.clone();
+
+  let mut invalidations = client.invalidation_rx();
+  tokio::spawn(async move {
+    while let Ok(invalidation) = invalidations.recv().await {
+      if invalidation.keys.contains(&key) {
+        _invalidated.swap(true, Ordering::SeqCst);
+      }
+    }
+  });
+
+  let _ = client.start_tracking(None, false, false, false, false).await?;
+  let _: () = client.get("foo{1}").await?;
+  let _: () = client.incr("foo{1}").await?;
+
+  sleep(Duration::from_secs(1)).await;
+  client.stop_tracking().await?;
+  if invalidated.load(Ordering::Acquire) {
+    Ok(())
+  } else {
+    panic!("Failed to invalidate foo");
+  }
+}
@@ -701,3 +701,12 @@ macro_rules! check_redis_7 (
     }
   }
 );
+
+macro_rules! check_redis_version (
+  ($client:ident, $major:expr, $minor:expr) => {
+    let version = $client.server_version().unwrap();
+    if (version.major, version.minor) < ($major, $minor) {
+      return Ok(());
+    }
+  }
+);